@@ -0,0 +1,234 @@
+use alloc::vec::Vec;
+
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+const BEL: u32 = 0x07;
+const ESC: u32 = 0x1B;
+
+const CSI: u32 = b'[' as _;
+const DCS: u32 = b'P' as _;
+const OSC: u32 = b']' as _;
+const PM: u32 = b'^' as _;
+const SOS: u32 = b'X' as _;
+const ST: u32 = b'\\' as _;
+const APC: u32 = b'_' as _;
+
+// Returns the final byte of the 7-bit equivalent of a C1 control at the start
+// of the string and the number of units that the control uses.
+fn c1_utf8(string: &[u8]) -> Option<(u32, usize)> {
+    match *string {
+        [0xC2, x @ 0x80..=0x9F, ..] => Some((u32::from(x) - 0x40, 2)),
+        _ => None,
+    }
+}
+
+#[cfg(any(doc, windows))]
+fn c1_utf16(string: &[u16]) -> Option<(u32, usize)> {
+    match *string {
+        [x @ 0x80..=0x9F, ..] => Some((u32::from(x) - 0x40, 1)),
+        _ => None,
+    }
+}
+
+struct Sequence {
+    len: usize,
+    is_sgr: bool,
+}
+
+impl Sequence {
+    const fn new(len: usize) -> Self {
+        Self { len, is_sgr: false }
+    }
+}
+
+// Parses the control sequence at the start of the string, if there is one.
+// Sequences that are not terminated are considered to continue until the
+// first character that cannot be part of them.
+fn parse<T, F>(string: &[T], c1_fn: F) -> Option<Sequence>
+where
+    F: Fn(&[T]) -> Option<(u32, usize)>,
+    T: Copy + Into<u32>,
+{
+    let unit = |i: usize| string.get(i).map(|&x| x.into());
+
+    let (kind, mut i) = if unit(0) == Some(ESC) {
+        match unit(1) {
+            Some(x @ 0x40..=0x5F) => (x, 2),
+            Some(0x20..=0x2F) => {
+                let mut i = 2;
+                while let Some(0x20..=0x2F) = unit(i) {
+                    i += 1;
+                }
+                if let Some(0x30..=0x7E) = unit(i) {
+                    i += 1;
+                }
+                return Some(Sequence::new(i));
+            }
+            Some(0x30..=0x3F | 0x60..=0x7E) => return Some(Sequence::new(2)),
+            _ => return Some(Sequence::new(1)),
+        }
+    } else {
+        // Other C1 controls are not followed by parameters, so they are not
+        // considered to be sequences.
+        match c1_fn(string)? {
+            x @ (CSI | DCS | OSC | PM | SOS | APC, _) => x,
+            _ => return None,
+        }
+    };
+
+    match kind {
+        CSI => {
+            let start = i;
+            while let Some(0x30..=0x3F) = unit(i) {
+                i += 1;
+            }
+            let parameters = start..i;
+            while let Some(0x20..=0x2F) = unit(i) {
+                i += 1;
+            }
+            if let Some(final_byte @ 0x40..=0x7E) = unit(i) {
+                let is_sgr = final_byte == u32::from(b'm')
+                    && i == parameters.end
+                    && parameters
+                        .clone()
+                        .all(|x| matches!(unit(x), Some(0x30..=0x3B)));
+                return Some(Sequence { len: i + 1, is_sgr });
+            }
+        }
+        DCS | OSC | PM | SOS | APC => {
+            while let Some(x) = unit(i) {
+                if kind == OSC && x == BEL {
+                    return Some(Sequence::new(i + 1));
+                } else if x == ESC && unit(i + 1) == Some(ST) {
+                    return Some(Sequence::new(i + 2));
+                } else if let Some((ST, len)) = c1_fn(&string[i..]) {
+                    return Some(Sequence::new(i + len));
+                }
+                i += 1;
+            }
+        }
+        _ => {}
+    }
+    Some(Sequence::new(i))
+}
+
+fn strip<T, F>(string: &[T], keep_sgr: bool, c1_fn: F) -> Vec<T>
+where
+    F: Fn(&[T]) -> Option<(u32, usize)>,
+    T: Copy + Into<u32>,
+{
+    let mut result = Vec::with_capacity(string.len());
+    let mut i = 0;
+    while i < string.len() {
+        if let Some(sequence) = parse(&string[i..], &c1_fn) {
+            let end = i + sequence.len;
+            if keep_sgr && sequence.is_sgr {
+                result.extend_from_slice(&string[i..end]);
+            }
+            i = end;
+        } else {
+            result.push(string[i]);
+            i += 1;
+        }
+    }
+    result
+}
+
+/// A value returned by [`strip_ansi`].
+pub struct StripAnsi<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    keep_sgr: bool,
+}
+
+impl<T> StripAnsi<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether Select Graphic Rendition sequences should be kept.
+    ///
+    /// These sequences, such as `\x1B[31m`, only change colors and other
+    /// text attributes, so they can be kept when writing to a terminal that
+    /// supports color. This option is disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn keep_sgr(mut self, keep_sgr: bool) -> Self {
+        self.keep_sgr = keep_sgr;
+        self
+    }
+}
+
+impl<T> ToBytes for StripAnsi<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let value = self.value.to_bytes();
+        ByteStr::from_vec(strip(value.0.as_bytes(), self.keep_sgr, c1_utf8))
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        let value = self.value.to_wide()?;
+        Some(WideStr(strip(&value.0, self.keep_sgr, c1_utf16)))
+    }
+}
+
+/// Wraps a value to remove its ANSI escape sequences.
+///
+/// Output captured from other programs often contains sequences that set
+/// colors or move the cursor, which should not be written to files or to
+/// terminals that do not support them. The returned struct implements
+/// [`ToBytes`] by removing all escape sequences and control strings defined
+/// by [ECMA-48], so it can be passed to functions such as [`write_lossy`].
+/// This includes:
+/// - control sequences (CSI), such as `\x1B[31m` and `\x1B[2J`,
+/// - operating system commands (OSC), such as `\x1B]0;title\x07`,
+/// - other control strings (DCS, SOS, PM, and APC),
+/// - other escape sequences, such as `\x1B(B` and `\x1B7`.
+///
+/// Sequences can also begin with a C1 control encoded as UTF-8, such as
+/// U+009B CONTROL SEQUENCE INTRODUCER. Sequences that are not terminated
+/// are removed until the first character that cannot be part of them, and
+/// control strings that are not terminated are removed until the end of
+/// the value. Other control characters are written unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::strip_ansi;
+/// use print_bytes::write_lossy;
+///
+/// let output = b"\x1B]0;title\x07\x1B[1;31merror\x1B[0m: \x1B[Kfailed";
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &strip_ansi(output))?;
+/// assert_eq!(b"error: failed", &*string);
+///
+/// string.clear();
+/// write_lossy(&mut string, &strip_ansi(output).keep_sgr(true))?;
+/// assert_eq!(b"\x1B[1;31merror\x1B[0m: failed", &*string);
+/// #
+/// # Ok::<_, std::io::Error>(())
+/// ```
+///
+/// [ECMA-48]: https://ecma-international.org/publications-and-standards/standards/ecma-48/
+/// [`write_lossy`]: super::write_lossy
+#[inline]
+#[must_use]
+pub fn strip_ansi<T>(value: &T) -> StripAnsi<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    StripAnsi {
+        value,
+        keep_sgr: false,
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(all(feature = "tokio", windows))]
+use std::io;
+
+#[cfg(all(feature = "tokio", windows))]
+use super::console::Console;
+
+// Wide character strings cannot be written to Windows Console
+// asynchronously, so output to it is always lossy. Only the standard streams
+// of tokio can write to the console, and they are recognized by their type,
+// since other writers cannot be inspected without specialization.
+#[cfg(feature = "tokio")]
+#[cfg_attr(not(windows), allow(clippy::extra_unused_type_parameters))]
+pub(super) fn is_tokio_console<W>() -> bool
+where
+    W: ?Sized,
+{
+    #[cfg(windows)]
+    {
+        use tokio::io::Stderr;
+        use tokio::io::Stdout;
+
+        let id = typeid::of::<W>();
+        if id == typeid::of::<Stdout>() || id == typeid::of::<&mut Stdout>() {
+            return Console::from_handle(&io::stdout()).is_some();
+        }
+        if id == typeid::of::<Stderr>() || id == typeid::of::<&mut Stderr>() {
+            return Console::from_handle(&io::stderr()).is_some();
+        }
+    }
+    false
+}
+
+macro_rules! impl_write_lossy_async {
+    ( $trait:path , $lossy:expr , $(#[ $attr:meta ])* ) => {
+        use std::future;
+        use std::io;
+        use std::pin::Pin;
+
+        use super::ToBytes;
+
+        #[inline]
+        $(#[$attr])*
+        pub async fn write_lossy_async<T, W>(
+            mut writer: W,
+            value: &T,
+        ) -> io::Result<()>
+        where
+            T: ?Sized + ToBytes,
+            W: $trait + Unpin,
+        {
+            let string = super::to_bytes_lossy(value, $lossy);
+            let mut string = &*string;
+            while !string.is_empty() {
+                let written_length = future::poll_fn(|cx| {
+                    let writer = Pin::new(&mut writer);
+                    <W as $trait>::poll_write(writer, cx, string)
+                })
+                .await?;
+                if written_length == 0 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ));
+                }
+                string = &string[written_length..];
+            }
+            Ok(())
+        }
+    };
+}
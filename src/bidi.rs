@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::chunks::Utf8Chunks;
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+// Returns whether the character has the Bidi_Control property.
+pub(super) fn is_bidi_control(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{61C}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}',
+    )
+}
+
+struct CharWriter<F>(F);
+
+impl<F> Write for CharWriter<F>
+where
+    F: FnMut(char),
+{
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        string.chars().for_each(&mut self.0);
+        Ok(())
+    }
+}
+
+fn escape<F>(ch: char, push_fn: F)
+where
+    F: FnMut(char),
+{
+    let _ = write!(CharWriter(push_fn), r"\u{{{:x}}}", u32::from(ch));
+}
+
+fn push_utf8(string: &mut Vec<u8>, ch: char) {
+    string.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+#[cfg(any(doc, windows))]
+fn push_utf16(string: &mut Vec<u16>, ch: char) {
+    string.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+}
+
+/// The policy used by [`bidi_safe`] for bidirectional formatting characters.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BidiPolicy {
+    /// Write each bidirectional formatting character as an escape, such as
+    /// `\u{202e}`.
+    ///
+    /// This policy is used by default when the print functions write to a
+    /// terminal.
+    #[default]
+    Escape,
+
+    /// Enclose the value in U+2068 FIRST STRONG ISOLATE and U+2069 POP
+    /// DIRECTIONAL ISOLATE, so that its formatting characters cannot affect
+    /// surrounding text.
+    ///
+    /// Characters within the value are written unchanged, so it will be
+    /// displayed as intended by its author. However, isolates that are
+    /// closed without being opened would end the enclosing isolate, so they
+    /// are escaped. Isolates that are not closed are closed at the end.
+    Isolate,
+
+    /// Write the value unchanged, but return a warning from
+    /// [`BidiSafe::check`] if it contains bidirectional formatting
+    /// characters.
+    Warn,
+}
+
+/// The warning returned by [`BidiSafe::check`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BidiWarning(char);
+
+impl BidiWarning {
+    /// Returns the first bidirectional formatting character in the value.
+    #[inline]
+    #[must_use]
+    pub const fn control(&self) -> char {
+        self.0
+    }
+}
+
+impl fmt::Display for BidiWarning {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value contains bidirectional formatting character U+{:04X}",
+            u32::from(self.0),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BidiWarning {}
+
+struct Neutralizer {
+    policy: BidiPolicy,
+    depth: usize,
+}
+
+impl Neutralizer {
+    const fn new(policy: BidiPolicy) -> Self {
+        Self { policy, depth: 0 }
+    }
+
+    fn escape(&mut self, ch: char) -> bool {
+        match self.policy {
+            BidiPolicy::Escape => is_bidi_control(ch),
+            BidiPolicy::Isolate => match ch {
+                '\u{2066}'..='\u{2068}' => {
+                    self.depth += 1;
+                    false
+                }
+                POP_DIRECTIONAL_ISOLATE => {
+                    let is_matched = self.depth != 0;
+                    self.depth = self.depth.saturating_sub(1);
+                    !is_matched
+                }
+                _ => false,
+            },
+            BidiPolicy::Warn => false,
+        }
+    }
+
+    // Returns whether the value would be written unchanged.
+    fn is_unchanged<I>(&self, mut chars: I) -> bool
+    where
+        I: Iterator<Item = char>,
+    {
+        match self.policy {
+            BidiPolicy::Escape => !chars.any(is_bidi_control),
+            BidiPolicy::Isolate => false,
+            BidiPolicy::Warn => true,
+        }
+    }
+
+    fn push_prefix<F>(&self, mut push_fn: F)
+    where
+        F: FnMut(char),
+    {
+        if self.policy == BidiPolicy::Isolate {
+            push_fn(FIRST_STRONG_ISOLATE);
+        }
+    }
+
+    fn push_suffix<F>(&self, mut push_fn: F)
+    where
+        F: FnMut(char),
+    {
+        if self.policy == BidiPolicy::Isolate {
+            for _ in 0..=self.depth {
+                push_fn(POP_DIRECTIONAL_ISOLATE);
+            }
+        }
+    }
+}
+
+/// A value returned by [`bidi_safe`].
+pub struct BidiSafe<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    policy: BidiPolicy,
+}
+
+impl<T> BidiSafe<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    /// Checks whether the value can be displayed safely.
+    ///
+    /// # Errors
+    ///
+    /// Returns a warning if [`BidiPolicy::Warn`] was requested and the value
+    /// contains a bidirectional formatting character. Other policies never
+    /// return a warning, since they neutralize these characters.
+    #[inline]
+    pub fn check(&self) -> Result<(), BidiWarning> {
+        if self.policy != BidiPolicy::Warn {
+            return Ok(());
+        }
+        chunks::decode(self.value, |chunk| match chunk {
+            Chunk::Str(string) => string
+                .chars()
+                .find(|&x| is_bidi_control(x))
+                .map_or(Ok(()), |x| Err(BidiWarning(x))),
+            Chunk::Invalid(_) => Ok(()),
+        })
+    }
+}
+
+impl<T> ToBytes for BidiSafe<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let mut neutralizer = Neutralizer::new(self.policy);
+        let value = self.value.to_bytes();
+        if neutralizer.is_unchanged(
+            Utf8Chunks(value.0.as_bytes()).flat_map(|(x, _)| x.chars()),
+        ) {
+            return value;
+        }
+        let value = value.0.as_bytes();
+
+        let mut string = Vec::with_capacity(value.len());
+        neutralizer.push_prefix(|x| push_utf8(&mut string, x));
+        for (valid, invalid) in Utf8Chunks(value) {
+            for ch in valid.chars() {
+                if neutralizer.escape(ch) {
+                    escape(ch, |x| push_utf8(&mut string, x));
+                } else {
+                    push_utf8(&mut string, ch);
+                }
+            }
+            string.extend_from_slice(invalid);
+        }
+        neutralizer.push_suffix(|x| push_utf8(&mut string, x));
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        let mut neutralizer = Neutralizer::new(self.policy);
+        let value = self.value.to_wide()?;
+        if neutralizer.is_unchanged(
+            char::decode_utf16(value.0.iter().copied()).filter_map(Result::ok),
+        ) {
+            return Some(value);
+        }
+
+        let mut string = Vec::with_capacity(value.0.len());
+        neutralizer.push_prefix(|x| push_utf16(&mut string, x));
+        for ch in char::decode_utf16(value.0) {
+            match ch {
+                Ok(ch) if neutralizer.escape(ch) => {
+                    escape(ch, |x| push_utf16(&mut string, x));
+                }
+                Ok(ch) => push_utf16(&mut string, ch),
+                Err(error) => string.push(error.unpaired_surrogate()),
+            }
+        }
+        neutralizer.push_suffix(|x| push_utf16(&mut string, x));
+        Some(WideStr(string))
+    }
+}
+
+/// Wraps a value to neutralize its bidirectional formatting characters.
+///
+/// Characters such as U+202E RIGHT-TO-LEFT OVERRIDE change the order in
+/// which text is displayed, so a path containing them can appear to have a
+/// different name or extension. This attack is known as [Trojan Source].
+///
+/// The returned struct implements [`ToBytes`] by handling characters with
+/// the Bidi_Control property according to the given policy. It can be
+/// passed to the print functions or [`write_lossy`]. Unlike
+/// [`Display::escape_invisible`], escapes are written without escaping
+/// backslashes, so the output may be ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::bidi_safe;
+/// use print_bytes::write_lossy;
+/// use print_bytes::BidiPolicy;
+///
+/// let file_name = "invoice\u{202E}fdp.exe".as_bytes();
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &bidi_safe(file_name, BidiPolicy::Escape))?;
+/// assert_eq!(br"invoice\u{202e}fdp.exe", &*string);
+///
+/// let value = bidi_safe(file_name, BidiPolicy::Warn);
+/// assert_eq!(Err('\u{202E}'), value.check().map_err(|x| x.control()));
+/// #
+/// # Ok::<_, std::io::Error>(())
+/// ```
+///
+/// [`Display::escape_invisible`]: super::Display::escape_invisible
+/// [Trojan Source]: https://trojansource.codes/
+/// [`write_lossy`]: super::write_lossy
+#[inline]
+#[must_use]
+pub fn bidi_safe<T>(value: &T, policy: BidiPolicy) -> BidiSafe<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    BidiSafe { value, policy }
+}
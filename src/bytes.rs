@@ -1,20 +1,42 @@
-use std::borrow::Cow;
-use std::ffi::CStr;
-use std::ffi::CString;
-use std::ops::Deref;
-
-#[cfg(windows)]
-type Bytes<'a> = &'a [u8];
-#[cfg(not(windows))]
-type Bytes<'a> = Cow<'a, [u8]>;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::ffi::CString;
+#[cfg(any(doc, windows))]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::ops::Deref;
 
 #[derive(Debug)]
 pub(super) enum ByteStrInner<'a> {
-    Bytes(Bytes<'a>),
+    Bytes(Cow<'a, [u8]>),
     #[cfg(windows)]
     Str(Cow<'a, str>),
 }
 
+impl<'a> ByteStrInner<'a> {
+    pub(super) fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Bytes(string) => string,
+            #[cfg(windows)]
+            Self::Str(string) => string.as_bytes(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(super) fn into_bytes(self) -> Cow<'a, [u8]> {
+        match self {
+            Self::Bytes(string) => string,
+            #[cfg(windows)]
+            Self::Str(Cow::Borrowed(string)) => {
+                Cow::Borrowed(string.as_bytes())
+            }
+            #[cfg(windows)]
+            Self::Str(Cow::Owned(string)) => Cow::Owned(string.into_bytes()),
+        }
+    }
+}
+
 /// A value returned by [`ToBytes::to_bytes`].
 ///
 /// This struct is usually initialized by calling the above method for
@@ -22,6 +44,12 @@ pub(super) enum ByteStrInner<'a> {
 #[derive(Debug)]
 pub struct ByteStr<'a>(pub(super) ByteStrInner<'a>);
 
+impl ByteStr<'_> {
+    pub(super) fn from_vec(string: Vec<u8>) -> Self {
+        Self(ByteStrInner::Bytes(Cow::Owned(string)))
+    }
+}
+
 #[cfg(any(doc, windows))]
 impl<'a> ByteStr<'a> {
     /// Wraps a byte string lossily.
@@ -84,9 +112,9 @@ impl WideStr {
 /// println_lossy(&ByteSlice(b"Hello, world!"));
 /// ```
 ///
-/// [`Display`]: ::std::fmt::Display
+/// [`Display`]: ::core::fmt::Display
 /// [`to_bytes`]: Self::to_bytes
-/// [`ToString`]: ::std::string::ToString
+/// [`ToString`]: ::alloc::string::ToString
 pub trait ToBytes {
     /// Returns a byte string that will be used to represent the instance.
     #[must_use]
@@ -109,10 +137,9 @@ pub trait ToBytes {
 }
 
 impl ToBytes for [u8] {
-    #[cfg_attr(windows, allow(clippy::useless_conversion))]
     #[inline]
     fn to_bytes(&self) -> ByteStr<'_> {
-        ByteStr(ByteStrInner::Bytes(self.into()))
+        ByteStr(ByteStrInner::Bytes(Cow::Borrowed(self)))
     }
 
     #[cfg(any(doc, windows))]
@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+fn push_caret(string: &mut Vec<u8>, mut byte: u8) {
+    if byte >= 0x80 {
+        string.extend_from_slice(b"M-");
+        byte -= 0x80;
+    }
+    match byte {
+        ..=0x1F => string.extend_from_slice(&[b'^', byte + 0x40]),
+        0x7F => string.extend_from_slice(b"^?"),
+        _ => string.push(byte),
+    }
+}
+
+/// A value returned by [`caret_notation`].
+pub struct CaretNotation<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    show_nonprinting: bool,
+    show_tabs: bool,
+    show_ends: bool,
+}
+
+impl<T> CaretNotation<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether control characters and non-ASCII bytes should be
+    /// displayed using `^` and `M-` notation, like the `-v` option of `cat`.
+    ///
+    /// Tabs and line feeds are not affected by this option. It is enabled by
+    /// default.
+    #[inline]
+    #[must_use]
+    pub fn show_nonprinting(mut self, show_nonprinting: bool) -> Self {
+        self.show_nonprinting = show_nonprinting;
+        self
+    }
+
+    /// Sets whether tabs should be displayed as `^I`, like the `-T` option of
+    /// `cat`.
+    ///
+    /// This option is disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn show_tabs(mut self, show_tabs: bool) -> Self {
+        self.show_tabs = show_tabs;
+        self
+    }
+
+    /// Sets whether `$` should be displayed at the end of each line, like the
+    /// `-E` option of `cat`.
+    ///
+    /// A carriage return before a line feed will also be displayed as `^M`.
+    /// This option is disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn show_ends(mut self, show_ends: bool) -> Self {
+        self.show_ends = show_ends;
+        self
+    }
+
+    /// Enables all options, like the `-A` option of `cat`.
+    #[inline]
+    #[must_use]
+    pub fn show_all(self) -> Self {
+        self.show_nonprinting(true).show_tabs(true).show_ends(true)
+    }
+}
+
+impl<T> ToBytes for CaretNotation<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let value = self.value.to_bytes();
+        let value = value.0.as_bytes();
+
+        let mut string = Vec::with_capacity(value.len());
+        for (i, &byte) in value.iter().enumerate() {
+            match byte {
+                b'\t' if self.show_tabs => string.extend_from_slice(b"^I"),
+                b'\n' if self.show_ends => string.extend_from_slice(b"$\n"),
+                b'\r'
+                    if self.show_ends && value.get(i + 1) == Some(&b'\n') =>
+                {
+                    string.extend_from_slice(b"^M");
+                }
+                b'\t' | b'\n' => string.push(byte),
+                _ if self.show_nonprinting => push_caret(&mut string, byte),
+                _ => string.push(byte),
+            }
+        }
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        None
+    }
+}
+
+/// Wraps a value to be displayed using caret notation, like the output of
+/// `cat -v`.
+///
+/// The returned struct implements [`ToBytes`] using the same algorithm as
+/// GNU `cat`, so it can be passed to functions such as [`println_lossy`]. By
+/// default, control characters other than tabs and line feeds are displayed
+/// as `^` followed by a character, such as `^A` for `\x01` and `^?` for
+/// `\x7F`. Bytes above `\x7F` are displayed as `M-` followed by the notation
+/// for the byte with its high bit cleared, so the output is always ASCII.
+///
+/// Options corresponding to the `-v`, `-T`, `-E`, and `-A` options of `cat`
+/// are available as methods of [`CaretNotation`]. When
+/// [`CaretNotation::show_nonprinting`] is disabled, bytes not affected by
+/// the other options are written unchanged. On Windows, the value is first
+/// converted using [`ToBytes::to_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::caret_notation;
+/// use print_bytes::write_lossy;
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &caret_notation(b"\x01\tfoo\xE9\n"))?;
+/// assert_eq!(b"^A\tfooM-i\n", &*string);
+///
+/// string.clear();
+/// let value = b"\x01\tfoo\xE9\n";
+/// write_lossy(&mut string, &caret_notation(value).show_all())?;
+/// assert_eq!(b"^A^IfooM-i$\n", &*string);
+/// #
+/// # Ok::<_, std::io::Error>(())
+/// ```
+///
+/// [`println_lossy`]: super::println_lossy
+#[inline]
+#[must_use]
+pub fn caret_notation<T>(value: &T) -> CaretNotation<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    CaretNotation {
+        value,
+        show_nonprinting: true,
+        show_tabs: false,
+        show_ends: false,
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(any(feature = "serde", feature = "tracing"))]
+use alloc::string::String;
+use core::str;
+
+use super::ToBytes;
+
+pub(super) struct Utf8Chunks<'a>(pub(super) &'a [u8]);
+
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = (&'a str, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let (valid, invalid) = match str::from_utf8(self.0) {
+            Ok(string) => {
+                self.0 = &[];
+                return Some((string, &[]));
+            }
+            Err(error) => {
+                let (valid, string) = self.0.split_at(error.valid_up_to());
+                let (invalid, string) =
+                    string.split_at(error.error_len().unwrap_or(string.len()));
+                self.0 = string;
+                (valid, invalid)
+            }
+        };
+        // SAFETY: This prefix was validated by the above call.
+        Some((unsafe { str::from_utf8_unchecked(valid) }, invalid))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Invalid<'a> {
+    // A maximal invalid subsequence, which [String::from_utf8_lossy] replaces
+    // with a single replacement character.
+    Bytes(&'a [u8]),
+    #[cfg(windows)]
+    Surrogate(u16),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Chunk<'a> {
+    Str(&'a str),
+    Invalid(Invalid<'a>),
+}
+
+// Decodes the most accurate representation of a value, passing each part to
+// the callback in order. Wide character strings are preferred, since they are
+// lossless for platform strings on Windows.
+pub(super) fn decode<T, E, F>(value: &T, mut push_fn: F) -> Result<(), E>
+where
+    T: ?Sized + ToBytes,
+    F: FnMut(Chunk<'_>) -> Result<(), E>,
+{
+    #[cfg(windows)]
+    if let Some(string) = value.to_wide() {
+        let mut buffer = [0; 4];
+        for ch in char::decode_utf16(string.0) {
+            push_fn(match ch {
+                Ok(ch) => Chunk::Str(ch.encode_utf8(&mut buffer)),
+                Err(error) => Chunk::Invalid(Invalid::Surrogate(
+                    error.unpaired_surrogate(),
+                )),
+            })?;
+        }
+        return Ok(());
+    }
+
+    let string = value.to_bytes().0;
+    for (valid, invalid) in Utf8Chunks(string.as_bytes()) {
+        if !valid.is_empty() {
+            push_fn(Chunk::Str(valid))?;
+        }
+        if !invalid.is_empty() {
+            push_fn(Chunk::Invalid(Invalid::Bytes(invalid)))?;
+        }
+    }
+    Ok(())
+}
+
+// Returns the value as a string, or [None] if it cannot be represented
+// losslessly.
+#[cfg(any(feature = "serde", feature = "tracing"))]
+pub(super) fn to_string<T>(value: &T) -> Option<String>
+where
+    T: ?Sized + ToBytes,
+{
+    let mut string = String::new();
+    decode(value, |chunk| match chunk {
+        Chunk::Str(chunk) => {
+            string.push_str(chunk);
+            Ok(())
+        }
+        Chunk::Invalid(_) => Err(()),
+    })
+    .ok()
+    .map(|()| string)
+}
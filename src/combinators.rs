@@ -0,0 +1,308 @@
+#[cfg(windows)]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+enum PartsInner<'a> {
+    Bytes(&'a mut Vec<u8>),
+    #[cfg(windows)]
+    Wide {
+        string: &'a mut Vec<u16>,
+        bytes: Vec<u8>,
+        is_wide: bool,
+    },
+}
+
+// Byte parts are decoded together, so that a character split between them
+// is not replaced.
+#[cfg(windows)]
+fn push_lossy(string: &mut Vec<u16>, bytes: &mut Vec<u8>) {
+    string.extend(String::from_utf8_lossy(bytes).encode_utf16());
+    bytes.clear();
+}
+
+/// A sequence of values being concatenated by [`from_fn`].
+///
+/// Values pushed to this struct will be represented as a single string, so
+/// that they can be written together.
+pub struct Parts<'a>(PartsInner<'a>);
+
+impl Parts<'_> {
+    fn to_bytes<F>(push_fn: F) -> ByteStr<'static>
+    where
+        F: FnOnce(&mut Parts<'_>),
+    {
+        let mut string = Vec::new();
+        push_fn(&mut Parts(PartsInner::Bytes(&mut string)));
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(windows)]
+    fn to_wide<F>(push_fn: F) -> Option<WideStr>
+    where
+        F: FnOnce(&mut Parts<'_>),
+    {
+        let mut string = Vec::new();
+        let mut parts = Parts(PartsInner::Wide {
+            string: &mut string,
+            bytes: Vec::new(),
+            is_wide: false,
+        });
+        push_fn(&mut parts);
+        let PartsInner::Wide {
+            string,
+            mut bytes,
+            is_wide,
+        } = parts.0
+        else {
+            unreachable!();
+        };
+        push_lossy(string, &mut bytes);
+        // Returning `None` when no part has a wide representation keeps the
+        // behavior of byte slices.
+        is_wide.then(|| WideStr::new(string))
+    }
+
+    /// Appends a value to the sequence.
+    #[inline]
+    pub fn push<T>(&mut self, value: &T)
+    where
+        T: ?Sized + ToBytes,
+    {
+        match &mut self.0 {
+            PartsInner::Bytes(string) => {
+                string.extend_from_slice(value.to_bytes().0.as_bytes());
+            }
+            #[cfg(windows)]
+            PartsInner::Wide {
+                string,
+                bytes,
+                is_wide,
+            } => {
+                if let Some(wide) = value.to_wide() {
+                    push_lossy(string, bytes);
+                    string.extend_from_slice(&wide.0);
+                    *is_wide = true;
+                } else {
+                    bytes.extend_from_slice(value.to_bytes().0.as_bytes());
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_to_bytes {
+    () => {
+        #[inline]
+        fn to_bytes(&self) -> ByteStr<'_> {
+            Parts::to_bytes(|parts| self.push_parts(parts))
+        }
+
+        #[cfg(any(doc, windows))]
+        #[inline]
+        fn to_wide(&self) -> Option<WideStr> {
+            Parts::to_wide(|parts| self.push_parts(parts))
+        }
+    };
+}
+
+/// A value returned by [`chain`].
+#[derive(Clone, Copy, Debug)]
+pub struct Chain<A, B>(A, B);
+
+impl<A, B> Chain<A, B>
+where
+    A: Deref,
+    A::Target: ToBytes,
+    B: Deref,
+    B::Target: ToBytes,
+{
+    fn push_parts(&self, parts: &mut Parts<'_>) {
+        parts.push(&*self.0);
+        parts.push(&*self.1);
+    }
+}
+
+impl<A, B> ToBytes for Chain<A, B>
+where
+    A: Deref,
+    A::Target: ToBytes,
+    B: Deref,
+    B::Target: ToBytes,
+{
+    impl_to_bytes!();
+}
+
+/// Concatenates two values.
+///
+/// Writing the returned value is equivalent to writing both values in order,
+/// except that they will be converted together. Thus, a character split
+/// between them will not be replaced when output is lossy.
+///
+/// Both values are usually references, but any type that dereferences to a
+/// value implementing [`ToBytes`] can be used, such as [`PathBuf`]. Values
+/// returned by this function can be chained by reference.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::chain;
+/// use print_bytes::write_lossy;
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &chain(b"foo/", b"bar.txt"))
+///     .expect("failed writing to vector");
+/// assert_eq!(b"foo/bar.txt", &*string);
+///
+/// string.clear();
+/// let path = chain(b"foo/", b"bar");
+/// write_lossy(&mut string, &chain(&path, b".txt"))
+///     .expect("failed writing to vector");
+/// assert_eq!(b"foo/bar.txt", &*string);
+/// ```
+///
+/// [`PathBuf`]: ::std::path::PathBuf
+#[inline]
+#[must_use]
+pub fn chain<A, B>(first: A, second: B) -> Chain<A, B>
+where
+    A: Deref,
+    A::Target: ToBytes,
+    B: Deref,
+    B::Target: ToBytes,
+{
+    Chain(first, second)
+}
+
+/// A value returned by [`join`].
+#[derive(Clone, Copy, Debug)]
+pub struct Join<I, S> {
+    iter: I,
+    separator: S,
+}
+
+impl<I, S> Join<I, S>
+where
+    I: Clone + IntoIterator,
+    I::Item: Deref,
+    <I::Item as Deref>::Target: ToBytes,
+    S: Deref,
+    S::Target: ToBytes,
+{
+    fn push_parts(&self, parts: &mut Parts<'_>) {
+        let mut iter = self.iter.clone().into_iter();
+        if let Some(value) = iter.next() {
+            parts.push(&*value);
+            for value in iter {
+                parts.push(&*self.separator);
+                parts.push(&*value);
+            }
+        }
+    }
+}
+
+impl<I, S> ToBytes for Join<I, S>
+where
+    I: Clone + IntoIterator,
+    I::Item: Deref,
+    <I::Item as Deref>::Target: ToBytes,
+    S: Deref,
+    S::Target: ToBytes,
+{
+    impl_to_bytes!();
+}
+
+/// Concatenates values, placing a separator between each pair.
+///
+/// The iterator is cloned each time the returned value is converted, so it
+/// will usually be a reference to a collection. As for [`chain`], the items
+/// and separator can be any type that dereferences to a value implementing
+/// [`ToBytes`].
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::join;
+/// use print_bytes::write_lossy;
+///
+/// let paths: [&[u8]; 3] = [b"/bin", b"/usr/bin", b"/usr/local/bin"];
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &join(paths, b":"))
+///     .expect("failed writing to vector");
+/// assert_eq!(b"/bin:/usr/bin:/usr/local/bin", &*string);
+/// ```
+#[inline]
+#[must_use]
+pub fn join<I, S>(iter: I, separator: S) -> Join<I, S>
+where
+    I: Clone + IntoIterator,
+    I::Item: Deref,
+    <I::Item as Deref>::Target: ToBytes,
+    S: Deref,
+    S::Target: ToBytes,
+{
+    Join { iter, separator }
+}
+
+/// A value returned by [`from_fn`].
+#[derive(Clone, Copy, Debug)]
+pub struct FromFn<F>(F);
+
+impl<F> FromFn<F>
+where
+    F: Fn(&mut Parts<'_>),
+{
+    fn push_parts(&self, parts: &mut Parts<'_>) {
+        (self.0)(parts);
+    }
+}
+
+impl<F> ToBytes for FromFn<F>
+where
+    F: Fn(&mut Parts<'_>),
+{
+    impl_to_bytes!();
+}
+
+/// Creates a value from a closure that pushes each of its parts.
+///
+/// The closure is called each time the returned value is converted.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::from_fn;
+/// use print_bytes::write_lossy;
+///
+/// let directory = b"foo";
+/// let file_name = b"bar";
+/// let extension = Some(b"txt");
+///
+/// let path = from_fn(|parts| {
+///     parts.push(directory);
+///     parts.push(b"/");
+///     parts.push(file_name);
+///     if let Some(extension) = extension {
+///         parts.push(b".");
+///         parts.push(extension);
+///     }
+/// });
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &path).expect("failed writing to vector");
+/// assert_eq!(b"foo/bar.txt", &*string);
+/// ```
+#[inline]
+#[must_use]
+pub fn from_fn<F>(push_fn: F) -> FromFn<F>
+where
+    F: Fn(&mut Parts<'_>),
+{
+    FromFn(push_fn)
+}
@@ -0,0 +1,172 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::fmt::Write;
+use std::process::Command;
+
+use super::chain;
+use super::shell;
+
+fn is_name(name: &OsStr) -> bool {
+    name.to_str().is_some_and(|name| {
+        name.starts_with(|x: char| x.is_ascii_alphabetic() || x == '_')
+            && name.chars().all(|x| x.is_ascii_alphanumeric() || x == '_')
+    })
+}
+
+// Words that are recognized as reserved words by POSIX, followed by those
+// that some shells also recognize, such as "time" in bash and zsh.
+const RESERVED_WORDS: &[&str] = &[
+    "!",
+    "{",
+    "}",
+    "case",
+    "do",
+    "done",
+    "elif",
+    "else",
+    "esac",
+    "fi",
+    "for",
+    "if",
+    "in",
+    "then",
+    "until",
+    "while",
+    "[[",
+    "]]",
+    "function",
+    "namespace",
+    "select",
+    "time",
+];
+
+fn is_reserved_word(name: &OsStr) -> bool {
+    name.to_str().is_some_and(|x| RESERVED_WORDS.contains(&x))
+}
+
+fn starts_with_dash(value: &OsStr) -> bool {
+    value.as_encoded_bytes().starts_with(b"-")
+}
+
+/// A value returned by [`command_line`].
+pub struct CommandLine<'a>(&'a Command);
+
+impl fmt::Display for CommandLine<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = self.0;
+
+        if let Some(directory) = command.get_current_dir() {
+            f.write_str("cd ")?;
+            // An operand starting with "-" would be parsed as an option, and
+            // "-" is interpreted as the previous directory even after "--".
+            if starts_with_dash(directory.as_os_str()) {
+                shell::write_sh(&mut *f, &chain(b"./", directory), false)?;
+            } else {
+                shell::write_sh(&mut *f, directory, false)?;
+            }
+            f.write_str(" && ")?;
+        }
+
+        // Assignments can only be used for variables with portable names.
+        let use_env = command
+            .get_envs()
+            .any(|(name, value)| value.is_none() || !is_name(name));
+        if use_env {
+            f.write_str("env ")?;
+            for (name, _) in command.get_envs().filter(|(_, x)| x.is_none()) {
+                f.write_str("-u ")?;
+                shell::write_sh(&mut *f, name, false)?;
+                f.write_char(' ')?;
+            }
+
+            let first_operand = command
+                .get_envs()
+                .find_map(|(name, value)| value.map(|_| name))
+                .unwrap_or_else(|| command.get_program());
+            if starts_with_dash(first_operand) {
+                f.write_str("-- ")?;
+            }
+        }
+        for (name, value) in command.get_envs() {
+            let Some(value) = value else {
+                continue;
+            };
+            if use_env {
+                shell::write_sh(
+                    &mut *f,
+                    &chain(&chain(name, b"="), value),
+                    false,
+                )?;
+            } else {
+                write!(f, "{}=", name.to_str().expect("invalid name"))?;
+                shell::write_sh(&mut *f, value, false)?;
+            }
+            f.write_char(' ')?;
+        }
+
+        // A program name containing "=" would be parsed as an assignment.
+        // Quoting prevents that for the shell, but not for `env`, so the
+        // program is run by another shell in that case.
+        let program = command.get_program();
+        let is_assignment = program.as_encoded_bytes().contains(&b'=');
+        if use_env && is_assignment {
+            f.write_str(r#"sh -c 'exec "$0" "$@"' "#)?;
+        }
+        // Reserved words are not recognized when quoted.
+        let always_quote = is_assignment || is_reserved_word(program);
+        shell::write_sh(&mut *f, program, always_quote)?;
+        for arg in command.get_args() {
+            f.write_char(' ')?;
+            shell::write_sh(&mut *f, arg, false)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a command to be displayed as a command line for a POSIX shell.
+///
+/// The returned struct implements [`fmt::Display`] by quoting each part of
+/// the command using the same rules as [`shell_quote`], so the output can be
+/// copied into a shell to run the same command. It includes:
+/// - the working directory, as a preceding `cd` command,
+/// - environment variables that were set or removed, as assignments or
+///   arguments to `env`,
+/// - the program and its arguments.
+///
+/// Changes made to the environment by [`Command::env_clear`] are not
+/// visible, so they will not be displayed.
+///
+/// Removed variables are passed to the `-u` option of `env`, which is not
+/// defined by POSIX but is supported by common implementations, such as
+/// those of GNU, BSD, and BusyBox. When `env` is used, a program name
+/// containing `=` is run using `sh -c`, since `env` would otherwise parse
+/// it as an assignment. Operands starting with `-` are separated from
+/// options using `--` for `env` and prefixed with `./` for `cd`. Program
+/// names that are shell reserved words, such as `if` or `time`, are quoted,
+/// so that the shell runs the program instead of interpreting the word.
+///
+/// # Examples
+///
+/// ```
+/// use std::process::Command;
+///
+/// use print_bytes::command_line;
+///
+/// let mut command = Command::new("grep");
+/// let _ = command
+///     .args(["-r", "foo bar", "src"])
+///     .current_dir("/tmp/my project")
+///     .env("LC_ALL", "C");
+/// assert_eq!(
+///     "cd '/tmp/my project' && LC_ALL=C grep -r 'foo bar' src",
+///     command_line(&command).to_string(),
+/// );
+/// ```
+///
+/// [`shell_quote`]: super::shell_quote
+#[inline]
+#[must_use]
+pub fn command_line(command: &Command) -> CommandLine<'_> {
+    CommandLine(command)
+}
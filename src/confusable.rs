@@ -0,0 +1,243 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::unicode;
+use super::unicode::SCRIPTS;
+use super::ToBytes;
+
+type ScriptSet = [u64; 4];
+
+// These scripts are only used to augment script sets, so they are never
+// reported.
+const AUGMENTED_SCRIPTS: [&str; 3] = ["Hanb", "Jpan", "Kore"];
+
+fn scripts(set: ScriptSet) -> impl Iterator<Item = Script> {
+    (0..SCRIPTS.len())
+        .filter(move |&x| set[x / 64] & (1 << (x % 64)) != 0)
+        .filter(|&x| !AUGMENTED_SCRIPTS.contains(&SCRIPTS[x].0))
+        .map(|x| Script(x.try_into().expect("invalid script index")))
+}
+
+/// A script, as defined by [Unicode Standard Annex #24].
+///
+/// [Unicode Standard Annex #24]: https://www.unicode.org/reports/tr24/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Script(u8);
+
+impl Script {
+    /// Returns the ISO 15924 code of this script, such as `"Cyrl"`.
+    #[inline]
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        SCRIPTS[usize::from(self.0)].0
+    }
+
+    /// Returns the name of this script, such as `"Cyrillic"`.
+    #[inline]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        SCRIPTS[usize::from(self.0)].1
+    }
+}
+
+impl fmt::Display for Script {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A character reported by [`check_confusables`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SuspiciousChar {
+    ch: char,
+    prototype: Option<&'static str>,
+    is_mixed_script: bool,
+}
+
+impl SuspiciousChar {
+    /// Returns the reported character.
+    #[inline]
+    #[must_use]
+    pub const fn ch(&self) -> char {
+        self.ch
+    }
+
+    /// Returns the ASCII string that this character can be confused with, if
+    /// any.
+    #[inline]
+    #[must_use]
+    pub const fn prototype(&self) -> Option<&'static str> {
+        self.prototype
+    }
+
+    /// Returns whether this character does not belong to the main script of
+    /// a value that mixes scripts.
+    #[inline]
+    #[must_use]
+    pub const fn is_mixed_script(&self) -> bool {
+        self.is_mixed_script
+    }
+}
+
+/// The report returned by [`check_confusables`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfusableReport {
+    scripts: Vec<Script>,
+    chars: Vec<SuspiciousChar>,
+    is_mixed_script: bool,
+}
+
+impl ConfusableReport {
+    /// Returns the scripts of the characters in the value, in sorted order.
+    ///
+    /// Only characters that belong to a single script are considered, so
+    /// characters such as digits and punctuation, which are shared by many
+    /// scripts, do not affect this list.
+    #[inline]
+    #[must_use]
+    pub fn scripts(&self) -> &[Script] {
+        &self.scripts
+    }
+
+    /// Returns each suspicious character in the value once, in the order
+    /// that they first appear.
+    #[inline]
+    #[must_use]
+    pub fn chars(&self) -> &[SuspiciousChar] {
+        &self.chars
+    }
+
+    /// Returns whether the value mixes scripts, according to the definition
+    /// of a single-script string in Unicode Technical Standard #39.
+    ///
+    /// Characters of Japanese and Korean writing systems are allowed to be
+    /// mixed, since the standard augments their script sets.
+    #[inline]
+    #[must_use]
+    pub fn is_mixed_script(&self) -> bool {
+        self.is_mixed_script
+    }
+
+    /// Returns whether any suspicious characters were found.
+    #[inline]
+    #[must_use]
+    pub fn is_suspicious(&self) -> bool {
+        !self.chars.is_empty()
+    }
+
+    pub(super) fn contains(&self, ch: char) -> bool {
+        self.chars.iter().any(|x| x.ch == ch)
+    }
+}
+
+/// Checks a value for characters that could be used to imitate other text.
+///
+/// This function implements two checks from [Unicode Technical Standard
+/// #39]:
+/// - A value mixes scripts when its resolved script set is empty. In that
+///   case, the script shared by the most characters is considered the main
+///   script, and characters that do not belong to it are reported.
+/// - Characters are confusable when they look like an ASCII string, such as
+///   U+0430 CYRILLIC SMALL LETTER A, which looks like `a`. They are reported
+///   even when the value does not mix scripts, since a value written
+///   entirely in another script can still imitate ASCII text.
+///
+/// Confusable characters are detected using each mapping from
+/// confusables.txt whose source is not ASCII and whose prototype is ASCII,
+/// such as U+2215 DIVISION SLASH, which looks like `/`. Other mappings are
+/// not included. Scripts and mappings are taken from Unicode 16.0.0.
+///
+/// Sequences that are not valid UTF-8 are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::check_confusables;
+///
+/// let report = check_confusables("p\u{430}ypal".as_bytes());
+/// assert!(report.is_mixed_script());
+///
+/// let ch = report.chars()[0];
+/// assert_eq!('\u{430}', ch.ch());
+/// assert_eq!(Some("a"), ch.prototype());
+/// assert!(ch.is_mixed_script());
+///
+/// assert!(!check_confusables(b"paypal").is_suspicious());
+/// ```
+///
+/// [Unicode Technical Standard #39]: https://www.unicode.org/reports/tr39/
+#[inline]
+#[must_use]
+pub fn check_confusables<T>(value: &T) -> ConfusableReport
+where
+    T: ?Sized + ToBytes,
+{
+    let mut chars = Vec::new();
+    let _ = chunks::decode(value, |chunk| {
+        if let Chunk::Str(string) = chunk {
+            chars.extend(string.chars());
+        }
+        Ok::<_, ()>(())
+    });
+
+    let mut resolved = [u64::MAX; 4];
+    let mut counts = [0_usize; SCRIPTS.len()];
+    let mut scripts_found = Vec::new();
+    for &ch in &chars {
+        let set = unicode::script_set(ch);
+        for (resolved, set) in resolved.iter_mut().zip(set) {
+            *resolved &= set;
+        }
+        if set == [u64::MAX; 4] {
+            continue;
+        }
+        let mut scripts = scripts(set).peekable();
+        if let Some(script) = scripts.next() {
+            if scripts.peek().is_none() && !scripts_found.contains(&script) {
+                scripts_found.push(script);
+            }
+        }
+        for (i, count) in counts.iter_mut().enumerate() {
+            if set[i / 64] & (1 << (i % 64)) != 0 {
+                *count += 1;
+            }
+        }
+    }
+    scripts_found.sort_unstable();
+
+    let is_mixed_script = resolved == [0; 4];
+    let main_script = counts
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, &x)| x)
+        .map(|(i, _)| i);
+
+    let mut suspicious_chars = Vec::<SuspiciousChar>::new();
+    for ch in chars {
+        if suspicious_chars.iter().any(|x| x.ch == ch) {
+            continue;
+        }
+        let prototype = unicode::confusable_prototype(ch);
+        let is_mixed_script = is_mixed_script
+            && main_script.is_some_and(|x| {
+                unicode::script_set(ch)[x / 64] & (1 << (x % 64)) == 0
+            });
+        if prototype.is_some() || is_mixed_script {
+            suspicious_chars.push(SuspiciousChar {
+                ch,
+                prototype,
+                is_mixed_script,
+            });
+        }
+    }
+
+    ConfusableReport {
+        scripts: scripts_found,
+        chars: suspicious_chars,
+        is_mixed_script,
+    }
+}
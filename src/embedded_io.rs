@@ -0,0 +1,45 @@
+//! Functions for writing to writers from the [embedded-io] crate.
+//!
+//! These functions do not require the "std" feature, so they can be used in
+//! `no_std` environments.
+//!
+//! [embedded-io]: https://crates.io/crates/embedded-io
+
+use ::embedded_io::Write;
+
+use super::ToBytes;
+
+/// Writes a value to an [`embedded_io::Write`] "writer".
+///
+/// This function is similar to [`write_lossy`], but writers from the
+/// [embedded-io] crate are never known to require Unicode. Thus, the bytes
+/// returned by [`ToBytes::to_bytes`] are always written unchanged, and
+/// borrowed values are written without allocating.
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::embedded_io::write_lossy;
+///
+/// let mut buffer = [0; 16];
+/// let mut writer = &mut buffer[..];
+/// write_lossy(&mut writer, b"foo\x80bar").expect("buffer is too small");
+/// let written_length = 16 - writer.len();
+/// assert_eq!(b"foo\x80bar", &buffer[..written_length]);
+/// ```
+///
+/// [embedded-io]: https://crates.io/crates/embedded-io
+/// [`embedded_io::Write`]: ::embedded_io::Write
+/// [`write_lossy`]: super::write_lossy
+#[inline]
+pub fn write_lossy<T, W>(mut writer: W, value: &T) -> Result<(), W::Error>
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    writer.write_all(value.to_bytes().0.as_bytes())
+}
@@ -0,0 +1,343 @@
+#[cfg(any(feature = "serde", feature = "tracing"))]
+use alloc::string::String;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::fmt::Write;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::chunks::Invalid;
+use super::confusable;
+use super::unicode;
+use super::ToBytes;
+
+/// Writes a value to a [`fmt::Write`] "writer".
+///
+/// Implementations of [`fmt::Write`] can only accept Unicode, so any
+/// character sequence that cannot be represented will be replaced with
+/// [`REPLACEMENT_CHARACTER`]. This function can also be used to write to a
+/// [`fmt::Formatter`].
+///
+/// For more information, see [the module-level documentation][module].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::write_fmt_lossy;
+///
+/// let mut string = String::new();
+/// write_fmt_lossy(&mut string, b"foo\x80bar")
+///     .expect("failed writing to string");
+/// assert_eq!("foo\u{FFFD}bar", string);
+/// ```
+///
+/// [module]: super
+#[inline]
+pub fn write_fmt_lossy<T, W>(mut writer: W, value: &T) -> fmt::Result
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    chunks::decode(value, |chunk| match chunk {
+        Chunk::Str(string) => writer.write_str(string),
+        Chunk::Invalid(_) => writer.write_char(REPLACEMENT_CHARACTER),
+    })
+}
+
+/// Writes a value to a [`fmt::Write`] "writer", escaping sequences that
+/// cannot be represented.
+///
+/// This function is similar to [`write_fmt_lossy`], but it writes invalid
+/// bytes as `\xNN` and unpaired surrogates as `\u{NNNN}`, using lowercase
+/// hexadecimal digits. Backslashes are written as `\\`, so the original
+/// string can always be recovered from the output.
+///
+/// For more information, see [the module-level documentation][module].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::write_fmt_escaped;
+///
+/// let mut string = String::new();
+/// write_fmt_escaped(&mut string, b"foo\\\x80bar")
+///     .expect("failed writing to string");
+/// assert_eq!(r"foo\\\x80bar", string);
+/// ```
+///
+/// [module]: super
+#[inline]
+pub fn write_fmt_escaped<T, W>(writer: W, value: &T) -> fmt::Result
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    write_escaped(writer, value, |_| false)
+}
+
+fn write_escaped<F, T, W>(
+    mut writer: W,
+    value: &T,
+    mut escape_fn: F,
+) -> fmt::Result
+where
+    F: FnMut(char) -> bool,
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    chunks::decode(value, |chunk| match chunk {
+        Chunk::Str(string) => {
+            let mut start = 0;
+            for (i, ch) in string.char_indices() {
+                if ch == '\\' {
+                    writer.write_str(&string[start..i])?;
+                    writer.write_str(r"\\")?;
+                } else if escape_fn(ch) {
+                    writer.write_str(&string[start..i])?;
+                    write!(writer, r"\u{{{:x}}}", u32::from(ch))?;
+                } else {
+                    continue;
+                }
+                start = i + ch.len_utf8();
+            }
+            writer.write_str(&string[start..])
+        }
+        Chunk::Invalid(Invalid::Bytes(string)) => string
+            .iter()
+            .try_for_each(|x| write!(writer, r"\x{:02x}", x)),
+        #[cfg(windows)]
+        Chunk::Invalid(Invalid::Surrogate(surrogate)) => {
+            write!(writer, r"\u{{{:x}}}", surrogate)
+        }
+    })
+}
+
+#[cfg(any(feature = "serde", feature = "tracing"))]
+pub(super) fn to_escaped_string<T>(value: &T) -> String
+where
+    T: ?Sized + ToBytes,
+{
+    let mut string = String::new();
+    write_fmt_escaped(&mut string, value).expect("failed writing to string");
+    string
+}
+
+/// A value returned by [`display`].
+///
+/// This struct implements [`fmt::Display`] by calling [`write_fmt_escaped`],
+/// so it can be used as a format argument.
+#[derive(Debug)]
+pub struct Display<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    escape_invisible: bool,
+    escape_confusables: bool,
+}
+
+// Deriving these traits would require the wrapped type to implement them.
+impl<T> Clone for Display<'_, T>
+where
+    T: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Display<'_, T> where T: ?Sized {}
+
+impl<T> Display<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether characters that are unlikely to be visible should be
+    /// escaped.
+    ///
+    /// When this option is enabled, characters are written as `\u{NNNN}`
+    /// escapes if they are in one of the following [general categories]:
+    /// - control characters (`Cc`), such as tabs and line feeds,
+    /// - format characters (`Cf`), such as U+200B ZERO WIDTH SPACE,
+    /// - private-use characters (`Co`),
+    /// - unassigned code points (`Cn`),
+    /// - separators (`Zs`, `Zl`, and `Zp`), such as U+00A0 NO-BREAK SPACE,
+    ///   except for U+0020 SPACE.
+    ///
+    /// Other characters that are ignored by default, such as variation
+    /// selectors, are also escaped. Since backslashes are already escaped,
+    /// the original string can still be recovered from the output.
+    ///
+    /// Categories are assigned using Unicode 16.0.0, so characters added by
+    /// later versions will be escaped as unassigned. This option is disabled
+    /// by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use print_bytes::display;
+    ///
+    /// let file_name = "foo\u{200B}\u{A0}.txt".as_bytes();
+    /// assert_eq!(
+    ///     r"foo\u{200b}\u{a0}.txt",
+    ///     display(file_name).escape_invisible(true).to_string(),
+    /// );
+    /// ```
+    ///
+    /// [general categories]: https://www.unicode.org/reports/tr44/#General_Category_Values
+    #[inline]
+    #[must_use]
+    pub fn escape_invisible(mut self, escape_invisible: bool) -> Self {
+        self.escape_invisible = escape_invisible;
+        self
+    }
+
+    /// Sets whether characters reported by [`check_confusables`] should be
+    /// escaped.
+    ///
+    /// When this option is enabled, characters that look like ASCII and
+    /// characters that do not belong to the main script of a value that
+    /// mixes scripts are written as `\u{NNNN}` escapes, which makes them
+    /// stand out from the surrounding text. This option is disabled by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use print_bytes::display;
+    ///
+    /// let host = "p\u{430}ypal.com".as_bytes();
+    /// assert_eq!(
+    ///     r"p\u{430}ypal.com",
+    ///     display(host).escape_confusables(true).to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`check_confusables`]: super::check_confusables
+    #[inline]
+    #[must_use]
+    pub fn escape_confusables(mut self, escape_confusables: bool) -> Self {
+        self.escape_confusables = escape_confusables;
+        self
+    }
+}
+
+impl<T> fmt::Display for Display<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let report = self
+            .escape_confusables
+            .then(|| confusable::check_confusables(self.value));
+        write_escaped(f, self.value, |ch| {
+            (self.escape_invisible && unicode::is_invisible(ch))
+                || report.as_ref().is_some_and(|x| x.contains(ch))
+        })
+    }
+}
+
+/// Wraps a value to be displayed using an escaped representation.
+///
+/// The returned struct implements [`fmt::Display`] using the same
+/// representation as [`write_fmt_escaped`]. Valid UTF-8 is displayed
+/// unchanged, except for backslashes, and the original string can always be
+/// recovered from the output.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::display;
+///
+/// let file_name = b"foo\x80.txt";
+/// assert_eq!(
+///     r"skipping foo\x80.txt",
+///     format!("skipping {}", display(file_name)),
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn display<T>(value: &T) -> Display<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    Display {
+        value,
+        escape_invisible: false,
+        escape_confusables: false,
+    }
+}
+
+#[cfg(feature = "log")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "log")))]
+mod log {
+    use log::kv::ToValue;
+    use log::kv::Value;
+
+    use super::Display;
+    use super::ToBytes;
+
+    /// When the `serde` feature is enabled, values are captured using their
+    /// [`Serialize`] implementation, so loggers that support serde receive
+    /// the lossless representation. Otherwise, values are captured using
+    /// their [`fmt::Display`] implementation, so loggers only receive the
+    /// representation written by [`write_fmt_escaped`], and the original
+    /// bytes cannot be recovered when it contains escapes.
+    ///
+    /// [`fmt::Display`]: ::core::fmt::Display
+    /// [`Serialize`]: ::serde::Serialize
+    /// [`write_fmt_escaped`]: super::super::write_fmt_escaped
+    impl<T> ToValue for Display<'_, T>
+    where
+        T: ?Sized + ToBytes,
+    {
+        #[inline]
+        fn to_value(&self) -> Value<'_> {
+            #[cfg(feature = "serde")]
+            {
+                Value::from_serde(self)
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                Value::from_display(self)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "serde")))]
+mod serde {
+    use serde::Serialize;
+    use serde::Serializer;
+
+    use super::Display;
+    use super::ToBytes;
+
+    /// Values are serialized losslessly by [`serde::serialize`], so the
+    /// escaping options of this struct are ignored.
+    ///
+    /// [`serde::serialize`]: super::super::serde::serialize
+    impl<T> Serialize for Display<'_, T>
+    where
+        T: ?Sized + ToBytes,
+    {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            super::super::serde::serialize(self.value, serializer)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Functions for writing to asynchronous writers from the [futures-io] crate.
+//!
+//! [futures-io]: https://crates.io/crates/futures-io
+
+impl_write_lossy_async!(
+    ::futures_io::AsyncWrite,
+    false,
+    /// Writes a value to an asynchronous "writer".
+    ///
+    /// This function is similar to [`write_lossy`] but accepts
+    /// implementations of [`AsyncWrite`]. The same representation of the
+    /// value will be written, including to writers that might be connected
+    /// to Windows Console.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use print_bytes::futures_io::write_lossy_async;
+    ///
+    /// # async fn write() -> std::io::Result<()> {
+    /// let mut string = Vec::new();
+    /// write_lossy_async(&mut string, b"foo\x80bar").await?;
+    /// assert_eq!(b"foo\x80bar", &*string);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AsyncWrite`]: ::futures_io::AsyncWrite
+    /// [`write_lossy`]: super::write_lossy
+);
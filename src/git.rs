@@ -0,0 +1,237 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+// Returns the escape for a byte, or `Some(None)` if it must be written as an
+// octal escape.
+fn escape(byte: u8, quote_path: bool) -> Option<Option<u8>> {
+    Some(Some(match byte {
+        b'\x07' => b'a',
+        b'\x08' => b'b',
+        b'\t' => b't',
+        b'\n' => b'n',
+        b'\x0B' => b'v',
+        b'\x0C' => b'f',
+        b'\r' => b'r',
+        b'"' | b'\\' => byte,
+        ..=b'\x1F' | b'\x7F' => return Some(None),
+        0x80.. if quote_path => return Some(None),
+        _ => return None,
+    }))
+}
+
+/// A value returned by [`git_quote`].
+pub struct GitQuote<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    quote_path: bool,
+    quote_spaces: bool,
+}
+
+impl<T> GitQuote<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether bytes of non-ASCII characters should be escaped, like
+    /// the `core.quotePath` option of Git.
+    ///
+    /// This option is enabled by default, which is also the default for Git.
+    #[inline]
+    #[must_use]
+    pub fn quote_path(mut self, quote_path: bool) -> Self {
+        self.quote_path = quote_path;
+        self
+    }
+
+    /// Sets whether values containing spaces should be quoted, even when
+    /// they contain no characters that need to be escaped.
+    ///
+    /// Git uses this behavior for the output of `git status`, but not for
+    /// most other commands. This option is disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn quote_spaces(mut self, quote_spaces: bool) -> Self {
+        self.quote_spaces = quote_spaces;
+        self
+    }
+}
+
+impl<T> ToBytes for GitQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let value = self.value.to_bytes();
+        let value = value.0.as_bytes();
+
+        let needs_quotes =
+            value.iter().any(|&x| escape(x, self.quote_path).is_some())
+                || (self.quote_spaces && value.contains(&b' '));
+        if !needs_quotes {
+            return ByteStr::from_vec(value.to_vec());
+        }
+
+        let mut string = Vec::with_capacity(value.len() + 2);
+        string.push(b'"');
+        for &byte in value {
+            match escape(byte, self.quote_path) {
+                Some(Some(escape)) => {
+                    string.extend_from_slice(&[b'\\', escape])
+                }
+                Some(None) => string.extend_from_slice(&[
+                    b'\\',
+                    b'0' + (byte >> 6),
+                    b'0' + ((byte >> 3) & 7),
+                    b'0' + (byte & 7),
+                ]),
+                None => string.push(byte),
+            }
+        }
+        string.push(b'"');
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        None
+    }
+}
+
+/// Wraps a value to be quoted like a path displayed by Git.
+///
+/// The returned struct implements [`ToBytes`] using the algorithm of Git's
+/// `quote_c_style` function. Values are only enclosed in double quotes when
+/// they contain a byte that must be escaped. Control characters and
+/// non-ASCII bytes are written as octal escapes, except for those with
+/// C escapes, such as `\n`.
+///
+/// Non-ASCII bytes are not escaped when [`GitQuote::quote_path`] is
+/// disabled, so the output may not be valid UTF-8. On Windows, the value is
+/// first converted using [`ToBytes::to_bytes`].
+///
+/// [`git_unquote`] can be used to recover the original bytes.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::git_quote;
+/// use print_bytes::write_lossy;
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &git_quote("caf\u{E9}\n.txt".as_bytes()))?;
+/// assert_eq!(br#""caf\303\251\n.txt""#, &*string);
+///
+/// string.clear();
+/// write_lossy(&mut string, &git_quote(b"foo bar.txt"))?;
+/// assert_eq!(b"foo bar.txt", &*string);
+/// #
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[inline]
+#[must_use]
+pub fn git_quote<T>(value: &T) -> GitQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    GitQuote {
+        value,
+        quote_path: true,
+        quote_spaces: false,
+    }
+}
+
+/// The error returned by [`git_unquote`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GitUnquoteError(());
+
+impl fmt::Display for GitUnquoteError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid quoted path")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GitUnquoteError {}
+
+/// Reverses the quoting performed by Git for a path.
+///
+/// Values that do not start with a double quote are returned unchanged,
+/// since Git does not quote them. Otherwise, the entire value must be a
+/// quoted string, as written by [`git_quote`] or Git's `quote_c_style`
+/// function. This function accepts the same escapes as Git's
+/// `unquote_c_style` function.
+///
+/// # Errors
+///
+/// Returns an error if the value starts with a double quote but is not a
+/// valid quoted string.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::git_unquote;
+///
+/// assert_eq!(b"foo bar", &*git_unquote(b"foo bar")?);
+/// assert_eq!(
+///     "caf\u{E9}\n".as_bytes(),
+///     &*git_unquote(br#""caf\303\251\n""#)?,
+/// );
+/// assert!(git_unquote(br#""foo"#).is_err());
+/// #
+/// # Ok::<_, print_bytes::GitUnquoteError>(())
+/// ```
+#[inline]
+pub fn git_unquote(string: &[u8]) -> Result<Cow<'_, [u8]>, GitUnquoteError> {
+    let Some(string) = string.strip_prefix(b"\"") else {
+        return Ok(Cow::Borrowed(string));
+    };
+    let string = string.strip_suffix(b"\"").ok_or(GitUnquoteError(()))?;
+
+    let mut result = Vec::with_capacity(string.len());
+    let mut bytes = string.iter().copied();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'"' => return Err(GitUnquoteError(())),
+            b'\\' => {}
+            _ => {
+                result.push(byte);
+                continue;
+            }
+        }
+        let byte = match bytes.next().ok_or(GitUnquoteError(()))? {
+            b'a' => b'\x07',
+            b'b' => b'\x08',
+            b'f' => b'\x0C',
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => b'\x0B',
+            byte @ (b'"' | b'\\') => byte,
+            byte @ b'0'..=b'3' => {
+                let mut code = byte - b'0';
+                for _ in 0..2 {
+                    match bytes.next() {
+                        Some(byte @ b'0'..=b'7') => {
+                            code = (code << 3) | (byte - b'0');
+                        }
+                        _ => return Err(GitUnquoteError(())),
+                    }
+                }
+                code
+            }
+            _ => return Err(GitUnquoteError(())),
+        };
+        result.push(byte);
+    }
+    Ok(Cow::Owned(result))
+}
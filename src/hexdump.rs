@@ -0,0 +1,215 @@
+use core::fmt;
+use core::fmt::Write;
+
+use super::chunks::Utf8Chunks;
+use super::display;
+use super::ToBytes;
+
+/// The layouts supported by [`hexdump`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum HexdumpStyle {
+    /// The layout used by `hexdump -C`:
+    ///
+    /// ```text
+    /// 00000000  66 6f 6f 80 0a                                    |foo..|
+    /// 00000005
+    /// ```
+    ///
+    /// Bytes are separated by spaces, and groups are separated by an
+    /// additional space. The default width is 16 bytes, with groups of 8.
+    Canonical,
+
+    /// The layout used by `xxd`:
+    ///
+    /// ```text
+    /// 00000000: 666f 6f80 0a                             foo..
+    /// ```
+    ///
+    /// Bytes within a group are not separated, and groups are separated by a
+    /// space. The default width is 16 bytes, with groups of 2.
+    Xxd,
+}
+
+/// A value returned by [`hexdump`].
+pub struct Hexdump<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    style: HexdumpStyle,
+    width: usize,
+    group_size: usize,
+    threshold: Option<f64>,
+}
+
+impl<T> Hexdump<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets the number of bytes displayed on each line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is zero.
+    #[inline]
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        assert_ne!(0, width, "width is zero");
+        self.width = width;
+        self
+    }
+
+    /// Sets the number of bytes in each group on a line.
+    ///
+    /// A group size of zero disables grouping.
+    #[inline]
+    #[must_use]
+    pub fn group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets the proportion of suspicious bytes above which the value will be
+    /// displayed as a hexdump.
+    ///
+    /// When this option is set, bytes that are not valid UTF-8 or that are
+    /// part of control characters are considered suspicious. If the
+    /// proportion of these bytes is at most the threshold, the value will
+    /// instead be displayed on a single line using the same representation
+    /// as [`display`] with [`Display::escape_invisible`] enabled, so control
+    /// characters and bidirectional formatting characters are still
+    /// escaped. For example, a threshold of `0.25` only displays a hexdump
+    /// when more than a quarter of the bytes are suspicious, and a threshold
+    /// of `0.0` displays one when any byte is.
+    ///
+    /// By default, a hexdump is always displayed.
+    ///
+    /// [`Display::escape_invisible`]: super::Display::escape_invisible
+    #[inline]
+    #[must_use]
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+fn is_suspicious(string: &[u8], threshold: f64) -> bool {
+    let mut count = 0;
+    for (valid, invalid) in Utf8Chunks(string) {
+        count += invalid.len();
+        count += valid
+            .chars()
+            .filter(|x| x.is_control())
+            .map(char::len_utf8)
+            .sum::<usize>();
+    }
+    let proportion = count as f64 / string.len() as f64;
+    proportion > threshold
+}
+
+impl<T> fmt::Display for Hexdump<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = self.value.to_bytes();
+        let string = string.0.as_bytes();
+
+        if let Some(threshold) = self.threshold {
+            if string.is_empty() || !is_suspicious(string, threshold) {
+                let value = display(self.value).escape_invisible(true);
+                return fmt::Display::fmt(&value, f);
+            }
+        }
+
+        let (offset_separator, byte_separator, gutter_start, gutter_end) =
+            match self.style {
+                HexdumpStyle::Canonical => ("  ", " ", "  |", "|"),
+                HexdumpStyle::Xxd => (": ", "", "  ", ""),
+            };
+        for (i, line) in string.chunks(self.width).enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+            write!(f, "{:08x}{}", i * self.width, offset_separator)?;
+            for j in 0..self.width {
+                if j != 0 {
+                    if self.group_size != 0 && j % self.group_size == 0 {
+                        f.write_char(' ')?;
+                    }
+                    f.write_str(byte_separator)?;
+                }
+                if let Some(byte) = line.get(j) {
+                    write!(f, "{:02x}", byte)?;
+                } else {
+                    f.write_str("  ")?;
+                }
+            }
+            f.write_str(gutter_start)?;
+            for &byte in line {
+                f.write_char(if matches!(byte, b' '..=b'~') {
+                    byte.into()
+                } else {
+                    '.'
+                })?;
+            }
+            f.write_str(gutter_end)?;
+        }
+        if self.style == HexdumpStyle::Canonical && !string.is_empty() {
+            write!(f, "\n{:08x}", string.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a value to be displayed as a hexdump.
+///
+/// The returned struct implements [`fmt::Display`] by writing the bytes
+/// returned by [`ToBytes::to_bytes`] in the given layout. Each line contains
+/// the offset of its first byte, the hexadecimal value of each byte, and a
+/// gutter with printable ASCII characters, where other bytes are replaced
+/// with `.`.
+///
+/// Lines are separated by line feeds, but no line feed is written after the
+/// last line, so the output can be used with [`println!`]. Unlike
+/// `hexdump`, repeated lines are not collapsed. Nothing is written for an
+/// empty value.
+///
+/// [`Hexdump::threshold`] can be used to only display a hexdump for values
+/// that are unlikely to be readable.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::hexdump;
+/// use print_bytes::HexdumpStyle;
+///
+/// assert_eq!(
+///     "00000000: 666f 6f80 0a                             foo..",
+///     hexdump(b"foo\x80\n", HexdumpStyle::Xxd).to_string(),
+/// );
+///
+/// let value = hexdump(b"foo\x80", HexdumpStyle::Canonical).threshold(0.5);
+/// assert_eq!(r"foo\x80", value.to_string());
+/// ```
+///
+/// [`println!`]: ::std::println
+#[inline]
+#[must_use]
+pub fn hexdump<T>(value: &T, style: HexdumpStyle) -> Hexdump<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    Hexdump {
+        value,
+        style,
+        width: 16,
+        group_size: match style {
+            HexdumpStyle::Canonical => 8,
+            HexdumpStyle::Xxd => 2,
+        },
+        threshold: None,
+    }
+}
@@ -0,0 +1,284 @@
+use alloc::string::String;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt::Write;
+use std::env;
+use std::ffi::OsString;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::chunks::Invalid;
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+// Returns whether color should be used for a stream, according to the
+// conventions described at <https://no-color.org/> and
+// <https://bixense.com/clicolors/>. Environment variables are read using the
+// callback, so that this function can be tested without modifying the
+// environment.
+fn is_color_enabled<F>(is_terminal: bool, mut var_os: F) -> bool
+where
+    F: FnMut(&str) -> Option<OsString>,
+{
+    if !is_terminal || var_os("NO_COLOR").is_some_and(|x| !x.is_empty()) {
+        return false;
+    }
+    if var_os("CLICOLOR_FORCE").is_some_and(|x| !x.is_empty() && x != "0") {
+        return true;
+    }
+    var_os("TERM").map_or(true, |x| x != "dumb")
+}
+
+/// The styles that can be used by [`highlight`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum HighlightStyle {
+    /// Swap the foreground and background colors (`\x1B[7m`), and swap
+    /// them back afterward (`\x1B[27m`).
+    #[default]
+    Reverse,
+
+    /// Use a red foreground color (`\x1B[31m`), and restore the default
+    /// foreground color afterward (`\x1B[39m`).
+    Red,
+}
+
+impl HighlightStyle {
+    const fn sgr(self) -> &'static str {
+        match self {
+            Self::Reverse => "\x1B[7m",
+            Self::Red => "\x1B[31m",
+        }
+    }
+
+    // Only the attribute that was set is reset, so that attributes set by
+    // the caller around the value are preserved.
+    const fn reset(self) -> &'static str {
+        match self {
+            Self::Reverse => "\x1B[27m",
+            Self::Red => "\x1B[39m",
+        }
+    }
+}
+
+/// A value returned by [`highlight`].
+pub struct Highlight<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    style: HighlightStyle,
+    color: bool,
+    lossy: bool,
+}
+
+impl<T> Highlight<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets the style used for highlighting.
+    ///
+    /// This option has no effect when color is disabled. The default is
+    /// [`HighlightStyle::Reverse`].
+    #[inline]
+    #[must_use]
+    pub fn style(mut self, style: HighlightStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether color should be used, overriding the value determined
+    /// by [`highlight`].
+    ///
+    /// This option can be used to implement options such as `--color`.
+    #[inline]
+    #[must_use]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets whether invalid sequences should be replaced with
+    /// [`REPLACEMENT_CHARACTER`] instead of being escaped.
+    ///
+    /// Backslashes are not escaped when this option is enabled. It is
+    /// disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    fn push_highlighted<F>(&self, string: &mut String, push_fn: F)
+    where
+        F: FnOnce(&mut String),
+    {
+        if self.color {
+            string.push_str(self.style.sgr());
+            push_fn(string);
+            string.push_str(self.style.reset());
+        } else {
+            push_fn(string);
+        }
+    }
+}
+
+impl<T> ToBytes for Highlight<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let mut string = String::new();
+        let _ = chunks::decode(self.value, |chunk| {
+            match chunk {
+                Chunk::Str(chunk) if self.lossy => string.push_str(chunk),
+                Chunk::Str(chunk) => {
+                    for (i, part) in chunk.split('\\').enumerate() {
+                        if i != 0 {
+                            self.push_highlighted(&mut string, |x| {
+                                x.push_str(r"\\");
+                            });
+                        }
+                        string.push_str(part);
+                    }
+                }
+                Chunk::Invalid(_) if self.lossy => {
+                    self.push_highlighted(&mut string, |x| {
+                        x.push(REPLACEMENT_CHARACTER);
+                    });
+                }
+                Chunk::Invalid(Invalid::Bytes(bytes)) => {
+                    self.push_highlighted(&mut string, |x| {
+                        for byte in bytes {
+                            let _ = write!(x, r"\x{:02x}", byte);
+                        }
+                    });
+                }
+                #[cfg(windows)]
+                Chunk::Invalid(Invalid::Surrogate(surrogate)) => {
+                    self.push_highlighted(&mut string, |x| {
+                        let _ = write!(x, r"\u{{{:x}}}", surrogate);
+                    });
+                }
+            }
+            Ok::<_, ()>(())
+        });
+        ByteStr::from_vec(string.into_bytes())
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        None
+    }
+}
+
+/// Wraps a value to highlight the parts that had to be escaped or replaced.
+///
+/// When a value containing invalid UTF-8 is displayed, it can be impossible
+/// to distinguish an inserted escape or [`REPLACEMENT_CHARACTER`] from the
+/// same text in the original value. The returned struct implements
+/// [`ToBytes`] using the same representation as [`write_fmt_escaped`], but
+/// each escape is wrapped in [Select Graphic Rendition] sequences, which
+/// terminals display using the requested [`HighlightStyle`]. Escaped
+/// backslashes are highlighted too. [`Highlight::lossy`] can be used to
+/// replace invalid sequences instead.
+///
+/// The `is_terminal` parameter should indicate whether the output stream is
+/// a terminal, such as by using [`IsTerminal`]. Color will only be used for
+/// terminals, and the following environment variables are also respected:
+/// - `NO_COLOR` disables color when it is not empty.
+/// - `CLICOLOR_FORCE` enables color when it is not empty or `0`, even if
+///   `TERM` is `dumb`. Color is still never used for other streams.
+/// - `TERM` disables color when it is `dumb`.
+///
+/// These variables are read by this function, and [`Highlight::color`] can
+/// be used to ignore them. On Windows, the console will only interpret these
+/// sequences when virtual terminal processing is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use std::io::IsTerminal;
+///
+/// use print_bytes::highlight;
+/// use print_bytes::println_lossy;
+/// use print_bytes::write_lossy;
+///
+/// let stdout = io::stdout();
+/// println_lossy(&highlight(b"foo\x80bar", stdout.is_terminal()));
+///
+/// let mut string = Vec::new();
+/// write_lossy(&mut string, &highlight(b"foo\x80", false))?;
+/// assert_eq!(br"foo\x80", &*string);
+///
+/// string.clear();
+/// write_lossy(&mut string, &highlight(b"foo\x80", false).color(true))?;
+/// assert_eq!(b"foo\x1B[7m\\x80\x1B[27m", &*string);
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// [`IsTerminal`]: ::std::io::IsTerminal
+/// [Select Graphic Rendition]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+/// [`write_fmt_escaped`]: super::write_fmt_escaped
+#[inline]
+#[must_use]
+pub fn highlight<T>(value: &T, is_terminal: bool) -> Highlight<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    Highlight {
+        value,
+        style: HighlightStyle::Reverse,
+        color: is_color_enabled(is_terminal, |x| env::var_os(x)),
+        lossy: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::is_color_enabled;
+
+    fn is_enabled(is_terminal: bool, vars: &[(&str, &str)]) -> bool {
+        is_color_enabled(is_terminal, |name| {
+            vars.iter()
+                .find(|&&(x, _)| x == name)
+                .map(|&(_, value)| OsString::from(value))
+        })
+    }
+
+    #[test]
+    fn test_is_color_enabled() {
+        assert!(is_enabled(true, &[]));
+        assert!(!is_enabled(false, &[]));
+        assert!(!is_enabled(false, &[("CLICOLOR_FORCE", "1")]));
+
+        assert!(is_enabled(true, &[("NO_COLOR", "")]));
+        assert!(!is_enabled(true, &[("NO_COLOR", "1")]));
+        assert!(!is_enabled(
+            true,
+            &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]
+        ));
+
+        assert!(!is_enabled(true, &[("TERM", "dumb")]));
+        assert!(is_enabled(
+            true,
+            &[("TERM", "dumb"), ("CLICOLOR_FORCE", "1")]
+        ));
+        assert!(!is_enabled(
+            true,
+            &[("TERM", "dumb"), ("CLICOLOR_FORCE", "0")]
+        ));
+        assert!(is_enabled(
+            true,
+            &[("TERM", "xterm"), ("CLICOLOR_FORCE", "")]
+        ));
+    }
+}
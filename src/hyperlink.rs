@@ -0,0 +1,232 @@
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::env;
+#[cfg(not(windows))]
+use std::ffi::CStr;
+use std::ffi::OsString;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::uri;
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+const START: &str = "\x1B]8;;";
+const TERMINATOR: &str = "\x1B\\";
+
+fn local_hostname() -> &'static str {
+    static HOSTNAME: OnceLock<String> = OnceLock::new();
+
+    HOSTNAME.get_or_init(|| {
+        #[cfg(windows)]
+        let hostname = env::var("COMPUTERNAME").ok();
+        #[cfg(not(windows))]
+        let hostname = {
+            // POSIX limits host names to 255 bytes.
+            let mut buffer = [0_u8; 256];
+            // SAFETY: The length passed is the length of the buffer.
+            let result = unsafe {
+                libc::gethostname(buffer.as_mut_ptr().cast(), buffer.len())
+            };
+            // The name is not required to be terminated if it was truncated.
+            (result == 0)
+                .then(|| CStr::from_bytes_until_nul(&buffer).ok())
+                .flatten()
+                .and_then(|x| x.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
+        hostname.map(|x| x.trim().to_owned()).unwrap_or_default()
+    })
+}
+
+// Returns whether hyperlinks should be written to a stream. Environment
+// variables are read using the callback, so that this function can be tested
+// without modifying the environment.
+fn is_enabled<F>(is_terminal: bool, var_os: F) -> bool
+where
+    F: FnOnce(&str) -> Option<OsString>,
+{
+    is_terminal && var_os("TERM").map_or(true, |x| x != "dumb")
+}
+
+/// A value returned by [`hyperlink`].
+pub struct Hyperlink<'a> {
+    path: &'a Path,
+    hostname: Option<&'a str>,
+    is_enabled: bool,
+}
+
+impl<'a> Hyperlink<'a> {
+    /// Sets the host name used in the link.
+    ///
+    /// By default, the name of the local host is used when it can be
+    /// determined. Terminals use this name to avoid opening a path on the
+    /// wrong host, such as when the output is from an SSH session. An empty
+    /// name can be given to omit it from the link.
+    #[inline]
+    #[must_use]
+    pub fn hostname(mut self, hostname: &'a str) -> Self {
+        self.hostname = Some(hostname);
+        self
+    }
+
+    /// Sets whether the hyperlink should be written, overriding the value
+    /// determined by [`hyperlink`].
+    #[inline]
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.is_enabled = enabled;
+        self
+    }
+
+    fn uri(&self) -> Option<String> {
+        if !self.is_enabled {
+            return None;
+        }
+        let path = if self.path.is_absolute() {
+            Cow::Borrowed(self.path)
+        } else {
+            Cow::Owned(env::current_dir().ok()?.join(self.path))
+        };
+        uri::file_uri(&path, self.hostname.unwrap_or_else(|| local_hostname()))
+    }
+}
+
+impl ToBytes for Hyperlink<'_> {
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let value = self.path.to_bytes();
+        let Some(uri) = self.uri() else {
+            return value;
+        };
+        let value = value.0.as_bytes();
+
+        let mut string = Vec::with_capacity(value.len() + 2 * uri.len());
+        for part in [START, &uri, TERMINATOR] {
+            string.extend_from_slice(part.as_bytes());
+        }
+        string.extend_from_slice(value);
+        for part in [START, TERMINATOR] {
+            string.extend_from_slice(part.as_bytes());
+        }
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        let value = self.path.to_wide()?;
+        let Some(uri) = self.uri() else {
+            return Some(value);
+        };
+
+        let mut string = Vec::with_capacity(value.0.len() + 2 * uri.len());
+        for part in [START, &uri, TERMINATOR] {
+            string.extend(part.encode_utf16());
+        }
+        string.extend_from_slice(&value.0);
+        for part in [START, TERMINATOR] {
+            string.extend(part.encode_utf16());
+        }
+        Some(WideStr(string))
+    }
+}
+
+/// Wraps a path to be displayed as a terminal hyperlink.
+///
+/// Many terminals support [OSC 8] hyperlinks, which allow clicking on text
+/// to open a URI. The returned struct implements [`ToBytes`] by writing the
+/// path unchanged, but enclosed in a hyperlink to its `file://` URI, so it
+/// can be passed to functions such as [`println_lossy`]. Relative paths are
+/// resolved using the current working directory.
+///
+/// The `is_terminal` parameter should indicate whether the output stream is
+/// a terminal, such as by using [`IsTerminal`]. Hyperlinks are only written
+/// to terminals, and not when the `TERM` environment variable is `dumb`,
+/// unless [`Hyperlink::enabled`] is used to override this decision.
+/// Otherwise, or if the URI cannot be determined, the path is written
+/// without a hyperlink. Terminals that do not support hyperlinks usually
+/// ignore them.
+///
+/// The URI is created as by [`path_to_file_uri`], so bytes of the path that
+/// cannot be used in a URI are percent-encoded, and paths containing `..`
+/// components are written without a hyperlink. By default, the host name is
+/// read from the `COMPUTERNAME` environment variable on Windows or using
+/// [`gethostname`] on other platforms, but [`Hyperlink::hostname`] can be
+/// used to change it.
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::io;
+/// use std::io::IsTerminal;
+///
+/// use print_bytes::hyperlink;
+/// use print_bytes::println_lossy;
+/// use print_bytes::write_lossy;
+///
+/// let path = env::current_exe()?;
+/// println_lossy(&hyperlink(&path, io::stdout().is_terminal()));
+///
+/// # #[cfg(unix)]
+/// # {
+/// let mut string = Vec::new();
+/// let path = hyperlink("/tmp/foo bar".as_ref(), false)
+///     .enabled(true)
+///     .hostname("host");
+/// write_lossy(&mut string, &path)?;
+/// assert_eq!(
+///     concat!(
+///         "\x1B]8;;file://host/tmp/foo%20bar\x1B\\",
+///         "/tmp/foo bar",
+///         "\x1B]8;;\x1B\\",
+///     )
+///     .as_bytes(),
+///     &*string,
+/// );
+/// # }
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// [`gethostname`]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/gethostname.html
+/// [`IsTerminal`]: ::std::io::IsTerminal
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+/// [`path_to_file_uri`]: super::path_to_file_uri
+/// [`println_lossy`]: super::println_lossy
+#[inline]
+#[must_use]
+pub fn hyperlink(path: &Path, is_terminal: bool) -> Hyperlink<'_> {
+    Hyperlink {
+        path,
+        hostname: None,
+        is_enabled: is_enabled(is_terminal, |x| env::var_os(x)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::is_enabled;
+
+    #[test]
+    fn test_is_enabled() {
+        let term = |value: &'static str| {
+            move |name: &str| {
+                assert_eq!("TERM", name);
+                Some(OsString::from(value))
+            }
+        };
+        assert!(is_enabled(true, |_| None));
+        assert!(is_enabled(true, term("xterm")));
+        assert!(!is_enabled(true, term("dumb")));
+        assert!(!is_enabled(false, |_| None));
+        assert!(!is_enabled(false, term("xterm")));
+    }
+}
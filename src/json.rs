@@ -0,0 +1,232 @@
+#[cfg(feature = "std")]
+use alloc::string::String;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::io;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::chunks::Invalid;
+use super::ToBytes;
+
+/// The policy used by [`write_json_string`] for sequences that are not valid
+/// UTF-8.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidUtf8 {
+    /// Replace each invalid sequence with [`REPLACEMENT_CHARACTER`], as
+    /// [`write_fmt_lossy`] would.
+    ///
+    /// [`write_fmt_lossy`]: super::write_fmt_lossy
+    #[default]
+    Replace,
+
+    /// Write each invalid byte as a lone low surrogate from `\udc80` to
+    /// `\udcff`, following the convention of [PEP 383]. Unpaired surrogates
+    /// on Windows are written as themselves.
+    ///
+    /// The output is still valid JSON syntax, and decoders that understand
+    /// the convention can recover the original string. However, [RFC 8259]
+    /// does not define the meaning of unpaired surrogates, so strict parsers,
+    /// such as [serde_json], will reject these escapes. This policy should
+    /// only be used when the output will be read by a compatible parser.
+    ///
+    /// [PEP 383]: https://peps.python.org/pep-0383/
+    /// [RFC 8259]: https://www.rfc-editor.org/rfc/rfc8259#section-8.2
+    /// [serde_json]: https://crates.io/crates/serde_json
+    SurrogateEscape,
+
+    /// Return [`JsonError::InvalidUtf8`] when the first invalid sequence is
+    /// found.
+    ///
+    /// The value is checked while it is written, so the writer will have
+    /// received an incomplete string when this error is returned.
+    Error,
+}
+
+/// The error returned by [`write_json_string`].
+///
+/// [`write_json_string_io`] returns this error wrapped in an [`io::Error`]
+/// with kind [`InvalidData`] instead.
+///
+/// [`InvalidData`]: ::std::io::ErrorKind::InvalidData
+/// [`io::Error`]: ::std::io::Error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum JsonError {
+    /// Writing to the writer failed.
+    Fmt(fmt::Error),
+
+    /// The value was not valid UTF-8, and [`InvalidUtf8::Error`] was
+    /// requested.
+    InvalidUtf8,
+}
+
+impl fmt::Display for JsonError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fmt(error) => error.fmt(f),
+            Self::InvalidUtf8 => f.write_str("value is not valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsonError {}
+
+impl From<fmt::Error> for JsonError {
+    #[inline]
+    fn from(value: fmt::Error) -> Self {
+        Self::Fmt(value)
+    }
+}
+
+fn write_str<W>(writer: &mut W, string: &str) -> fmt::Result
+where
+    W: Write,
+{
+    let mut start = 0;
+    for (i, ch) in string.char_indices() {
+        let escape = match ch {
+            '"' => r#"\""#,
+            '\\' => r"\\",
+            '\n' => r"\n",
+            '\r' => r"\r",
+            '\t' => r"\t",
+            '\x08' => r"\b",
+            '\x0C' => r"\f",
+            '\0'..='\x1F' => "",
+            _ => continue,
+        };
+        writer.write_str(&string[start..i])?;
+        if escape.is_empty() {
+            write!(writer, r"\u{:04x}", u32::from(ch))?;
+        } else {
+            writer.write_str(escape)?;
+        }
+        start = i + ch.len_utf8();
+    }
+    writer.write_str(&string[start..])
+}
+
+/// Writes a value to a [`fmt::Write`] "writer" as a JSON string.
+///
+/// The output is always a valid JSON string literal, including the
+/// surrounding quotes. Quotes, backslashes, and control characters are
+/// escaped, and sequences that are not valid UTF-8 are handled according to
+/// the given policy.
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails or the value cannot be
+/// represented using the given policy. In either case, the output may be
+/// incomplete.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::write_json_string;
+/// use print_bytes::InvalidUtf8;
+///
+/// let mut string = String::new();
+/// write_json_string(&mut string, b"\"foo\"\x80", InvalidUtf8::Replace)?;
+/// assert_eq!("\"\\\"foo\\\"\u{FFFD}\"", string);
+///
+/// string.clear();
+/// let policy = InvalidUtf8::SurrogateEscape;
+/// write_json_string(&mut string, b"foo\x80", policy)?;
+/// assert_eq!(r#""foo\udc80""#, string);
+/// #
+/// # Ok::<_, print_bytes::JsonError>(())
+/// ```
+#[inline]
+pub fn write_json_string<T, W>(
+    mut writer: W,
+    value: &T,
+    policy: InvalidUtf8,
+) -> Result<(), JsonError>
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    writer.write_char('"')?;
+    chunks::decode(value, |chunk| {
+        match chunk {
+            Chunk::Str(string) => write_str(&mut writer, string)?,
+            Chunk::Invalid(_) if policy == InvalidUtf8::Error => {
+                return Err(JsonError::InvalidUtf8);
+            }
+            Chunk::Invalid(_) if policy == InvalidUtf8::Replace => {
+                writer.write_char(REPLACEMENT_CHARACTER)?;
+            }
+            Chunk::Invalid(Invalid::Bytes(string)) => {
+                string.iter().try_for_each(|&x| {
+                    write!(writer, r"\u{:04x}", 0xDC00 | u16::from(x))
+                })?;
+            }
+            #[cfg(windows)]
+            Chunk::Invalid(Invalid::Surrogate(surrogate)) => {
+                write!(writer, r"\u{:04x}", surrogate)?;
+            }
+        }
+        Ok(())
+    })?;
+    writer.write_char('"')?;
+    Ok(())
+}
+
+/// Writes a value to an [`io::Write`] "writer" as a JSON string.
+///
+/// This function is equivalent to [`write_json_string`], but the string is
+/// assembled before being passed to the writer, so nothing is written when
+/// the value cannot be represented using the given policy. The string is
+/// then written using a single call to [`Write::write_all`].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails. If the value cannot be
+/// represented using the given policy, an error of kind [`InvalidData`] is
+/// returned, which wraps [`JsonError::InvalidUtf8`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+///
+/// use print_bytes::write_json_string_io;
+/// use print_bytes::InvalidUtf8;
+///
+/// let mut string = Vec::new();
+/// write_json_string_io(&mut string, b"foo\x80", InvalidUtf8::Replace)?;
+/// assert_eq!("\"foo\u{FFFD}\"".as_bytes(), string);
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// [`InvalidData`]: io::ErrorKind::InvalidData
+/// [`Write::write_all`]: io::Write::write_all
+#[cfg(feature = "std")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn write_json_string_io<T, W>(
+    mut writer: W,
+    value: &T,
+    policy: InvalidUtf8,
+) -> io::Result<()>
+where
+    T: ?Sized + ToBytes,
+    W: io::Write,
+{
+    let mut string = String::new();
+    match write_json_string(&mut string, value, policy) {
+        Ok(()) => {}
+        Err(JsonError::Fmt(_)) => panic!("failed writing to string"),
+        Err(error @ JsonError::InvalidUtf8) => {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+        }
+    }
+    writer.write_all(string.as_bytes())
+}
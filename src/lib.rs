@@ -25,13 +25,49 @@
 //! Windows Console can display these paths, so this crate will output them
 //! losslessly when writing to that terminal.
 //!
+//! ### Note: Bidirectional Text
+//!
+//! When the print functions write to a terminal, bidirectional formatting
+//! characters are escaped, as if the value was wrapped by [`bidi_safe`] with
+//! [`BidiPolicy::Escape`]. Otherwise, a value containing these characters
+//! could make the output appear different from the bytes written. Other
+//! policies can be used by calling [`bidi_safe`] explicitly, and other
+//! functions never escape these characters. Values without these characters
+//! are written without being copied.
+//!
 //! # Features
 //!
 //! These features are optional and can be enabled or disabled in a
 //! "Cargo.toml" file.
 //!
+//! ### Default Features
+//!
+//! - **std** -
+//!   Provides the functions that write to [`std::io::Write`] "writers", as
+//!   well as the print functions. Without this feature, the crate only
+//!   requires the [`alloc`] crate, so it can be used in `no_std`
+//!   environments.
+//!
+//!   This feature was added in version 3.0. Previously, the same
+//!   functionality was always available, so crates that disable default
+//!   features must now enable it to keep using it.
+//!
 //! ### Optional Features
 //!
+//! - **embedded-io** -
+//!   Provides the `embedded_io` module, which contains functions for writing
+//!   to writers from the [embedded-io] crate.
+//!
+//! - **futures-io** -
+//!   Provides the `futures_io` module, which contains functions for writing
+//!   to asynchronous writers from the [futures-io] crate.
+//!
+//! - **log** -
+//!   Provides an implementation of `ToValue` from the [log] crate for
+//!   [`Display`], allowing values to be logged as structured key-values.
+//!   Only the escaped representation is logged, unless the **serde**
+//!   feature is also enabled, in which case values are logged losslessly.
+//!
 //! - **os\_str\_bytes** -
 //!   Provides implementations of [`ToBytes`] for:
 //!   - [`OsStr`]
@@ -39,6 +75,24 @@
 //!   - [`Path`]
 //!   - [`PathBuf`]
 //!
+//!   It also provides `command_line`, for displaying a [`Command`] as a
+//!   shell command line, `hyperlink`, for displaying a [`Path`] as a
+//!   terminal hyperlink, and `path_to_file_uri` and `file_uri_to_path`, for
+//!   converting between paths and `file://` URIs.
+//!
+//! - **serde** -
+//!   Provides the `serde` module, which contains functions for serializing
+//!   values losslessly using the [serde] crate, and an implementation of
+//!   `Serialize` for [`Display`].
+//!
+//! - **tokio** -
+//!   Provides the `tokio` module, which contains functions for writing to
+//!   asynchronous writers from the [tokio] crate.
+//!
+//! - **tracing** -
+//!   Provides the `tracing` module, which contains functions for recording
+//!   values as fields using the [tracing] crate.
+//!
 //! ### Nightly Features
 //!
 //! These features are unstable, since they rely on unstable Rust features.
@@ -68,6 +122,10 @@
 //! # Ok::<_, io::Error>(())
 //! ```
 //!
+//! [`Command`]: ::std::process::Command
+//! [embedded-io]: https://crates.io/crates/embedded-io
+//! [futures-io]: https://crates.io/crates/futures-io
+//! [log]: https://crates.io/crates/log
 //! [`OsStr`]: ::std::ffi::OsStr
 //! [`OsString`]: ::std::ffi::OsString
 //! [`Path`]: ::std::path::Path
@@ -75,6 +133,10 @@
 //! [`Path::to_string_lossy`]: ::std::path::Path::to_string_lossy
 //! [`PathBuf`]: ::std::path::PathBuf
 //! [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+//! [`String::from_utf8_lossy`]: ::alloc::string::String::from_utf8_lossy
+//! [serde]: https://crates.io/crates/serde
+//! [tokio]: https://crates.io/crates/tokio
+//! [tracing]: https://crates.io/crates/tracing
 //! [wtf8_audience]: https://simonsapin.github.io/wtf-8/#intended-audience
 
 #![cfg_attr(feature = "specialization", allow(incomplete_features))]
@@ -83,23 +145,180 @@
 // https://github.com/rust-lang/docs.rs/issues/147#issuecomment-389544407
 #![cfg_attr(print_bytes_docs_rs, feature(doc_cfg))]
 #![cfg_attr(feature = "specialization", feature(specialization))]
+#![no_std]
 #![warn(unused_results)]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use alloc::borrow::Cow;
+#[cfg(all(windows, feature = "std"))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::ops::Deref;
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
+use std::io::IsTerminal;
+#[cfg(feature = "std")]
 use std::io::Write;
 
 mod bytes;
 pub use bytes::ByteStr;
-use bytes::ByteStrInner;
 pub use bytes::ToBytes;
 #[cfg(any(doc, windows))]
 pub use bytes::WideStr;
 
-#[cfg(windows)]
+mod ansi;
+pub use ansi::strip_ansi;
+pub use ansi::StripAnsi;
+
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+#[macro_use]
+mod async_io;
+
+mod bidi;
+pub use bidi::bidi_safe;
+pub use bidi::BidiPolicy;
+pub use bidi::BidiSafe;
+pub use bidi::BidiWarning;
+
+mod caret;
+pub use caret::caret_notation;
+pub use caret::CaretNotation;
+
+mod chunks;
+
+mod combinators;
+pub use combinators::chain;
+pub use combinators::from_fn;
+pub use combinators::join;
+pub use combinators::Chain;
+pub use combinators::FromFn;
+pub use combinators::Join;
+pub use combinators::Parts;
+
+#[cfg(feature = "os_str_bytes")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "os_str_bytes")))]
+mod command;
+#[cfg(feature = "os_str_bytes")]
+pub use command::command_line;
+#[cfg(feature = "os_str_bytes")]
+pub use command::CommandLine;
+
+mod confusable;
+pub use confusable::check_confusables;
+pub use confusable::ConfusableReport;
+pub use confusable::Script;
+pub use confusable::SuspiciousChar;
+
+#[cfg(all(feature = "std", windows))]
 mod console;
 
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "embedded-io")))]
+pub mod embedded_io;
+
+mod fmt_write;
+pub use fmt_write::display;
+pub use fmt_write::write_fmt_escaped;
+pub use fmt_write::write_fmt_lossy;
+pub use fmt_write::Display;
+
+#[cfg(feature = "futures-io")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "futures-io")))]
+pub mod futures_io;
+
+mod git;
+pub use git::git_quote;
+pub use git::git_unquote;
+pub use git::GitQuote;
+pub use git::GitUnquoteError;
+
+mod hexdump;
+pub use hexdump::hexdump;
+pub use hexdump::Hexdump;
+pub use hexdump::HexdumpStyle;
+
+#[cfg(feature = "std")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+mod highlight;
+#[cfg(feature = "std")]
+pub use highlight::highlight;
+#[cfg(feature = "std")]
+pub use highlight::Highlight;
+#[cfg(feature = "std")]
+pub use highlight::HighlightStyle;
+
+#[cfg(feature = "os_str_bytes")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "os_str_bytes")))]
+mod hyperlink;
+#[cfg(feature = "os_str_bytes")]
+pub use hyperlink::hyperlink;
+#[cfg(feature = "os_str_bytes")]
+pub use hyperlink::Hyperlink;
+
+mod json;
+pub use json::write_json_string;
+#[cfg(feature = "std")]
+pub use json::write_json_string_io;
+pub use json::InvalidUtf8;
+pub use json::JsonError;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
+mod literal;
+pub use literal::c_str_literal;
+pub use literal::rust_byte_str_literal;
+pub use literal::rust_str_literal;
+pub use literal::CStrLiteral;
+pub use literal::RustByteStrLiteral;
+pub use literal::RustStrLiteral;
+
+mod ls;
+pub use ls::ls_quote;
+pub use ls::LsQuote;
+pub use ls::QuotingStyle;
+
+mod shell;
+pub use shell::cmd_quote;
+pub use shell::powershell_quote;
+pub use shell::shell_quote;
+pub use shell::CmdQuote;
+pub use shell::PowerShellQuote;
+pub use shell::ShellQuote;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "tokio")))]
+pub mod tokio;
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "tracing")))]
+pub mod tracing;
+
+mod unicode;
+
+mod uri;
+#[cfg(feature = "os_str_bytes")]
+pub use uri::file_uri_to_path;
+#[cfg(feature = "os_str_bytes")]
+pub use uri::path_to_file_uri;
+pub use uri::percent_decode;
+pub use uri::percent_encode;
+pub use uri::EncodeSet;
+pub use uri::PercentEncode;
+pub use uri::UriError;
+
+#[cfg(feature = "std")]
 #[cfg_attr(test, macro_use)]
 mod writer;
+#[cfg(feature = "std")]
 pub use writer::WriteLossy;
 
 #[cfg(test)]
@@ -137,15 +356,51 @@ mod tests;
 /// ```
 ///
 /// [module]: self
+#[cfg(feature = "std")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
 #[inline]
-pub fn write_lossy<T, W>(mut writer: W, value: &T) -> io::Result<()>
+pub fn write_lossy<T, W>(writer: W, value: &T) -> io::Result<()>
+where
+    T: ?Sized + ToBytes,
+    W: Write + WriteLossy,
+{
+    write_value(writer, value, b"")
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(not(windows), allow(unused_variables))]
+fn to_bytes_lossy<T>(value: &T, lossy: bool) -> Cow<'_, [u8]>
+where
+    T: ?Sized + ToBytes,
+{
+    let string = value.to_bytes().0.into_bytes();
+    #[cfg(windows)]
+    if lossy {
+        if let Cow::Owned(string) = String::from_utf8_lossy(&string) {
+            return Cow::Owned(string.into_bytes());
+        }
+    }
+    string
+}
+
+#[cfg(feature = "std")]
+fn write_value<T, W>(
+    mut writer: W,
+    value: &T,
+    terminator: &[u8],
+) -> io::Result<()>
 where
     T: ?Sized + ToBytes,
     W: Write + WriteLossy,
 {
+    #[cfg(not(windows))]
+    let lossy = false;
     #[cfg(windows)]
     let lossy = if let Some(mut console) = writer.__to_console() {
-        if let Some(string) = value.to_wide() {
+        if let Some(mut string) = value.to_wide() {
+            string
+                .0
+                .extend(String::from_utf8_lossy(terminator).encode_utf16());
             return console.write_wide_all(&string.0);
         }
         true
@@ -153,28 +408,161 @@ where
         false
     };
 
-    #[cfg(windows)]
-    let buffer;
-    let string = value.to_bytes().0;
-    #[cfg_attr(not(windows), allow(clippy::infallible_destructuring_match))]
-    let string = match &string {
-        ByteStrInner::Bytes(string) => {
-            #[cfg(windows)]
-            if lossy {
-                buffer = String::from_utf8_lossy(string);
-                buffer.as_bytes()
-            } else {
-                string
-            }
-            #[cfg(not(windows))]
-            string
-        }
-        #[cfg(windows)]
-        ByteStrInner::Str(string) => string.as_bytes(),
-    };
-    writer.write_all(string)
+    let string = to_bytes_lossy(value, lossy);
+    if terminator.is_empty() {
+        return writer.write_all(&string);
+    }
+
+    let mut buffer = Vec::with_capacity(string.len() + terminator.len());
+    buffer.extend_from_slice(&string);
+    buffer.extend_from_slice(terminator);
+    writer.write_all(&buffer)
+}
+
+/// Writes a value to a "writer", followed by a terminator, using a single
+/// write when possible.
+///
+/// Unlike calling [`write_lossy`] and writing the terminator separately, this
+/// function assembles the entire line before passing it to the writer. When
+/// the writer is unbuffered, such as [`Stderr`], the line will usually be
+/// written by a single system call. On Unix, writes of at most `PIPE_BUF`
+/// bytes to a pipe are atomic, so lines written concurrently by multiple
+/// processes sharing the pipe will not be interleaved. [`println_lossy`] and
+/// [`eprintln_lossy`] write lines in the same way.
+///
+/// Buffered writers only pass the line to the underlying stream when they are
+/// flushed, so this guarantee requires flushing them before each line.
+///
+/// For more information, see [the module-level documentation][module].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::io;
+///
+/// use print_bytes::write_atomic_lossy;
+///
+/// # #[cfg(feature = "os_str_bytes")]
+/// write_atomic_lossy(io::stderr(), &env::current_exe()?, b"\n")?;
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// [module]: self
+/// [`Stderr`]: io::Stderr
+#[cfg(feature = "std")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn write_atomic_lossy<T, W>(
+    writer: W,
+    value: &T,
+    terminator: &[u8],
+) -> io::Result<()>
+where
+    T: ?Sized + ToBytes,
+    W: Write + WriteLossy,
+{
+    write_value(writer, value, terminator)
+}
+
+/// Writes multiple values to a "writer", following each with a terminator.
+///
+/// This function is equivalent to calling [`write_lossy`] for each value and
+/// writing the terminator after it. Since the writer is only passed once, a
+/// lock can be held for the entire sequence.
+///
+/// Items can be references or any other type that dereferences to a value
+/// implementing [`ToBytes`], such as [`OsString`].
+///
+/// For more information, see [the module-level documentation][module].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::write_terminated_lossy;
+///
+/// let values: [&[u8]; 2] = [b"foo", b"bar"];
+///
+/// let mut string = Vec::new();
+/// write_terminated_lossy(&mut string, values, b"\0")
+///     .expect("failed writing to vector");
+/// assert_eq!(b"foo\0bar\0", &*string);
+/// ```
+///
+/// [module]: self
+/// [`OsString`]: ::std::ffi::OsString
+#[cfg(feature = "std")]
+#[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn write_terminated_lossy<I, W>(
+    mut writer: W,
+    iter: I,
+    terminator: &[u8],
+) -> io::Result<()>
+where
+    I: IntoIterator,
+    I::Item: Deref,
+    <I::Item as Deref>::Target: ToBytes,
+    W: Write + WriteLossy,
+{
+    for value in iter {
+        write_lossy(&mut writer, &*value)?;
+        writer.write_all(terminator)?;
+    }
+    Ok(())
+}
+
+// Bidirectional formatting characters are escaped when printing to a
+// terminal, since they could make the output misleading. The terminator is
+// written in the same call as the value, so that lines printed by multiple
+// threads are not interleaved.
+#[cfg(feature = "std")]
+fn write_printed<T, W>(
+    writer: W,
+    value: &T,
+    terminator: &[u8],
+    is_terminal: bool,
+) -> io::Result<()>
+where
+    T: ?Sized + ToBytes,
+    W: Write + WriteLossy,
+{
+    if is_terminal {
+        write_value(writer, &bidi_safe(value, BidiPolicy::Escape), terminator)
+    } else {
+        write_value(writer, value, terminator)
+    }
 }
 
+#[cfg(feature = "std")]
+fn write_printed_terminated<I, W>(
+    mut writer: W,
+    iter: I,
+    terminator: &[u8],
+    is_terminal: bool,
+) -> io::Result<()>
+where
+    I: IntoIterator,
+    I::Item: Deref,
+    <I::Item as Deref>::Target: ToBytes,
+    W: Write + WriteLossy,
+{
+    for value in iter {
+        write_printed(&mut writer, &*value, terminator, is_terminal)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
 macro_rules! expect_print {
     ( $label:literal , $result:expr ) => {
         $result
@@ -182,34 +570,82 @@ macro_rules! expect_print {
     };
 }
 
+#[cfg(feature = "std")]
 macro_rules! r#impl {
     (
         $writer:expr ,
         $(#[ $print_fn_attr:meta ])* $print_fn:ident ,
         $(#[ $println_fn_attr:meta ])* $println_fn:ident ,
+        $(#[ $println_all_fn_attr:meta ])* $println_all_fn:ident ,
+        $(#[ $print0_fn_attr:meta ])* $print0_fn:ident ,
         $label:literal ,
     ) => {
+        #[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
         #[inline]
         $(#[$print_fn_attr])*
         pub fn $print_fn<T>(value: &T)
         where
             T: ?Sized + ToBytes,
         {
-            expect_print!($label, write_lossy($writer, value));
+            let writer = $writer;
+            let is_terminal = writer.is_terminal();
+            expect_print!(
+                $label,
+                write_printed(writer, value, b"", is_terminal)
+            );
         }
 
+        #[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
         #[inline]
         $(#[$println_fn_attr])*
         pub fn $println_fn<T>(value: &T)
         where
             T: ?Sized + ToBytes,
         {
-            let mut writer = $writer.lock();
-            expect_print!($label, write_lossy(&mut writer, value));
-            expect_print!($label, writer.write_all(b"\n"));
+            let writer = $writer.lock();
+            let is_terminal = writer.is_terminal();
+            expect_print!(
+                $label,
+                write_printed(writer, value, b"\n", is_terminal)
+            );
+        }
+
+        #[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+        #[inline]
+        $(#[$println_all_fn_attr])*
+        pub fn $println_all_fn<I>(iter: I)
+        where
+            I: IntoIterator,
+            I::Item: Deref,
+            <I::Item as Deref>::Target: ToBytes,
+        {
+            let writer = $writer.lock();
+            let is_terminal = writer.is_terminal();
+            expect_print!(
+                $label,
+                write_printed_terminated(writer, iter, b"\n", is_terminal)
+            );
+        }
+
+        #[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = "std")))]
+        #[inline]
+        $(#[$print0_fn_attr])*
+        pub fn $print0_fn<I>(iter: I)
+        where
+            I: IntoIterator,
+            I::Item: Deref,
+            <I::Item as Deref>::Target: ToBytes,
+        {
+            let writer = $writer.lock();
+            let is_terminal = writer.is_terminal();
+            expect_print!(
+                $label,
+                write_printed_terminated(writer, iter, b"\0", is_terminal)
+            );
         }
     };
 }
+#[cfg(feature = "std")]
 r#impl!(
     io::stderr(),
     /// Prints a value to the standard error stream.
@@ -238,6 +674,7 @@ r#impl!(
     /// ```
     ///
     /// [module]: self
+    /// [`eprint!`]: ::std::eprint
     eprint_lossy,
     /// Prints a value to the standard error stream, followed by a newline.
     ///
@@ -265,9 +702,60 @@ r#impl!(
     /// ```
     ///
     /// [module]: self
+    /// [`eprintln!`]: ::std::eprintln
     eprintln_lossy,
+    /// Prints multiple values to the standard error stream, following each
+    /// with a newline.
+    ///
+    /// The stream is locked once for all values, instead of once per value.
+    ///
+    /// For more information, see [the module-level documentation][module].
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    ///
+    /// use print_bytes::eprintln_all_lossy;
+    ///
+    /// # #[cfg(feature = "os_str_bytes")]
+    /// eprintln_all_lossy(env::args_os().skip(1));
+    /// ```
+    ///
+    /// [module]: self
+    eprintln_all_lossy,
+    /// Prints multiple values to the standard error stream, following each
+    /// with a null byte.
+    ///
+    /// This output can be read by programs such as `xargs -0`. The stream is
+    /// locked once for all values, instead of once per value.
+    ///
+    /// For more information, see [the module-level documentation][module].
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    ///
+    /// use print_bytes::eprint0_lossy;
+    ///
+    /// # #[cfg(feature = "os_str_bytes")]
+    /// eprint0_lossy(env::args_os().skip(1));
+    /// ```
+    ///
+    /// [module]: self
+    eprint0_lossy,
     "stderr",
 );
+#[cfg(feature = "std")]
 r#impl!(
     io::stdout(),
     /// Prints a value to the standard output stream.
@@ -296,6 +784,7 @@ r#impl!(
     /// ```
     ///
     /// [module]: self
+    /// [`print!`]: ::std::print
     print_lossy,
     /// Prints a value to the standard output stream, followed by a newline.
     ///
@@ -323,6 +812,56 @@ r#impl!(
     /// ```
     ///
     /// [module]: self
+    /// [`println!`]: ::std::println
     println_lossy,
+    /// Prints multiple values to the standard output stream, following each
+    /// with a newline.
+    ///
+    /// The stream is locked once for all values, instead of once per value.
+    ///
+    /// For more information, see [the module-level documentation][module].
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    ///
+    /// use print_bytes::println_all_lossy;
+    ///
+    /// # #[cfg(feature = "os_str_bytes")]
+    /// println_all_lossy(env::args_os().skip(1));
+    /// ```
+    ///
+    /// [module]: self
+    println_all_lossy,
+    /// Prints multiple values to the standard output stream, following each
+    /// with a null byte.
+    ///
+    /// This output can be read by programs such as `xargs -0`. The stream is
+    /// locked once for all values, instead of once per value.
+    ///
+    /// For more information, see [the module-level documentation][module].
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    ///
+    /// use print_bytes::print0_lossy;
+    ///
+    /// # #[cfg(feature = "os_str_bytes")]
+    /// print0_lossy(env::args_os().skip(1));
+    /// ```
+    ///
+    /// [module]: self
+    print0_lossy,
     "stdout",
 );
@@ -0,0 +1,245 @@
+use core::fmt;
+use core::fmt::Write;
+
+use super::bidi::is_bidi_control;
+use super::chunks;
+use super::chunks::Chunk;
+use super::ToBytes;
+
+/// A value returned by [`rust_byte_str_literal`].
+pub struct RustByteStrLiteral<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> fmt::Display for RustByteStrLiteral<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("b\"")?;
+        for &byte in self.0.to_bytes().0.as_bytes() {
+            match byte {
+                b'"' => f.write_str(r#"\""#)?,
+                b'\\' => f.write_str(r"\\")?,
+                b'\0' => f.write_str(r"\0")?,
+                b'\n' => f.write_str(r"\n")?,
+                b'\r' => f.write_str(r"\r")?,
+                b'\t' => f.write_str(r"\t")?,
+                b' '..=b'~' => f.write_char(byte.into())?,
+                _ => write!(f, r"\x{:02x}", byte)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Wraps a value to be displayed as a Rust byte string literal.
+///
+/// The returned struct implements [`fmt::Display`] by writing a literal
+/// such as `b"foo\x80"`, which evaluates to exactly the bytes returned by
+/// [`ToBytes::to_bytes`]. Printable ASCII characters are written unchanged,
+/// and all other bytes are escaped, so the output is always ASCII.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::rust_byte_str_literal;
+///
+/// assert_eq!(
+///     r#"b"\"foo\"\n\x80""#,
+///     rust_byte_str_literal(b"\"foo\"\n\x80").to_string(),
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn rust_byte_str_literal<T>(value: &T) -> RustByteStrLiteral<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    RustByteStrLiteral(value)
+}
+
+/// A value returned by [`rust_str_literal`].
+pub struct RustStrLiteral<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> fmt::Display for RustStrLiteral<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        chunks::decode(self.0, |chunk| {
+            let Chunk::Str(string) = chunk else {
+                unreachable!("invalid string");
+            };
+            for ch in string.chars() {
+                match ch {
+                    '"' => f.write_str(r#"\""#)?,
+                    '\\' => f.write_str(r"\\")?,
+                    '\0' => f.write_str(r"\0")?,
+                    '\n' => f.write_str(r"\n")?,
+                    '\r' => f.write_str(r"\r")?,
+                    '\t' => f.write_str(r"\t")?,
+                    _ if ch.is_control() || is_bidi_control(ch) => {
+                        write!(f, r"\u{{{:x}}}", u32::from(ch))?;
+                    }
+                    _ => f.write_char(ch)?,
+                }
+            }
+            Ok::<_, fmt::Error>(())
+        })?;
+        f.write_char('"')
+    }
+}
+
+/// Wraps a value to be displayed as a Rust string literal, if it is valid
+/// UTF-8.
+///
+/// The returned struct implements [`fmt::Display`] by writing a literal
+/// such as `"foo\n"`, which evaluates to exactly the original string.
+/// Control characters and bidirectional formatting characters are escaped,
+/// since the compiler rejects the latter by default. All other characters
+/// are written unchanged.
+///
+/// Returns [`None`] if the value cannot be represented losslessly as a
+/// string. [`rust_byte_str_literal`] can be used for these values instead.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::rust_str_literal;
+///
+/// let literal = rust_str_literal("caf\u{E9}\n".as_bytes())
+///     .expect("invalid string");
+/// assert_eq!("\"caf\u{E9}\\n\"", literal.to_string());
+///
+/// assert!(rust_str_literal(b"\x80").is_none());
+/// ```
+#[inline]
+#[must_use]
+pub fn rust_str_literal<T>(value: &T) -> Option<RustStrLiteral<'_, T>>
+where
+    T: ?Sized + ToBytes,
+{
+    chunks::decode(value, |chunk| match chunk {
+        Chunk::Str(_) => Ok(()),
+        Chunk::Invalid(_) => Err(()),
+    })
+    .ok()
+    .map(|()| RustStrLiteral(value))
+}
+
+/// A value returned by [`c_str_literal`].
+pub struct CStrLiteral<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    hex_escapes: bool,
+}
+
+impl<T> CStrLiteral<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether escaped bytes should be written using hexadecimal
+    /// escapes, such as `\x80`, instead of octal escapes, such as `\200`.
+    ///
+    /// This option is disabled by default.
+    #[inline]
+    #[must_use]
+    pub fn hex_escapes(mut self, hex_escapes: bool) -> Self {
+        self.hex_escapes = hex_escapes;
+        self
+    }
+}
+
+impl<T> fmt::Display for CStrLiteral<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        let mut after_hex_escape = false;
+        let mut after_question_mark = false;
+        for &byte in self.value.to_bytes().0.as_bytes() {
+            // Hexadecimal escapes have no length limit, so a following digit
+            // must be separated by starting a new literal.
+            if after_hex_escape && byte.is_ascii_hexdigit() {
+                f.write_str(r#""""#)?;
+            }
+            after_hex_escape = false;
+
+            match byte {
+                b'"' => f.write_str(r#"\""#)?,
+                b'\\' => f.write_str(r"\\")?,
+                b'\x07' => f.write_str(r"\a")?,
+                b'\x08' => f.write_str(r"\b")?,
+                b'\t' => f.write_str(r"\t")?,
+                b'\n' => f.write_str(r"\n")?,
+                b'\x0B' => f.write_str(r"\v")?,
+                b'\x0C' => f.write_str(r"\f")?,
+                b'\r' => f.write_str(r"\r")?,
+                // This prevents trigraphs, such as "??/", from being parsed.
+                b'?' if after_question_mark => f.write_str(r"\?")?,
+                b' '..=b'~' => f.write_char(byte.into())?,
+                _ if self.hex_escapes => {
+                    write!(f, r"\x{:02x}", byte)?;
+                    after_hex_escape = true;
+                }
+                // Octal escapes are limited to three digits.
+                _ => write!(f, r"\{:03o}", byte)?,
+            }
+            after_question_mark = byte == b'?';
+        }
+        f.write_char('"')
+    }
+}
+
+/// Wraps a value to be displayed as a C string literal.
+///
+/// The returned struct implements [`fmt::Display`] by writing a literal
+/// such as `"foo\200"`, which contains exactly the bytes returned by
+/// [`ToBytes::to_bytes`], followed by the terminating null byte added by
+/// the compiler. Printable ASCII characters are written unchanged, and all
+/// other bytes are escaped, so the output is always ASCII.
+///
+/// Escaped bytes are written using octal escapes by default. When
+/// [`CStrLiteral::hex_escapes`] is enabled, a hexadecimal escape followed by
+/// a hexadecimal digit would be parsed as a single escape, so the literal is
+/// split at that position, such as `"\x80""a"`. Adjacent literals are
+/// concatenated by the compiler.
+///
+/// Since C strings are terminated by a null byte, values containing one will
+/// appear truncated to most C functions.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::c_str_literal;
+///
+/// assert_eq!(
+///     r#""\"foo\"\n\200a""#,
+///     c_str_literal(b"\"foo\"\n\x80a").to_string(),
+/// );
+/// assert_eq!(
+///     r#""\x80""a""#,
+///     c_str_literal(b"\x80a").hex_escapes(true).to_string(),
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn c_str_literal<T>(value: &T) -> CStrLiteral<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    CStrLiteral {
+        value,
+        hex_escapes: false,
+    }
+}
@@ -0,0 +1,466 @@
+use alloc::vec::Vec;
+
+use super::chunks::Utf8Chunks;
+use super::ByteStr;
+use super::ToBytes;
+#[cfg(any(doc, windows))]
+use super::WideStr;
+
+/// The quoting styles supported by [`ls_quote`].
+///
+/// Each variant corresponds to a value of the `--quoting-style` option of
+/// GNU `ls`, and its output is identical to that of `ls` running with a
+/// UTF-8 locale and no translations. Characters are considered to be
+/// printable unless they are control characters.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum QuotingStyle {
+    /// Output values unchanged (`literal`).
+    Literal,
+
+    /// Enclose values in single quotes when they contain characters that are
+    /// special to a shell (`shell`).
+    Shell,
+
+    /// Always enclose values in single quotes (`shell-always`).
+    ShellAlways,
+
+    /// Similar to [`Self::Shell`], but write nonprintable characters using
+    /// `$'...'` escapes (`shell-escape`).
+    ShellEscape,
+
+    /// Enclose values in double quotes and use C escapes (`c`).
+    C,
+
+    /// Similar to [`Self::C`], but omit the quotes and escape spaces
+    /// (`escape`).
+    Escape,
+
+    /// Similar to [`Self::C`], but use the quotes for the locale (`locale`).
+    /// For UTF-8 locales, these are `‘` and `’`.
+    Locale,
+}
+
+impl QuotingStyle {
+    /// Returns the style used by `ls` when neither `--quoting-style` nor the
+    /// `QUOTING_STYLE` environment variable is given.
+    ///
+    /// When writing to a terminal, `ls` also replaces nonprintable
+    /// characters with `?` for some styles. That behavior can be enabled
+    /// using [`LsQuote::hide_control_chars`].
+    #[inline]
+    #[must_use]
+    pub const fn ls_default(is_terminal: bool) -> Self {
+        if is_terminal {
+            Self::ShellEscape
+        } else {
+            Self::Literal
+        }
+    }
+}
+
+enum Item<'a> {
+    Char(char),
+    Invalid(&'a [u8]),
+}
+
+fn items(string: &[u8]) -> impl Iterator<Item = Item<'_>> {
+    Utf8Chunks(string).flat_map(|(valid, invalid)| {
+        valid
+            .chars()
+            .map(Item::Char)
+            .chain((!invalid.is_empty()).then_some(Item::Invalid(invalid)))
+    })
+}
+
+fn push_char(string: &mut Vec<u8>, ch: char) {
+    string.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+fn push_octal(string: &mut Vec<u8>, byte: u8) {
+    string.extend_from_slice(&[
+        b'0' + (byte >> 6),
+        b'0' + ((byte >> 3) & 7),
+        b'0' + (byte & 7),
+    ]);
+}
+
+fn c_escape(ch: char) -> Option<u8> {
+    Some(match ch {
+        '\x07' => b'a',
+        '\x08' => b'b',
+        '\t' => b't',
+        '\n' => b'n',
+        '\x0B' => b'v',
+        '\x0C' => b'f',
+        '\r' => b'r',
+        '\\' => b'\\',
+        _ => return None,
+    })
+}
+
+fn quote_c(
+    string: &mut Vec<u8>,
+    value: &[u8],
+    quotes: Option<(char, char)>,
+    escape_spaces: bool,
+) {
+    if let Some((left_quote, _)) = quotes {
+        push_char(string, left_quote);
+    }
+    let mut items = items(value).peekable();
+    while let Some(item) = items.next() {
+        let ch = match item {
+            Item::Char(ch) => ch,
+            Item::Invalid(bytes) => {
+                for &byte in bytes {
+                    string.push(b'\\');
+                    push_octal(string, byte);
+                }
+                continue;
+            }
+        };
+        if quotes.is_some_and(|(_, x)| x == ch) || (ch == ' ' && escape_spaces)
+        {
+            string.push(b'\\');
+            push_char(string, ch);
+        } else if ch == '\0' {
+            string.extend_from_slice(br"\0");
+            // Avoid changing the meaning of the escape.
+            if matches!(items.peek(), Some(Item::Char('0'..='9'))) {
+                string.extend_from_slice(b"00");
+            }
+        } else if let Some(escape) = c_escape(ch) {
+            string.extend_from_slice(&[b'\\', escape]);
+        } else if ch.is_control() {
+            for &byte in ch.encode_utf8(&mut [0; 4]).as_bytes() {
+                string.push(b'\\');
+                push_octal(string, byte);
+            }
+        } else {
+            push_char(string, ch);
+        }
+    }
+    if let Some((_, right_quote)) = quotes {
+        push_char(string, right_quote);
+    }
+}
+
+// Returned when quotes were elided but are necessary.
+struct Restart;
+
+struct ShellQuoter<'a> {
+    string: &'a mut Vec<u8>,
+    elide_quotes: bool,
+    is_escape_open: bool,
+    has_single_quote: bool,
+}
+
+impl ShellQuoter<'_> {
+    fn start_escape(&mut self) -> Result<(), Restart> {
+        if self.elide_quotes {
+            return Err(Restart);
+        }
+        if !self.is_escape_open {
+            self.string.extend_from_slice(b"'$'");
+            self.is_escape_open = true;
+        }
+        self.string.push(b'\\');
+        Ok(())
+    }
+
+    fn end_escape(&mut self) {
+        if self.is_escape_open {
+            self.string.extend_from_slice(b"''");
+            self.is_escape_open = false;
+        }
+    }
+
+    fn push_octal(&mut self, bytes: &[u8]) -> Result<(), Restart> {
+        for &byte in bytes {
+            self.start_escape()?;
+            push_octal(self.string, byte);
+        }
+        Ok(())
+    }
+
+    // Returns whether the value could be quoted using the C style without
+    // escapes.
+    fn quote(&mut self, value: &[u8], escape: bool) -> Result<bool, Restart> {
+        let mut is_c_compatible = true;
+
+        if !self.elide_quotes {
+            self.string.push(b'\'');
+        }
+        for (i, item) in items(value).enumerate() {
+            let ch = match item {
+                Item::Char(ch) => ch,
+                Item::Invalid(bytes) => {
+                    is_c_compatible = false;
+                    if escape {
+                        self.push_octal(bytes)?;
+                    } else {
+                        self.end_escape();
+                        self.string.extend_from_slice(bytes);
+                    }
+                    continue;
+                }
+            };
+
+            let mut is_compatible = false;
+            let escaped = match ch {
+                '\0' => escape.then_some(b'0'),
+                '\x07' | '\x08' | '\x0B' | '\x0C' => {
+                    c_escape(ch).filter(|_| escape)
+                }
+                '\t' | '\n' | '\r' if self.elide_quotes => {
+                    return Err(Restart);
+                }
+                '\t' | '\n' | '\r' => c_escape(ch).filter(|_| escape),
+                '{' | '}' if value.len() != 1 => None,
+                '#' | '~' if i != 0 => None,
+                ' ' | '#' | '\'' | '{' | '}' | '~' => {
+                    is_compatible = true;
+                    if self.elide_quotes {
+                        return Err(Restart);
+                    }
+                    if ch == '\'' {
+                        self.has_single_quote = true;
+                        self.string.extend_from_slice(br"'\'");
+                        self.is_escape_open = false;
+                    }
+                    None
+                }
+                '!' | '"' | '$' | '&' | '(' | ')' | '*' | ';' | '<' | '='
+                | '>' | '?' | '[' | '\\' | '^' | '`' | '|' => {
+                    if self.elide_quotes {
+                        return Err(Restart);
+                    }
+                    None
+                }
+                _ if ch.is_control() => {
+                    if escape {
+                        self.push_octal(
+                            ch.encode_utf8(&mut [0; 4]).as_bytes(),
+                        )?;
+                        is_c_compatible = false;
+                        continue;
+                    }
+                    None
+                }
+                _ => {
+                    is_compatible = true;
+                    None
+                }
+            };
+            is_c_compatible &= is_compatible;
+
+            if let Some(escaped) = escaped {
+                self.start_escape()?;
+                self.string.push(escaped);
+            } else {
+                self.end_escape();
+                push_char(self.string, ch);
+            }
+        }
+        if self.elide_quotes && value.is_empty() {
+            return Err(Restart);
+        }
+        if !self.elide_quotes {
+            self.string.push(b'\'');
+        }
+        Ok(is_c_compatible)
+    }
+}
+
+fn quote_shell(
+    string: &mut Vec<u8>,
+    value: &[u8],
+    elide_quotes: bool,
+    escape: bool,
+) {
+    let start = string.len();
+    let mut quoter = ShellQuoter {
+        string,
+        elide_quotes,
+        is_escape_open: false,
+        has_single_quote: false,
+    };
+    let is_c_compatible = loop {
+        match quoter.quote(value, escape) {
+            Ok(is_c_compatible) => break is_c_compatible,
+            Err(Restart) => {
+                quoter.string.truncate(start);
+                quoter.elide_quotes = false;
+                quoter.is_escape_open = false;
+            }
+        }
+    };
+    if !quoter.has_single_quote {
+        return;
+    }
+
+    // Single quotes are commonly used as apostrophes, so `ls` avoids
+    // escaping them when possible.
+    if is_c_compatible {
+        quoter.string.truncate(start);
+        quote_c(quoter.string, value, Some(('"', '"')), false);
+        return;
+    }
+
+    // Otherwise, `ls` quotes the value again without resetting whether an
+    // escape is open. When the first pass ended with an escape, the second
+    // pass closes it before the first unescaped character, or it does not
+    // open the first escape.
+    if quoter.is_escape_open {
+        quoter.string.truncate(start);
+        let result = quoter.quote(value, escape);
+        debug_assert!(result.is_ok());
+    }
+}
+
+fn hide_control_chars(string: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(string.len());
+    let mut chunks = Utf8Chunks(string).peekable();
+    while let Some((valid, invalid)) = chunks.next() {
+        for ch in valid.chars() {
+            if ch.is_control() {
+                result.push(b'?');
+            } else {
+                push_char(&mut result, ch);
+            }
+        }
+        // An incomplete character at the end is replaced by a single
+        // question mark.
+        let count = if chunks.peek().is_none() {
+            invalid.len().min(1)
+        } else {
+            invalid.len()
+        };
+        result.extend((0..count).map(|_| b'?'));
+    }
+    result
+}
+
+/// A value returned by [`ls_quote`].
+pub struct LsQuote<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    style: QuotingStyle,
+    hide_control_chars: bool,
+}
+
+impl<T> LsQuote<'_, T>
+where
+    T: ?Sized,
+{
+    /// Sets whether nonprintable characters should be replaced with `?` when
+    /// they would not be escaped, like the `--hide-control-chars` option of
+    /// `ls`.
+    ///
+    /// This option only affects [`QuotingStyle::Literal`],
+    /// [`QuotingStyle::Shell`], and [`QuotingStyle::ShellAlways`]. It is
+    /// disabled by default, but `ls` enables it when writing to a terminal.
+    #[inline]
+    #[must_use]
+    pub fn hide_control_chars(mut self, hide_control_chars: bool) -> Self {
+        self.hide_control_chars = hide_control_chars;
+        self
+    }
+}
+
+impl<T> ToBytes for LsQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn to_bytes(&self) -> ByteStr<'_> {
+        let value = self.value.to_bytes();
+        let value = value.0.as_bytes();
+
+        let mut string = Vec::with_capacity(value.len() + 2);
+        match self.style {
+            QuotingStyle::Literal => string.extend_from_slice(value),
+            QuotingStyle::Shell => {
+                quote_shell(&mut string, value, true, false)
+            }
+            QuotingStyle::ShellAlways => {
+                quote_shell(&mut string, value, false, false);
+            }
+            QuotingStyle::ShellEscape => {
+                quote_shell(&mut string, value, true, true);
+            }
+            QuotingStyle::C => {
+                quote_c(&mut string, value, Some(('"', '"')), false);
+            }
+            QuotingStyle::Escape => quote_c(&mut string, value, None, true),
+            QuotingStyle::Locale => quote_c(
+                &mut string,
+                value,
+                Some(('\u{2018}', '\u{2019}')),
+                false,
+            ),
+        }
+
+        if self.hide_control_chars
+            && matches!(
+                self.style,
+                QuotingStyle::Literal
+                    | QuotingStyle::Shell
+                    | QuotingStyle::ShellAlways,
+            )
+        {
+            string = hide_control_chars(&string);
+        }
+        ByteStr::from_vec(string)
+    }
+
+    #[cfg(any(doc, windows))]
+    #[inline]
+    fn to_wide(&self) -> Option<WideStr> {
+        None
+    }
+}
+
+/// Wraps a value to be quoted like a file name displayed by GNU `ls`.
+///
+/// The returned struct implements [`ToBytes`], since some styles do not
+/// escape invalid UTF-8. Thus, it can be written using any function in this
+/// crate. On Windows, the value is first converted using
+/// [`ToBytes::to_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::ls_quote;
+/// use print_bytes::write_lossy;
+/// use print_bytes::QuotingStyle;
+///
+/// let quote = |x: &[u8], style| {
+///     let mut string = Vec::new();
+///     write_lossy(&mut string, &ls_quote(x, style))
+///         .expect("failed writing to vector");
+///     string
+/// };
+/// assert_eq!(b"'foo bar'", &*quote(b"foo bar", QuotingStyle::Shell));
+/// assert_eq!(b"\"it's\"", &*quote(b"it's", QuotingStyle::Shell));
+/// assert_eq!(
+///     br"'foo'$'\n''bar'",
+///     &*quote(b"foo\nbar", QuotingStyle::ShellEscape),
+/// );
+/// assert_eq!(br"foo\ \200", &*quote(b"foo \x80", QuotingStyle::Escape));
+/// ```
+#[inline]
+#[must_use]
+pub fn ls_quote<T>(value: &T, style: QuotingStyle) -> LsQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    LsQuote {
+        value,
+        style,
+        hide_control_chars: false,
+    }
+}
@@ -0,0 +1,410 @@
+//! Functions for serializing and deserializing values losslessly using the
+//! [serde] crate.
+//!
+//! Values that are valid UTF-8 are serialized as plain strings, so they are
+//! readable in formats such as JSON. Otherwise, human-readable formats will
+//! receive a map with a single `"escaped"` key, whose value is the
+//! representation written by [`write_fmt_escaped`]. Other formats always
+//! receive the escaped representation as a string, since they cannot
+//! distinguish between these cases otherwise.
+//!
+//! The modules in this module can be used with the `#[serde(with = "...")]`
+//! attribute, and their functions reconstruct the exact bytes or platform
+//! string that was serialized. However, platform strings serialized on Windows
+//! may not be representable on other platforms, and vice versa.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Deserialize;
+//! use serde::Serialize;
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Entry {
+//!     #[serde(with = "print_bytes::serde::bytes")]
+//!     name: Vec<u8>,
+//! }
+//!
+//! let entry = Entry {
+//!     name: b"foo\x80.txt".to_vec(),
+//! };
+//! let json = serde_json::to_string(&entry)?;
+//! assert_eq!(r#"{"name":{"escaped":"foo\\x80.txt"}}"#, json);
+//! assert_eq!(entry, serde_json::from_str(&json)?);
+//! #
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+//!
+//! [serde]: https://crates.io/crates/serde
+//! [`write_fmt_escaped`]: super::write_fmt_escaped
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de;
+use ::serde::de::MapAccess;
+use ::serde::ser::SerializeMap;
+use ::serde::Deserializer;
+use ::serde::Serializer;
+
+use super::chunks;
+use super::fmt_write;
+use super::ToBytes;
+
+const ESCAPED_KEY: &str = "escaped";
+
+const INVALID_ESCAPE: &str = "invalid escape sequence";
+
+/// Serializes a value losslessly.
+///
+/// This function can be used with the `#[serde(serialize_with = "...")]`
+/// attribute for any type that implements [`ToBytes`]. The output can be
+/// deserialized by the modules in [this module][module].
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+///
+/// [module]: self
+#[inline]
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + ToBytes,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        if let Some(string) = chunks::to_string(value) {
+            return serializer.serialize_str(&string);
+        }
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            ESCAPED_KEY,
+            &fmt_write::to_escaped_string(value),
+        )?;
+        map.end()
+    } else {
+        serializer.serialize_str(&fmt_write::to_escaped_string(value))
+    }
+}
+
+trait Buffer: Default {
+    type Output;
+
+    fn push_str(&mut self, string: &str);
+
+    fn push_byte(&mut self, byte: u8) -> Result<(), &'static str>;
+
+    fn push_surrogate(&mut self, surrogate: u16) -> Result<(), &'static str>;
+
+    fn finish(self) -> Result<Self::Output, &'static str>;
+}
+
+impl Buffer for Vec<u8> {
+    type Output = Self;
+
+    fn push_str(&mut self, string: &str) {
+        self.extend_from_slice(string.as_bytes());
+    }
+
+    fn push_byte(&mut self, byte: u8) -> Result<(), &'static str> {
+        self.push(byte);
+        Ok(())
+    }
+
+    fn push_surrogate(&mut self, _: u16) -> Result<(), &'static str> {
+        Err("unpaired surrogates cannot be represented as bytes")
+    }
+
+    fn finish(self) -> Result<Self::Output, &'static str> {
+        Ok(self)
+    }
+}
+
+fn parse_hex<T>(string: &str) -> Option<T>
+where
+    T: TryFrom<u32>,
+{
+    // [u32::from_str_radix] would accept a sign.
+    if string.is_empty()
+        || string.len() > 4
+        || !string.bytes().all(|x| x.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    u32::from_str_radix(string, 16)
+        .ok()
+        .and_then(|x| x.try_into().ok())
+}
+
+// Reverses the representation written by [write_fmt_escaped].
+fn unescape<B>(mut string: &str, buffer: &mut B) -> Result<(), &'static str>
+where
+    B: Buffer,
+{
+    while let Some(index) = string.find('\\') {
+        buffer.push_str(&string[..index]);
+        string = &string[index + 1..];
+
+        let escape_len = match string.as_bytes().first() {
+            Some(b'\\') => {
+                buffer.push_str("\\");
+                1
+            }
+            Some(b'x') => {
+                let byte = string
+                    .get(1..3)
+                    .and_then(parse_hex)
+                    .ok_or(INVALID_ESCAPE)?;
+                buffer.push_byte(byte)?;
+                3
+            }
+            Some(b'u') => {
+                let end = string.find('}').ok_or(INVALID_ESCAPE)?;
+                let surrogate = string[1..end]
+                    .strip_prefix('{')
+                    .and_then(parse_hex)
+                    .filter(|x| (0xD800..=0xDFFF).contains(x))
+                    .ok_or(INVALID_ESCAPE)?;
+                buffer.push_surrogate(surrogate)?;
+                end + 1
+            }
+            _ => return Err(INVALID_ESCAPE),
+        };
+        string = &string[escape_len..];
+    }
+    buffer.push_str(string);
+    Ok(())
+}
+
+struct Visitor<B> {
+    is_escaped: bool,
+    _buffer: PhantomData<B>,
+}
+
+impl<B> Visitor<B>
+where
+    B: Buffer,
+{
+    fn finish<E>(string: &str, is_escaped: bool) -> Result<B::Output, E>
+    where
+        E: de::Error,
+    {
+        let mut buffer = B::default();
+        if is_escaped {
+            unescape(string, &mut buffer).map_err(E::custom)?;
+        } else {
+            buffer.push_str(string);
+        }
+        buffer.finish().map_err(E::custom)
+    }
+}
+
+impl<'de, B> de::Visitor<'de> for Visitor<B>
+where
+    B: Buffer,
+{
+    type Value = B::Output;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_escaped {
+            f.write_str("an escaped string")
+        } else {
+            f.write_str("a string or a map with an escaped string")
+        }
+    }
+
+    fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::finish(string, self.is_escaped)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        if self.is_escaped {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+
+        let key: String = map
+            .next_key()?
+            .ok_or_else(|| de::Error::missing_field(ESCAPED_KEY))?;
+        if key != ESCAPED_KEY {
+            return Err(de::Error::unknown_field(&key, &[ESCAPED_KEY]));
+        }
+        let string: String = map.next_value()?;
+        if map.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        Self::finish(&string, true)
+    }
+}
+
+fn deserialize<'de, B, D>(deserializer: D) -> Result<B::Output, D::Error>
+where
+    B: Buffer,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(Visitor::<B> {
+            is_escaped: false,
+            _buffer: PhantomData,
+        })
+    } else {
+        deserializer.deserialize_str(Visitor::<B> {
+            is_escaped: true,
+            _buffer: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "os_str_bytes")]
+#[derive(Default)]
+struct OsStringBuffer(#[cfg(not(windows))] Vec<u8>, #[cfg(windows)] Vec<u16>);
+
+#[cfg(feature = "os_str_bytes")]
+impl Buffer for OsStringBuffer {
+    type Output = std::ffi::OsString;
+
+    fn push_str(&mut self, string: &str) {
+        #[cfg(windows)]
+        self.0.extend(string.encode_utf16());
+        #[cfg(not(windows))]
+        self.0.extend_from_slice(string.as_bytes());
+    }
+
+    fn push_byte(&mut self, byte: u8) -> Result<(), &'static str> {
+        #[cfg(windows)]
+        {
+            let _ = byte;
+            Err("invalid bytes cannot be represented on this platform")
+        }
+        #[cfg(not(windows))]
+        {
+            self.0.push(byte);
+            Ok(())
+        }
+    }
+
+    fn push_surrogate(&mut self, surrogate: u16) -> Result<(), &'static str> {
+        #[cfg(windows)]
+        {
+            self.0.push(surrogate);
+            Ok(())
+        }
+        #[cfg(not(windows))]
+        {
+            let _ = surrogate;
+            Err("unpaired surrogates cannot be represented on this platform")
+        }
+    }
+
+    fn finish(self) -> Result<Self::Output, &'static str> {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+
+            Ok(Self::Output::from_wide(&self.0))
+        }
+        #[cfg(not(windows))]
+        {
+            use os_str_bytes::OsStringBytes;
+
+            Self::Output::from_io_vec(self.0)
+                .ok_or("invalid bytes cannot be represented on this platform")
+        }
+    }
+}
+
+#[cfg(feature = "os_str_bytes")]
+#[derive(Default)]
+struct PathBufBuffer(OsStringBuffer);
+
+#[cfg(feature = "os_str_bytes")]
+impl Buffer for PathBufBuffer {
+    type Output = std::path::PathBuf;
+
+    fn push_str(&mut self, string: &str) {
+        self.0.push_str(string);
+    }
+
+    fn push_byte(&mut self, byte: u8) -> Result<(), &'static str> {
+        self.0.push_byte(byte)
+    }
+
+    fn push_surrogate(&mut self, surrogate: u16) -> Result<(), &'static str> {
+        self.0.push_surrogate(surrogate)
+    }
+
+    fn finish(self) -> Result<Self::Output, &'static str> {
+        self.0.finish().map(Into::into)
+    }
+}
+
+macro_rules! r#impl {
+    ( $name:ident , $type:ty , $buffer:ident $(, $feature:literal)? ) => {
+        #[doc = concat!(
+            "Functions for serializing [`",
+            stringify!($type),
+            "`] losslessly.",
+        )]
+        ///
+        /// For more information, see [the parent module][module].
+        ///
+        /// [module]: super
+        $(
+            #[cfg(feature = $feature)]
+            #[cfg_attr(print_bytes_docs_rs, doc(cfg(feature = $feature)))]
+        )?
+        pub mod $name {
+            use ::serde::Deserializer;
+            use ::serde::Serializer;
+
+            /// Serializes a value losslessly.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the serializer fails.
+            #[inline]
+            pub fn serialize<S>(
+                value: &$type,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                super::serialize(value, serializer)
+            }
+
+            /// Deserializes a value serialized by [`serialize`].
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the deserializer fails or the input
+            /// cannot be represented by the output type.
+            #[inline]
+            pub fn deserialize<'de, D>(
+                deserializer: D,
+            ) -> Result<$type, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::deserialize::<super::$buffer, _>(deserializer)
+            }
+        }
+    };
+}
+type BytesBuffer = Vec<u8>;
+
+r#impl!(bytes, alloc::vec::Vec<u8>, BytesBuffer);
+r#impl!(
+    os_string,
+    std::ffi::OsString,
+    OsStringBuffer,
+    "os_str_bytes"
+);
+r#impl!(path_buf, std::path::PathBuf, PathBufBuffer, "os_str_bytes");
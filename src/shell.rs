@@ -0,0 +1,465 @@
+use alloc::string::String;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::fmt::Write;
+
+use super::chunks;
+use super::chunks::Chunk;
+use super::chunks::Invalid;
+use super::ToBytes;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Quote {
+    None,
+    Single,
+    Dollar,
+}
+
+struct QuoteWriter<W> {
+    writer: W,
+    quote: Quote,
+}
+
+impl<W> QuoteWriter<W>
+where
+    W: Write,
+{
+    fn set_quote(&mut self, quote: Quote) -> fmt::Result {
+        if quote == self.quote {
+            return Ok(());
+        }
+        if self.quote != Quote::None {
+            self.writer.write_char('\'')?;
+        }
+        match quote {
+            Quote::None => {}
+            Quote::Single => self.writer.write_char('\'')?,
+            Quote::Dollar => self.writer.write_str("$'")?,
+        }
+        self.quote = quote;
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, string: &[u8]) -> fmt::Result {
+        self.set_quote(Quote::Dollar)?;
+        string
+            .iter()
+            .try_for_each(|x| write!(self.writer, r"\x{:02x}", x))
+    }
+
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        if ch == '\'' {
+            self.set_quote(Quote::None)?;
+            self.writer.write_str(r"\'")
+        } else if ch.is_control() {
+            self.write_bytes(ch.encode_utf8(&mut [0; 4]).as_bytes())
+        } else {
+            self.set_quote(Quote::Single)?;
+            self.writer.write_char(ch)
+        }
+    }
+}
+
+fn is_safe(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || "%+,-./:=@_".contains(ch)
+}
+
+pub(super) fn write_sh<T, W>(
+    mut writer: W,
+    value: &T,
+    always_quote: bool,
+) -> fmt::Result
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    if !always_quote {
+        let mut is_empty = true;
+        // A leading "=" would cause zsh to expand the word to the path of a
+        // command.
+        let is_safe = chunks::decode(value, |chunk| match chunk {
+            Chunk::Str(string)
+                if string.chars().all(is_safe)
+                    && !(is_empty && string.starts_with('=')) =>
+            {
+                is_empty = false;
+                Ok(())
+            }
+            _ => Err(()),
+        })
+        .is_ok();
+        if is_safe && !is_empty {
+            return chunks::decode(value, |chunk| match chunk {
+                Chunk::Str(string) => writer.write_str(string),
+                Chunk::Invalid(_) => unreachable!(),
+            });
+        }
+    }
+
+    let mut writer = QuoteWriter {
+        writer,
+        quote: Quote::None,
+    };
+    let mut is_empty = true;
+    chunks::decode(value, |chunk| {
+        is_empty = false;
+        match chunk {
+            Chunk::Str(string) => {
+                string.chars().try_for_each(|x| writer.write_char(x))
+            }
+            Chunk::Invalid(Invalid::Bytes(string)) => {
+                writer.write_bytes(string)
+            }
+            // Unpaired surrogates cannot be passed to a POSIX shell.
+            #[cfg(windows)]
+            Chunk::Invalid(Invalid::Surrogate(_)) => {
+                writer.write_char(REPLACEMENT_CHARACTER)
+            }
+        }
+    })?;
+    if is_empty {
+        writer.set_quote(Quote::Single)?;
+    }
+    writer.set_quote(Quote::None)
+}
+
+/// A value returned by [`shell_quote`].
+pub struct ShellQuote<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> fmt::Display for ShellQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_sh(f, self.0, false)
+    }
+}
+
+/// Wraps a value to be displayed as a word for a POSIX shell.
+///
+/// The returned struct implements [`fmt::Display`] using the shortest of a
+/// few quoting styles, so that a shell will parse the output as a single
+/// word containing exactly the original bytes:
+/// - Values containing only safe characters, such as `foo/bar.txt`, are
+///   displayed unchanged, unless they start with `=`, which Zsh would
+///   expand to the path of a command.
+/// - Other values are enclosed in single quotes, with each single quote
+///   written as `\'` outside of them.
+/// - Control characters and invalid bytes are written as `\xNN` within
+///   `$'...'` quotes.
+///
+/// The `$'...'` syntax is supported by Bash, Zsh, KornShell, and shells
+/// conforming to POSIX.1-2024, but not by some older shells. It is only
+/// used when necessary.
+///
+/// On Windows, unpaired surrogates cannot be represented and will be
+/// replaced with [`REPLACEMENT_CHARACTER`].
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::shell_quote;
+///
+/// assert_eq!("foo/bar.txt", shell_quote(b"foo/bar.txt").to_string());
+/// assert_eq!("'foo bar.txt'", shell_quote(b"foo bar.txt").to_string());
+/// assert_eq!(r"'foo'\''s'", shell_quote(b"foo's").to_string());
+/// assert_eq!(r"'foo'$'\x80'", shell_quote(b"foo\x80").to_string());
+/// ```
+///
+/// [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+#[inline]
+#[must_use]
+pub fn shell_quote<T>(value: &T) -> ShellQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    ShellQuote(value)
+}
+
+fn is_powershell_safe(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || "+-./:=\\_".contains(ch)
+}
+
+fn is_single_quote(ch: char) -> bool {
+    matches!(ch, '\'' | '\u{2018}'..='\u{201B}')
+}
+
+fn is_double_quote(ch: char) -> bool {
+    matches!(ch, '"' | '\u{201C}'..='\u{201E}')
+}
+
+fn write_powershell_char<W>(writer: &mut W, ch: char) -> fmt::Result
+where
+    W: Write,
+{
+    let escape = match ch {
+        '\0' => '0',
+        '\x07' => 'a',
+        '\x08' => 'b',
+        '\t' => 't',
+        '\n' => 'n',
+        '\x0B' => 'v',
+        '\x0C' => 'f',
+        '\r' => 'r',
+        '`' | '$' => ch,
+        _ if is_double_quote(ch) => ch,
+        _ if ch.is_control() => {
+            return write!(writer, "$([char]0x{:x})", u32::from(ch));
+        }
+        _ => return writer.write_char(ch),
+    };
+    writer.write_char('`')?;
+    writer.write_char(escape)
+}
+
+fn write_powershell<T, W>(mut writer: W, value: &T) -> fmt::Result
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    let mut is_empty = true;
+    let mut is_safe = true;
+    let mut is_printable = true;
+    let _ = chunks::decode(value, |chunk| {
+        match chunk {
+            Chunk::Str(string) => {
+                // Words starting with other characters could be parsed as
+                // numbers, parameter names, or operators.
+                if is_empty {
+                    is_safe &= string.starts_with(|x: char| {
+                        x.is_ascii_alphabetic() || "/\\_".contains(x)
+                    });
+                }
+                is_safe &= string.chars().all(is_powershell_safe);
+                is_printable &= !string.chars().any(char::is_control);
+            }
+            #[cfg(windows)]
+            Chunk::Invalid(Invalid::Surrogate(_)) => {
+                is_safe = false;
+                is_printable = false;
+            }
+            Chunk::Invalid(_) => is_safe = false,
+        }
+        is_empty = false;
+        Ok::<_, ()>(())
+    });
+    if is_safe && !is_empty {
+        return chunks::decode(value, |chunk| match chunk {
+            Chunk::Str(string) => writer.write_str(string),
+            Chunk::Invalid(_) => unreachable!(),
+        });
+    }
+
+    if is_printable {
+        writer.write_char('\'')?;
+        chunks::decode(value, |chunk| match chunk {
+            Chunk::Str(string) => string.chars().try_for_each(|x| {
+                if is_single_quote(x) {
+                    writer.write_char(x)?;
+                }
+                writer.write_char(x)
+            }),
+            Chunk::Invalid(_) => writer.write_char(REPLACEMENT_CHARACTER),
+        })?;
+        return writer.write_char('\'');
+    }
+
+    writer.write_char('"')?;
+    chunks::decode(value, |chunk| match chunk {
+        Chunk::Str(string) => string
+            .chars()
+            .try_for_each(|x| write_powershell_char(&mut writer, x)),
+        Chunk::Invalid(Invalid::Bytes(_)) => {
+            writer.write_char(REPLACEMENT_CHARACTER)
+        }
+        #[cfg(windows)]
+        Chunk::Invalid(Invalid::Surrogate(surrogate)) => {
+            write!(writer, "$([char]0x{:x})", surrogate)
+        }
+    })?;
+    writer.write_char('"')
+}
+
+/// A value returned by [`powershell_quote`].
+pub struct PowerShellQuote<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> fmt::Display for PowerShellQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_powershell(f, self.0)
+    }
+}
+
+/// Wraps a value to be displayed as a string argument for PowerShell.
+///
+/// The returned struct implements [`fmt::Display`] using the shortest of a
+/// few quoting styles, so that PowerShell will parse the output as a single
+/// string containing exactly the original characters:
+/// - Values containing only safe characters are displayed unchanged.
+/// - Other values are enclosed in single quotes, with each single quote
+///   doubled. This includes the typographic quotes that PowerShell also
+///   accepts.
+/// - Values containing control characters are enclosed in double quotes,
+///   using backtick escapes. Characters without an escape that is supported
+///   by Windows PowerShell 5.1 are written as `$([char]0xNN)`.
+///
+/// PowerShell strings are sequences of UTF-16 code units, so unpaired
+/// surrogates are preserved, but invalid bytes cannot be represented and
+/// will be replaced with [`REPLACEMENT_CHARACTER`].
+///
+/// This function does not account for the quoting performed by versions of
+/// PowerShell before 7.3 when passing arguments to native programs.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::powershell_quote;
+///
+/// let quote = |x: &[u8]| powershell_quote(x).to_string();
+/// assert_eq!(r"C:\foo\bar.txt", quote(br"C:\foo\bar.txt"));
+/// assert_eq!("'foo''s bar'", quote(b"foo's bar"));
+/// assert_eq!("\"foo`tbar`$\"", quote(b"foo\tbar$"));
+/// ```
+///
+/// [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+#[inline]
+#[must_use]
+pub fn powershell_quote<T>(value: &T) -> PowerShellQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    PowerShellQuote(value)
+}
+
+fn write_cmd_char<W>(writer: &mut W, ch: char) -> fmt::Result
+where
+    W: Write,
+{
+    match ch {
+        '%' => writer.write_str("%%"),
+        '!' | '"' | '&' | '(' | ')' | '<' | '>' | '^' | '|' => {
+            writer.write_char('^')?;
+            writer.write_char(ch)
+        }
+        _ => writer.write_char(ch),
+    }
+}
+
+fn write_cmd_backslashes<W>(writer: &mut W, count: usize) -> fmt::Result
+where
+    W: Write,
+{
+    (0..count).try_for_each(|_| writer.write_char('\\'))
+}
+
+fn write_cmd<T, W>(mut writer: W, value: &T) -> fmt::Result
+where
+    T: ?Sized + ToBytes,
+    W: Write,
+{
+    // Arguments are parsed by the program, which requires looking ahead to
+    // determine how backslashes should be escaped.
+    let mut string = String::new();
+    let _ = chunks::decode(value, |chunk| {
+        match chunk {
+            Chunk::Str(chunk) => string.extend(chunk.chars().map(|x| {
+                // These characters would end the command.
+                if matches!(x, '\0' | '\n' | '\r') {
+                    REPLACEMENT_CHARACTER
+                } else {
+                    x
+                }
+            })),
+            Chunk::Invalid(_) => string.push(REPLACEMENT_CHARACTER),
+        }
+        Ok::<_, ()>(())
+    });
+
+    if !string.is_empty() && !string.contains([' ', '\t', '"']) {
+        return string
+            .chars()
+            .try_for_each(|x| write_cmd_char(&mut writer, x));
+    }
+
+    write_cmd_char(&mut writer, '"')?;
+    let mut backslashes = 0;
+    for ch in string.chars() {
+        match ch {
+            '\\' => {
+                backslashes += 1;
+                continue;
+            }
+            '"' => {
+                write_cmd_backslashes(&mut writer, 2 * backslashes + 1)?;
+            }
+            _ => write_cmd_backslashes(&mut writer, backslashes)?,
+        }
+        backslashes = 0;
+        write_cmd_char(&mut writer, ch)?;
+    }
+    write_cmd_backslashes(&mut writer, 2 * backslashes)?;
+    write_cmd_char(&mut writer, '"')
+}
+
+/// A value returned by [`cmd_quote`].
+pub struct CmdQuote<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> fmt::Display for CmdQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cmd(f, self.0)
+    }
+}
+
+/// Wraps a value to be displayed as a program argument in a batch file for
+/// `cmd.exe`.
+///
+/// The returned struct implements [`fmt::Display`] by quoting the value in
+/// two layers, so that the program will receive it as a single argument:
+/// - The argument is quoted as expected by [`CommandLineToArgvW`] and the
+///   Microsoft C runtime, which most programs use to parse their command
+///   lines. Values containing whitespace or double quotes are enclosed in
+///   double quotes, and backslashes preceding a double quote are escaped.
+/// - Characters interpreted by `cmd.exe`, including the double quotes added
+///   above, are escaped with `^`. Percent signs are doubled instead, as
+///   required by batch files, and delayed expansion is assumed to be
+///   disabled.
+///
+/// Line breaks, null characters, invalid bytes, and unpaired surrogates
+/// cannot be represented and will be replaced with
+/// [`REPLACEMENT_CHARACTER`].
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::cmd_quote;
+///
+/// let quote = |x: &[u8]| cmd_quote(x).to_string();
+/// assert_eq!(r"C:\foo\bar.txt", quote(br"C:\foo\bar.txt"));
+/// assert_eq!(r#"^"C:\foo bar\\^""#, quote(br"C:\foo bar\"));
+/// assert_eq!("100%%^&1", quote(b"100%&1"));
+/// ```
+///
+/// [`CommandLineToArgvW`]: https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw
+/// [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+#[inline]
+#[must_use]
+pub fn cmd_quote<T>(value: &T) -> CmdQuote<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    CmdQuote(value)
+}
@@ -1,4 +1,4 @@
-#![cfg(windows)]
+#![cfg(all(feature = "std", windows))]
 
 use std::io;
 use std::io::Write;
@@ -0,0 +1,44 @@
+//! Functions for writing to asynchronous writers from the [tokio] crate.
+//!
+//! [tokio]: https://crates.io/crates/tokio
+
+impl_write_lossy_async!(
+    ::tokio::io::AsyncWrite,
+    super::async_io::is_tokio_console::<W>(),
+    /// Writes a value to an asynchronous "writer".
+    ///
+    /// This function is similar to [`write_lossy`] but accepts
+    /// implementations of [`AsyncWrite`]. The same representation of the
+    /// value will be written, except that Windows Console cannot be written
+    /// to losslessly. Output is only converted for the console when the
+    /// writer is [`Stdout`] or [`Stderr`], or a mutable reference to one.
+    ///
+    /// Like [`Stdout`], some writers must be flushed after writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use print_bytes::tokio::write_lossy_async;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # async fn write() -> io::Result<()> {
+    /// let mut stdout = tokio::io::stdout();
+    /// # #[cfg(feature = "os_str_bytes")]
+    /// write_lossy_async(&mut stdout, &env::current_exe()?).await?;
+    /// stdout.flush().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AsyncWrite`]: ::tokio::io::AsyncWrite
+    /// [`Stderr`]: ::tokio::io::Stderr
+    /// [`Stdout`]: ::tokio::io::Stdout
+    /// [`write_lossy`]: super::write_lossy
+);
@@ -0,0 +1,109 @@
+//! Functions for recording values as fields of spans and events from the
+//! [tracing] crate.
+//!
+//! [`Value`] cannot be implemented outside of [tracing], so these functions
+//! return existing implementations of it.
+//!
+//! # Examples
+//!
+//! ```
+//! use print_bytes::tracing::value;
+//!
+//! let file_name = b"foo\x80.txt";
+//! tracing::warn!(file_name = value(file_name), "skipping file");
+//! ```
+//!
+//! [tracing]: https://crates.io/crates/tracing
+
+use alloc::boxed::Box;
+
+use tracing::Value;
+
+use super::chunks;
+use super::fmt_write;
+use super::ToBytes;
+
+/// Converts a value to a field value.
+///
+/// When the value is valid UTF-8, it will be recorded as a string
+/// unchanged. Otherwise, the representation written by [`write_fmt_escaped`]
+/// will be recorded as a string instead. Either way, the same value will
+/// always be recorded the same way.
+///
+/// Valid UTF-8 that looks like an escape will not be distinguishable from
+/// bytes that had to be escaped. [`escaped_value`] can be used when the
+/// recorded string must be reversible.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::tracing::value;
+///
+/// # #[cfg(feature = "os_str_bytes")]
+/// tracing::info!(exe = value(&std::env::current_exe()?), "starting");
+/// #
+/// # Ok::<_, std::io::Error>(())
+/// ```
+///
+/// [`write_fmt_escaped`]: super::write_fmt_escaped
+#[inline]
+pub fn value<T>(value: &T) -> impl Value
+where
+    T: ?Sized + ToBytes,
+{
+    chunks::to_string(value)
+        .unwrap_or_else(|| fmt_write::to_escaped_string(value))
+}
+
+/// Converts a value to a field value, escaping it even when it is valid
+/// UTF-8.
+///
+/// The representation written by [`write_fmt_escaped`] will always be
+/// recorded as a string. Since backslashes are escaped by it, the original
+/// bytes can be recovered from the recorded string.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::tracing::escaped_value;
+///
+/// let file_name = br"C:\foo\x80.txt";
+/// tracing::warn!(file_name = escaped_value(file_name), "skipping file");
+/// ```
+///
+/// [`write_fmt_escaped`]: super::write_fmt_escaped
+#[inline]
+pub fn escaped_value<T>(value: &T) -> impl Value
+where
+    T: ?Sized + ToBytes,
+{
+    fmt_write::to_escaped_string(value)
+}
+
+/// Converts a value to a field value, recording raw bytes when necessary.
+///
+/// When the value is valid UTF-8, it will be recorded as a string
+/// unchanged. Otherwise, the bytes returned by [`ToBytes::to_bytes`] will be
+/// recorded using [`Visit::record_bytes`], which allows subscribers to
+/// preserve them.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::tracing::raw_value;
+///
+/// let file_name = b"foo\x80.txt";
+/// tracing::warn!(file_name = raw_value(file_name), "skipping file");
+/// ```
+///
+/// [`Visit::record_bytes`]: tracing::field::Visit::record_bytes
+#[inline]
+pub fn raw_value<T>(value: &T) -> impl Value
+where
+    T: ?Sized + ToBytes,
+{
+    match chunks::to_string(value) {
+        Some(string) => Box::new(string) as Box<dyn Value>,
+        None => Box::new(Box::<[_]>::from(value.to_bytes().0.as_bytes())),
+    }
+}
@@ -0,0 +1,4944 @@
+// This file is generated by tools/generate_unicode.py from the data
+// files of Unicode 16.0.0. Do not edit it directly.
+
+use core::cmp::Ordering;
+
+// Characters that are unlikely to be visible when displayed. This table
+// includes characters in the following general categories:
+// - Cc (Control), Cf (Format), Co (Private_Use), and Cn (Unassigned)
+// - Zl (Line_Separator) and Zp (Paragraph_Separator)
+// - Zs (Space_Separator), except for U+0020
+//
+// It also includes characters in other categories with the
+// Default_Ignorable_Code_Point property, such as variation selectors and
+// Hangul fillers. Surrogates are excluded, since they are not characters.
+const INVISIBLE: &[(char, char)] = &[
+    ('\u{0}', '\u{1F}'),
+    ('\u{7F}', '\u{A0}'),
+    ('\u{AD}', '\u{AD}'),
+    ('\u{34F}', '\u{34F}'),
+    ('\u{378}', '\u{379}'),
+    ('\u{380}', '\u{383}'),
+    ('\u{38B}', '\u{38B}'),
+    ('\u{38D}', '\u{38D}'),
+    ('\u{3A2}', '\u{3A2}'),
+    ('\u{530}', '\u{530}'),
+    ('\u{557}', '\u{558}'),
+    ('\u{58B}', '\u{58C}'),
+    ('\u{590}', '\u{590}'),
+    ('\u{5C8}', '\u{5CF}'),
+    ('\u{5EB}', '\u{5EE}'),
+    ('\u{5F5}', '\u{605}'),
+    ('\u{61C}', '\u{61C}'),
+    ('\u{6DD}', '\u{6DD}'),
+    ('\u{70E}', '\u{70F}'),
+    ('\u{74B}', '\u{74C}'),
+    ('\u{7B2}', '\u{7BF}'),
+    ('\u{7FB}', '\u{7FC}'),
+    ('\u{82E}', '\u{82F}'),
+    ('\u{83F}', '\u{83F}'),
+    ('\u{85C}', '\u{85D}'),
+    ('\u{85F}', '\u{85F}'),
+    ('\u{86B}', '\u{86F}'),
+    ('\u{88F}', '\u{896}'),
+    ('\u{8E2}', '\u{8E2}'),
+    ('\u{984}', '\u{984}'),
+    ('\u{98D}', '\u{98E}'),
+    ('\u{991}', '\u{992}'),
+    ('\u{9A9}', '\u{9A9}'),
+    ('\u{9B1}', '\u{9B1}'),
+    ('\u{9B3}', '\u{9B5}'),
+    ('\u{9BA}', '\u{9BB}'),
+    ('\u{9C5}', '\u{9C6}'),
+    ('\u{9C9}', '\u{9CA}'),
+    ('\u{9CF}', '\u{9D6}'),
+    ('\u{9D8}', '\u{9DB}'),
+    ('\u{9DE}', '\u{9DE}'),
+    ('\u{9E4}', '\u{9E5}'),
+    ('\u{9FF}', '\u{A00}'),
+    ('\u{A04}', '\u{A04}'),
+    ('\u{A0B}', '\u{A0E}'),
+    ('\u{A11}', '\u{A12}'),
+    ('\u{A29}', '\u{A29}'),
+    ('\u{A31}', '\u{A31}'),
+    ('\u{A34}', '\u{A34}'),
+    ('\u{A37}', '\u{A37}'),
+    ('\u{A3A}', '\u{A3B}'),
+    ('\u{A3D}', '\u{A3D}'),
+    ('\u{A43}', '\u{A46}'),
+    ('\u{A49}', '\u{A4A}'),
+    ('\u{A4E}', '\u{A50}'),
+    ('\u{A52}', '\u{A58}'),
+    ('\u{A5D}', '\u{A5D}'),
+    ('\u{A5F}', '\u{A65}'),
+    ('\u{A77}', '\u{A80}'),
+    ('\u{A84}', '\u{A84}'),
+    ('\u{A8E}', '\u{A8E}'),
+    ('\u{A92}', '\u{A92}'),
+    ('\u{AA9}', '\u{AA9}'),
+    ('\u{AB1}', '\u{AB1}'),
+    ('\u{AB4}', '\u{AB4}'),
+    ('\u{ABA}', '\u{ABB}'),
+    ('\u{AC6}', '\u{AC6}'),
+    ('\u{ACA}', '\u{ACA}'),
+    ('\u{ACE}', '\u{ACF}'),
+    ('\u{AD1}', '\u{ADF}'),
+    ('\u{AE4}', '\u{AE5}'),
+    ('\u{AF2}', '\u{AF8}'),
+    ('\u{B00}', '\u{B00}'),
+    ('\u{B04}', '\u{B04}'),
+    ('\u{B0D}', '\u{B0E}'),
+    ('\u{B11}', '\u{B12}'),
+    ('\u{B29}', '\u{B29}'),
+    ('\u{B31}', '\u{B31}'),
+    ('\u{B34}', '\u{B34}'),
+    ('\u{B3A}', '\u{B3B}'),
+    ('\u{B45}', '\u{B46}'),
+    ('\u{B49}', '\u{B4A}'),
+    ('\u{B4E}', '\u{B54}'),
+    ('\u{B58}', '\u{B5B}'),
+    ('\u{B5E}', '\u{B5E}'),
+    ('\u{B64}', '\u{B65}'),
+    ('\u{B78}', '\u{B81}'),
+    ('\u{B84}', '\u{B84}'),
+    ('\u{B8B}', '\u{B8D}'),
+    ('\u{B91}', '\u{B91}'),
+    ('\u{B96}', '\u{B98}'),
+    ('\u{B9B}', '\u{B9B}'),
+    ('\u{B9D}', '\u{B9D}'),
+    ('\u{BA0}', '\u{BA2}'),
+    ('\u{BA5}', '\u{BA7}'),
+    ('\u{BAB}', '\u{BAD}'),
+    ('\u{BBA}', '\u{BBD}'),
+    ('\u{BC3}', '\u{BC5}'),
+    ('\u{BC9}', '\u{BC9}'),
+    ('\u{BCE}', '\u{BCF}'),
+    ('\u{BD1}', '\u{BD6}'),
+    ('\u{BD8}', '\u{BE5}'),
+    ('\u{BFB}', '\u{BFF}'),
+    ('\u{C0D}', '\u{C0D}'),
+    ('\u{C11}', '\u{C11}'),
+    ('\u{C29}', '\u{C29}'),
+    ('\u{C3A}', '\u{C3B}'),
+    ('\u{C45}', '\u{C45}'),
+    ('\u{C49}', '\u{C49}'),
+    ('\u{C4E}', '\u{C54}'),
+    ('\u{C57}', '\u{C57}'),
+    ('\u{C5B}', '\u{C5C}'),
+    ('\u{C5E}', '\u{C5F}'),
+    ('\u{C64}', '\u{C65}'),
+    ('\u{C70}', '\u{C76}'),
+    ('\u{C8D}', '\u{C8D}'),
+    ('\u{C91}', '\u{C91}'),
+    ('\u{CA9}', '\u{CA9}'),
+    ('\u{CB4}', '\u{CB4}'),
+    ('\u{CBA}', '\u{CBB}'),
+    ('\u{CC5}', '\u{CC5}'),
+    ('\u{CC9}', '\u{CC9}'),
+    ('\u{CCE}', '\u{CD4}'),
+    ('\u{CD7}', '\u{CDC}'),
+    ('\u{CDF}', '\u{CDF}'),
+    ('\u{CE4}', '\u{CE5}'),
+    ('\u{CF0}', '\u{CF0}'),
+    ('\u{CF4}', '\u{CFF}'),
+    ('\u{D0D}', '\u{D0D}'),
+    ('\u{D11}', '\u{D11}'),
+    ('\u{D45}', '\u{D45}'),
+    ('\u{D49}', '\u{D49}'),
+    ('\u{D50}', '\u{D53}'),
+    ('\u{D64}', '\u{D65}'),
+    ('\u{D80}', '\u{D80}'),
+    ('\u{D84}', '\u{D84}'),
+    ('\u{D97}', '\u{D99}'),
+    ('\u{DB2}', '\u{DB2}'),
+    ('\u{DBC}', '\u{DBC}'),
+    ('\u{DBE}', '\u{DBF}'),
+    ('\u{DC7}', '\u{DC9}'),
+    ('\u{DCB}', '\u{DCE}'),
+    ('\u{DD5}', '\u{DD5}'),
+    ('\u{DD7}', '\u{DD7}'),
+    ('\u{DE0}', '\u{DE5}'),
+    ('\u{DF0}', '\u{DF1}'),
+    ('\u{DF5}', '\u{E00}'),
+    ('\u{E3B}', '\u{E3E}'),
+    ('\u{E5C}', '\u{E80}'),
+    ('\u{E83}', '\u{E83}'),
+    ('\u{E85}', '\u{E85}'),
+    ('\u{E8B}', '\u{E8B}'),
+    ('\u{EA4}', '\u{EA4}'),
+    ('\u{EA6}', '\u{EA6}'),
+    ('\u{EBE}', '\u{EBF}'),
+    ('\u{EC5}', '\u{EC5}'),
+    ('\u{EC7}', '\u{EC7}'),
+    ('\u{ECF}', '\u{ECF}'),
+    ('\u{EDA}', '\u{EDB}'),
+    ('\u{EE0}', '\u{EFF}'),
+    ('\u{F48}', '\u{F48}'),
+    ('\u{F6D}', '\u{F70}'),
+    ('\u{F98}', '\u{F98}'),
+    ('\u{FBD}', '\u{FBD}'),
+    ('\u{FCD}', '\u{FCD}'),
+    ('\u{FDB}', '\u{FFF}'),
+    ('\u{10C6}', '\u{10C6}'),
+    ('\u{10C8}', '\u{10CC}'),
+    ('\u{10CE}', '\u{10CF}'),
+    ('\u{115F}', '\u{1160}'),
+    ('\u{1249}', '\u{1249}'),
+    ('\u{124E}', '\u{124F}'),
+    ('\u{1257}', '\u{1257}'),
+    ('\u{1259}', '\u{1259}'),
+    ('\u{125E}', '\u{125F}'),
+    ('\u{1289}', '\u{1289}'),
+    ('\u{128E}', '\u{128F}'),
+    ('\u{12B1}', '\u{12B1}'),
+    ('\u{12B6}', '\u{12B7}'),
+    ('\u{12BF}', '\u{12BF}'),
+    ('\u{12C1}', '\u{12C1}'),
+    ('\u{12C6}', '\u{12C7}'),
+    ('\u{12D7}', '\u{12D7}'),
+    ('\u{1311}', '\u{1311}'),
+    ('\u{1316}', '\u{1317}'),
+    ('\u{135B}', '\u{135C}'),
+    ('\u{137D}', '\u{137F}'),
+    ('\u{139A}', '\u{139F}'),
+    ('\u{13F6}', '\u{13F7}'),
+    ('\u{13FE}', '\u{13FF}'),
+    ('\u{1680}', '\u{1680}'),
+    ('\u{169D}', '\u{169F}'),
+    ('\u{16F9}', '\u{16FF}'),
+    ('\u{1716}', '\u{171E}'),
+    ('\u{1737}', '\u{173F}'),
+    ('\u{1754}', '\u{175F}'),
+    ('\u{176D}', '\u{176D}'),
+    ('\u{1771}', '\u{1771}'),
+    ('\u{1774}', '\u{177F}'),
+    ('\u{17B4}', '\u{17B5}'),
+    ('\u{17DE}', '\u{17DF}'),
+    ('\u{17EA}', '\u{17EF}'),
+    ('\u{17FA}', '\u{17FF}'),
+    ('\u{180B}', '\u{180F}'),
+    ('\u{181A}', '\u{181F}'),
+    ('\u{1879}', '\u{187F}'),
+    ('\u{18AB}', '\u{18AF}'),
+    ('\u{18F6}', '\u{18FF}'),
+    ('\u{191F}', '\u{191F}'),
+    ('\u{192C}', '\u{192F}'),
+    ('\u{193C}', '\u{193F}'),
+    ('\u{1941}', '\u{1943}'),
+    ('\u{196E}', '\u{196F}'),
+    ('\u{1975}', '\u{197F}'),
+    ('\u{19AC}', '\u{19AF}'),
+    ('\u{19CA}', '\u{19CF}'),
+    ('\u{19DB}', '\u{19DD}'),
+    ('\u{1A1C}', '\u{1A1D}'),
+    ('\u{1A5F}', '\u{1A5F}'),
+    ('\u{1A7D}', '\u{1A7E}'),
+    ('\u{1A8A}', '\u{1A8F}'),
+    ('\u{1A9A}', '\u{1A9F}'),
+    ('\u{1AAE}', '\u{1AAF}'),
+    ('\u{1ACF}', '\u{1AFF}'),
+    ('\u{1B4D}', '\u{1B4D}'),
+    ('\u{1BF4}', '\u{1BFB}'),
+    ('\u{1C38}', '\u{1C3A}'),
+    ('\u{1C4A}', '\u{1C4C}'),
+    ('\u{1C8B}', '\u{1C8F}'),
+    ('\u{1CBB}', '\u{1CBC}'),
+    ('\u{1CC8}', '\u{1CCF}'),
+    ('\u{1CFB}', '\u{1CFF}'),
+    ('\u{1F16}', '\u{1F17}'),
+    ('\u{1F1E}', '\u{1F1F}'),
+    ('\u{1F46}', '\u{1F47}'),
+    ('\u{1F4E}', '\u{1F4F}'),
+    ('\u{1F58}', '\u{1F58}'),
+    ('\u{1F5A}', '\u{1F5A}'),
+    ('\u{1F5C}', '\u{1F5C}'),
+    ('\u{1F5E}', '\u{1F5E}'),
+    ('\u{1F7E}', '\u{1F7F}'),
+    ('\u{1FB5}', '\u{1FB5}'),
+    ('\u{1FC5}', '\u{1FC5}'),
+    ('\u{1FD4}', '\u{1FD5}'),
+    ('\u{1FDC}', '\u{1FDC}'),
+    ('\u{1FF0}', '\u{1FF1}'),
+    ('\u{1FF5}', '\u{1FF5}'),
+    ('\u{1FFF}', '\u{200F}'),
+    ('\u{2028}', '\u{202F}'),
+    ('\u{205F}', '\u{206F}'),
+    ('\u{2072}', '\u{2073}'),
+    ('\u{208F}', '\u{208F}'),
+    ('\u{209D}', '\u{209F}'),
+    ('\u{20C1}', '\u{20CF}'),
+    ('\u{20F1}', '\u{20FF}'),
+    ('\u{218C}', '\u{218F}'),
+    ('\u{242A}', '\u{243F}'),
+    ('\u{244B}', '\u{245F}'),
+    ('\u{2B74}', '\u{2B75}'),
+    ('\u{2B96}', '\u{2B96}'),
+    ('\u{2CF4}', '\u{2CF8}'),
+    ('\u{2D26}', '\u{2D26}'),
+    ('\u{2D28}', '\u{2D2C}'),
+    ('\u{2D2E}', '\u{2D2F}'),
+    ('\u{2D68}', '\u{2D6E}'),
+    ('\u{2D71}', '\u{2D7E}'),
+    ('\u{2D97}', '\u{2D9F}'),
+    ('\u{2DA7}', '\u{2DA7}'),
+    ('\u{2DAF}', '\u{2DAF}'),
+    ('\u{2DB7}', '\u{2DB7}'),
+    ('\u{2DBF}', '\u{2DBF}'),
+    ('\u{2DC7}', '\u{2DC7}'),
+    ('\u{2DCF}', '\u{2DCF}'),
+    ('\u{2DD7}', '\u{2DD7}'),
+    ('\u{2DDF}', '\u{2DDF}'),
+    ('\u{2E5E}', '\u{2E7F}'),
+    ('\u{2E9A}', '\u{2E9A}'),
+    ('\u{2EF4}', '\u{2EFF}'),
+    ('\u{2FD6}', '\u{2FEF}'),
+    ('\u{3000}', '\u{3000}'),
+    ('\u{3040}', '\u{3040}'),
+    ('\u{3097}', '\u{3098}'),
+    ('\u{3100}', '\u{3104}'),
+    ('\u{3130}', '\u{3130}'),
+    ('\u{3164}', '\u{3164}'),
+    ('\u{318F}', '\u{318F}'),
+    ('\u{31E6}', '\u{31EE}'),
+    ('\u{321F}', '\u{321F}'),
+    ('\u{A48D}', '\u{A48F}'),
+    ('\u{A4C7}', '\u{A4CF}'),
+    ('\u{A62C}', '\u{A63F}'),
+    ('\u{A6F8}', '\u{A6FF}'),
+    ('\u{A7CE}', '\u{A7CF}'),
+    ('\u{A7D2}', '\u{A7D2}'),
+    ('\u{A7D4}', '\u{A7D4}'),
+    ('\u{A7DD}', '\u{A7F1}'),
+    ('\u{A82D}', '\u{A82F}'),
+    ('\u{A83A}', '\u{A83F}'),
+    ('\u{A878}', '\u{A87F}'),
+    ('\u{A8C6}', '\u{A8CD}'),
+    ('\u{A8DA}', '\u{A8DF}'),
+    ('\u{A954}', '\u{A95E}'),
+    ('\u{A97D}', '\u{A97F}'),
+    ('\u{A9CE}', '\u{A9CE}'),
+    ('\u{A9DA}', '\u{A9DD}'),
+    ('\u{A9FF}', '\u{A9FF}'),
+    ('\u{AA37}', '\u{AA3F}'),
+    ('\u{AA4E}', '\u{AA4F}'),
+    ('\u{AA5A}', '\u{AA5B}'),
+    ('\u{AAC3}', '\u{AADA}'),
+    ('\u{AAF7}', '\u{AB00}'),
+    ('\u{AB07}', '\u{AB08}'),
+    ('\u{AB0F}', '\u{AB10}'),
+    ('\u{AB17}', '\u{AB1F}'),
+    ('\u{AB27}', '\u{AB27}'),
+    ('\u{AB2F}', '\u{AB2F}'),
+    ('\u{AB6C}', '\u{AB6F}'),
+    ('\u{ABEE}', '\u{ABEF}'),
+    ('\u{ABFA}', '\u{ABFF}'),
+    ('\u{D7A4}', '\u{D7AF}'),
+    ('\u{D7C7}', '\u{D7CA}'),
+    ('\u{D7FC}', '\u{D7FF}'),
+    ('\u{E000}', '\u{F8FF}'),
+    ('\u{FA6E}', '\u{FA6F}'),
+    ('\u{FADA}', '\u{FAFF}'),
+    ('\u{FB07}', '\u{FB12}'),
+    ('\u{FB18}', '\u{FB1C}'),
+    ('\u{FB37}', '\u{FB37}'),
+    ('\u{FB3D}', '\u{FB3D}'),
+    ('\u{FB3F}', '\u{FB3F}'),
+    ('\u{FB42}', '\u{FB42}'),
+    ('\u{FB45}', '\u{FB45}'),
+    ('\u{FBC3}', '\u{FBD2}'),
+    ('\u{FD90}', '\u{FD91}'),
+    ('\u{FDC8}', '\u{FDCE}'),
+    ('\u{FDD0}', '\u{FDEF}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FE1A}', '\u{FE1F}'),
+    ('\u{FE53}', '\u{FE53}'),
+    ('\u{FE67}', '\u{FE67}'),
+    ('\u{FE6C}', '\u{FE6F}'),
+    ('\u{FE75}', '\u{FE75}'),
+    ('\u{FEFD}', '\u{FF00}'),
+    ('\u{FFA0}', '\u{FFA0}'),
+    ('\u{FFBF}', '\u{FFC1}'),
+    ('\u{FFC8}', '\u{FFC9}'),
+    ('\u{FFD0}', '\u{FFD1}'),
+    ('\u{FFD8}', '\u{FFD9}'),
+    ('\u{FFDD}', '\u{FFDF}'),
+    ('\u{FFE7}', '\u{FFE7}'),
+    ('\u{FFEF}', '\u{FFFB}'),
+    ('\u{FFFE}', '\u{FFFF}'),
+    ('\u{1000C}', '\u{1000C}'),
+    ('\u{10027}', '\u{10027}'),
+    ('\u{1003B}', '\u{1003B}'),
+    ('\u{1003E}', '\u{1003E}'),
+    ('\u{1004E}', '\u{1004F}'),
+    ('\u{1005E}', '\u{1007F}'),
+    ('\u{100FB}', '\u{100FF}'),
+    ('\u{10103}', '\u{10106}'),
+    ('\u{10134}', '\u{10136}'),
+    ('\u{1018F}', '\u{1018F}'),
+    ('\u{1019D}', '\u{1019F}'),
+    ('\u{101A1}', '\u{101CF}'),
+    ('\u{101FE}', '\u{1027F}'),
+    ('\u{1029D}', '\u{1029F}'),
+    ('\u{102D1}', '\u{102DF}'),
+    ('\u{102FC}', '\u{102FF}'),
+    ('\u{10324}', '\u{1032C}'),
+    ('\u{1034B}', '\u{1034F}'),
+    ('\u{1037B}', '\u{1037F}'),
+    ('\u{1039E}', '\u{1039E}'),
+    ('\u{103C4}', '\u{103C7}'),
+    ('\u{103D6}', '\u{103FF}'),
+    ('\u{1049E}', '\u{1049F}'),
+    ('\u{104AA}', '\u{104AF}'),
+    ('\u{104D4}', '\u{104D7}'),
+    ('\u{104FC}', '\u{104FF}'),
+    ('\u{10528}', '\u{1052F}'),
+    ('\u{10564}', '\u{1056E}'),
+    ('\u{1057B}', '\u{1057B}'),
+    ('\u{1058B}', '\u{1058B}'),
+    ('\u{10593}', '\u{10593}'),
+    ('\u{10596}', '\u{10596}'),
+    ('\u{105A2}', '\u{105A2}'),
+    ('\u{105B2}', '\u{105B2}'),
+    ('\u{105BA}', '\u{105BA}'),
+    ('\u{105BD}', '\u{105BF}'),
+    ('\u{105F4}', '\u{105FF}'),
+    ('\u{10737}', '\u{1073F}'),
+    ('\u{10756}', '\u{1075F}'),
+    ('\u{10768}', '\u{1077F}'),
+    ('\u{10786}', '\u{10786}'),
+    ('\u{107B1}', '\u{107B1}'),
+    ('\u{107BB}', '\u{107FF}'),
+    ('\u{10806}', '\u{10807}'),
+    ('\u{10809}', '\u{10809}'),
+    ('\u{10836}', '\u{10836}'),
+    ('\u{10839}', '\u{1083B}'),
+    ('\u{1083D}', '\u{1083E}'),
+    ('\u{10856}', '\u{10856}'),
+    ('\u{1089F}', '\u{108A6}'),
+    ('\u{108B0}', '\u{108DF}'),
+    ('\u{108F3}', '\u{108F3}'),
+    ('\u{108F6}', '\u{108FA}'),
+    ('\u{1091C}', '\u{1091E}'),
+    ('\u{1093A}', '\u{1093E}'),
+    ('\u{10940}', '\u{1097F}'),
+    ('\u{109B8}', '\u{109BB}'),
+    ('\u{109D0}', '\u{109D1}'),
+    ('\u{10A04}', '\u{10A04}'),
+    ('\u{10A07}', '\u{10A0B}'),
+    ('\u{10A14}', '\u{10A14}'),
+    ('\u{10A18}', '\u{10A18}'),
+    ('\u{10A36}', '\u{10A37}'),
+    ('\u{10A3B}', '\u{10A3E}'),
+    ('\u{10A49}', '\u{10A4F}'),
+    ('\u{10A59}', '\u{10A5F}'),
+    ('\u{10AA0}', '\u{10ABF}'),
+    ('\u{10AE7}', '\u{10AEA}'),
+    ('\u{10AF7}', '\u{10AFF}'),
+    ('\u{10B36}', '\u{10B38}'),
+    ('\u{10B56}', '\u{10B57}'),
+    ('\u{10B73}', '\u{10B77}'),
+    ('\u{10B92}', '\u{10B98}'),
+    ('\u{10B9D}', '\u{10BA8}'),
+    ('\u{10BB0}', '\u{10BFF}'),
+    ('\u{10C49}', '\u{10C7F}'),
+    ('\u{10CB3}', '\u{10CBF}'),
+    ('\u{10CF3}', '\u{10CF9}'),
+    ('\u{10D28}', '\u{10D2F}'),
+    ('\u{10D3A}', '\u{10D3F}'),
+    ('\u{10D66}', '\u{10D68}'),
+    ('\u{10D86}', '\u{10D8D}'),
+    ('\u{10D90}', '\u{10E5F}'),
+    ('\u{10E7F}', '\u{10E7F}'),
+    ('\u{10EAA}', '\u{10EAA}'),
+    ('\u{10EAE}', '\u{10EAF}'),
+    ('\u{10EB2}', '\u{10EC1}'),
+    ('\u{10EC5}', '\u{10EFB}'),
+    ('\u{10F28}', '\u{10F2F}'),
+    ('\u{10F5A}', '\u{10F6F}'),
+    ('\u{10F8A}', '\u{10FAF}'),
+    ('\u{10FCC}', '\u{10FDF}'),
+    ('\u{10FF7}', '\u{10FFF}'),
+    ('\u{1104E}', '\u{11051}'),
+    ('\u{11076}', '\u{1107E}'),
+    ('\u{110BD}', '\u{110BD}'),
+    ('\u{110C3}', '\u{110CF}'),
+    ('\u{110E9}', '\u{110EF}'),
+    ('\u{110FA}', '\u{110FF}'),
+    ('\u{11135}', '\u{11135}'),
+    ('\u{11148}', '\u{1114F}'),
+    ('\u{11177}', '\u{1117F}'),
+    ('\u{111E0}', '\u{111E0}'),
+    ('\u{111F5}', '\u{111FF}'),
+    ('\u{11212}', '\u{11212}'),
+    ('\u{11242}', '\u{1127F}'),
+    ('\u{11287}', '\u{11287}'),
+    ('\u{11289}', '\u{11289}'),
+    ('\u{1128E}', '\u{1128E}'),
+    ('\u{1129E}', '\u{1129E}'),
+    ('\u{112AA}', '\u{112AF}'),
+    ('\u{112EB}', '\u{112EF}'),
+    ('\u{112FA}', '\u{112FF}'),
+    ('\u{11304}', '\u{11304}'),
+    ('\u{1130D}', '\u{1130E}'),
+    ('\u{11311}', '\u{11312}'),
+    ('\u{11329}', '\u{11329}'),
+    ('\u{11331}', '\u{11331}'),
+    ('\u{11334}', '\u{11334}'),
+    ('\u{1133A}', '\u{1133A}'),
+    ('\u{11345}', '\u{11346}'),
+    ('\u{11349}', '\u{1134A}'),
+    ('\u{1134E}', '\u{1134F}'),
+    ('\u{11351}', '\u{11356}'),
+    ('\u{11358}', '\u{1135C}'),
+    ('\u{11364}', '\u{11365}'),
+    ('\u{1136D}', '\u{1136F}'),
+    ('\u{11375}', '\u{1137F}'),
+    ('\u{1138A}', '\u{1138A}'),
+    ('\u{1138C}', '\u{1138D}'),
+    ('\u{1138F}', '\u{1138F}'),
+    ('\u{113B6}', '\u{113B6}'),
+    ('\u{113C1}', '\u{113C1}'),
+    ('\u{113C3}', '\u{113C4}'),
+    ('\u{113C6}', '\u{113C6}'),
+    ('\u{113CB}', '\u{113CB}'),
+    ('\u{113D6}', '\u{113D6}'),
+    ('\u{113D9}', '\u{113E0}'),
+    ('\u{113E3}', '\u{113FF}'),
+    ('\u{1145C}', '\u{1145C}'),
+    ('\u{11462}', '\u{1147F}'),
+    ('\u{114C8}', '\u{114CF}'),
+    ('\u{114DA}', '\u{1157F}'),
+    ('\u{115B6}', '\u{115B7}'),
+    ('\u{115DE}', '\u{115FF}'),
+    ('\u{11645}', '\u{1164F}'),
+    ('\u{1165A}', '\u{1165F}'),
+    ('\u{1166D}', '\u{1167F}'),
+    ('\u{116BA}', '\u{116BF}'),
+    ('\u{116CA}', '\u{116CF}'),
+    ('\u{116E4}', '\u{116FF}'),
+    ('\u{1171B}', '\u{1171C}'),
+    ('\u{1172C}', '\u{1172F}'),
+    ('\u{11747}', '\u{117FF}'),
+    ('\u{1183C}', '\u{1189F}'),
+    ('\u{118F3}', '\u{118FE}'),
+    ('\u{11907}', '\u{11908}'),
+    ('\u{1190A}', '\u{1190B}'),
+    ('\u{11914}', '\u{11914}'),
+    ('\u{11917}', '\u{11917}'),
+    ('\u{11936}', '\u{11936}'),
+    ('\u{11939}', '\u{1193A}'),
+    ('\u{11947}', '\u{1194F}'),
+    ('\u{1195A}', '\u{1199F}'),
+    ('\u{119A8}', '\u{119A9}'),
+    ('\u{119D8}', '\u{119D9}'),
+    ('\u{119E5}', '\u{119FF}'),
+    ('\u{11A48}', '\u{11A4F}'),
+    ('\u{11AA3}', '\u{11AAF}'),
+    ('\u{11AF9}', '\u{11AFF}'),
+    ('\u{11B0A}', '\u{11BBF}'),
+    ('\u{11BE2}', '\u{11BEF}'),
+    ('\u{11BFA}', '\u{11BFF}'),
+    ('\u{11C09}', '\u{11C09}'),
+    ('\u{11C37}', '\u{11C37}'),
+    ('\u{11C46}', '\u{11C4F}'),
+    ('\u{11C6D}', '\u{11C6F}'),
+    ('\u{11C90}', '\u{11C91}'),
+    ('\u{11CA8}', '\u{11CA8}'),
+    ('\u{11CB7}', '\u{11CFF}'),
+    ('\u{11D07}', '\u{11D07}'),
+    ('\u{11D0A}', '\u{11D0A}'),
+    ('\u{11D37}', '\u{11D39}'),
+    ('\u{11D3B}', '\u{11D3B}'),
+    ('\u{11D3E}', '\u{11D3E}'),
+    ('\u{11D48}', '\u{11D4F}'),
+    ('\u{11D5A}', '\u{11D5F}'),
+    ('\u{11D66}', '\u{11D66}'),
+    ('\u{11D69}', '\u{11D69}'),
+    ('\u{11D8F}', '\u{11D8F}'),
+    ('\u{11D92}', '\u{11D92}'),
+    ('\u{11D99}', '\u{11D9F}'),
+    ('\u{11DAA}', '\u{11EDF}'),
+    ('\u{11EF9}', '\u{11EFF}'),
+    ('\u{11F11}', '\u{11F11}'),
+    ('\u{11F3B}', '\u{11F3D}'),
+    ('\u{11F5B}', '\u{11FAF}'),
+    ('\u{11FB1}', '\u{11FBF}'),
+    ('\u{11FF2}', '\u{11FFE}'),
+    ('\u{1239A}', '\u{123FF}'),
+    ('\u{1246F}', '\u{1246F}'),
+    ('\u{12475}', '\u{1247F}'),
+    ('\u{12544}', '\u{12F8F}'),
+    ('\u{12FF3}', '\u{12FFF}'),
+    ('\u{13430}', '\u{1343F}'),
+    ('\u{13456}', '\u{1345F}'),
+    ('\u{143FB}', '\u{143FF}'),
+    ('\u{14647}', '\u{160FF}'),
+    ('\u{1613A}', '\u{167FF}'),
+    ('\u{16A39}', '\u{16A3F}'),
+    ('\u{16A5F}', '\u{16A5F}'),
+    ('\u{16A6A}', '\u{16A6D}'),
+    ('\u{16ABF}', '\u{16ABF}'),
+    ('\u{16ACA}', '\u{16ACF}'),
+    ('\u{16AEE}', '\u{16AEF}'),
+    ('\u{16AF6}', '\u{16AFF}'),
+    ('\u{16B46}', '\u{16B4F}'),
+    ('\u{16B5A}', '\u{16B5A}'),
+    ('\u{16B62}', '\u{16B62}'),
+    ('\u{16B78}', '\u{16B7C}'),
+    ('\u{16B90}', '\u{16D3F}'),
+    ('\u{16D7A}', '\u{16E3F}'),
+    ('\u{16E9B}', '\u{16EFF}'),
+    ('\u{16F4B}', '\u{16F4E}'),
+    ('\u{16F88}', '\u{16F8E}'),
+    ('\u{16FA0}', '\u{16FDF}'),
+    ('\u{16FE5}', '\u{16FEF}'),
+    ('\u{16FF2}', '\u{16FFF}'),
+    ('\u{187F8}', '\u{187FF}'),
+    ('\u{18CD6}', '\u{18CFE}'),
+    ('\u{18D09}', '\u{1AFEF}'),
+    ('\u{1AFF4}', '\u{1AFF4}'),
+    ('\u{1AFFC}', '\u{1AFFC}'),
+    ('\u{1AFFF}', '\u{1AFFF}'),
+    ('\u{1B123}', '\u{1B131}'),
+    ('\u{1B133}', '\u{1B14F}'),
+    ('\u{1B153}', '\u{1B154}'),
+    ('\u{1B156}', '\u{1B163}'),
+    ('\u{1B168}', '\u{1B16F}'),
+    ('\u{1B2FC}', '\u{1BBFF}'),
+    ('\u{1BC6B}', '\u{1BC6F}'),
+    ('\u{1BC7D}', '\u{1BC7F}'),
+    ('\u{1BC89}', '\u{1BC8F}'),
+    ('\u{1BC9A}', '\u{1BC9B}'),
+    ('\u{1BCA0}', '\u{1CBFF}'),
+    ('\u{1CCFA}', '\u{1CCFF}'),
+    ('\u{1CEB4}', '\u{1CEFF}'),
+    ('\u{1CF2E}', '\u{1CF2F}'),
+    ('\u{1CF47}', '\u{1CF4F}'),
+    ('\u{1CFC4}', '\u{1CFFF}'),
+    ('\u{1D0F6}', '\u{1D0FF}'),
+    ('\u{1D127}', '\u{1D128}'),
+    ('\u{1D173}', '\u{1D17A}'),
+    ('\u{1D1EB}', '\u{1D1FF}'),
+    ('\u{1D246}', '\u{1D2BF}'),
+    ('\u{1D2D4}', '\u{1D2DF}'),
+    ('\u{1D2F4}', '\u{1D2FF}'),
+    ('\u{1D357}', '\u{1D35F}'),
+    ('\u{1D379}', '\u{1D3FF}'),
+    ('\u{1D455}', '\u{1D455}'),
+    ('\u{1D49D}', '\u{1D49D}'),
+    ('\u{1D4A0}', '\u{1D4A1}'),
+    ('\u{1D4A3}', '\u{1D4A4}'),
+    ('\u{1D4A7}', '\u{1D4A8}'),
+    ('\u{1D4AD}', '\u{1D4AD}'),
+    ('\u{1D4BA}', '\u{1D4BA}'),
+    ('\u{1D4BC}', '\u{1D4BC}'),
+    ('\u{1D4C4}', '\u{1D4C4}'),
+    ('\u{1D506}', '\u{1D506}'),
+    ('\u{1D50B}', '\u{1D50C}'),
+    ('\u{1D515}', '\u{1D515}'),
+    ('\u{1D51D}', '\u{1D51D}'),
+    ('\u{1D53A}', '\u{1D53A}'),
+    ('\u{1D53F}', '\u{1D53F}'),
+    ('\u{1D545}', '\u{1D545}'),
+    ('\u{1D547}', '\u{1D549}'),
+    ('\u{1D551}', '\u{1D551}'),
+    ('\u{1D6A6}', '\u{1D6A7}'),
+    ('\u{1D7CC}', '\u{1D7CD}'),
+    ('\u{1DA8C}', '\u{1DA9A}'),
+    ('\u{1DAA0}', '\u{1DAA0}'),
+    ('\u{1DAB0}', '\u{1DEFF}'),
+    ('\u{1DF1F}', '\u{1DF24}'),
+    ('\u{1DF2B}', '\u{1DFFF}'),
+    ('\u{1E007}', '\u{1E007}'),
+    ('\u{1E019}', '\u{1E01A}'),
+    ('\u{1E022}', '\u{1E022}'),
+    ('\u{1E025}', '\u{1E025}'),
+    ('\u{1E02B}', '\u{1E02F}'),
+    ('\u{1E06E}', '\u{1E08E}'),
+    ('\u{1E090}', '\u{1E0FF}'),
+    ('\u{1E12D}', '\u{1E12F}'),
+    ('\u{1E13E}', '\u{1E13F}'),
+    ('\u{1E14A}', '\u{1E14D}'),
+    ('\u{1E150}', '\u{1E28F}'),
+    ('\u{1E2AF}', '\u{1E2BF}'),
+    ('\u{1E2FA}', '\u{1E2FE}'),
+    ('\u{1E300}', '\u{1E4CF}'),
+    ('\u{1E4FA}', '\u{1E5CF}'),
+    ('\u{1E5FB}', '\u{1E5FE}'),
+    ('\u{1E600}', '\u{1E7DF}'),
+    ('\u{1E7E7}', '\u{1E7E7}'),
+    ('\u{1E7EC}', '\u{1E7EC}'),
+    ('\u{1E7EF}', '\u{1E7EF}'),
+    ('\u{1E7FF}', '\u{1E7FF}'),
+    ('\u{1E8C5}', '\u{1E8C6}'),
+    ('\u{1E8D7}', '\u{1E8FF}'),
+    ('\u{1E94C}', '\u{1E94F}'),
+    ('\u{1E95A}', '\u{1E95D}'),
+    ('\u{1E960}', '\u{1EC70}'),
+    ('\u{1ECB5}', '\u{1ED00}'),
+    ('\u{1ED3E}', '\u{1EDFF}'),
+    ('\u{1EE04}', '\u{1EE04}'),
+    ('\u{1EE20}', '\u{1EE20}'),
+    ('\u{1EE23}', '\u{1EE23}'),
+    ('\u{1EE25}', '\u{1EE26}'),
+    ('\u{1EE28}', '\u{1EE28}'),
+    ('\u{1EE33}', '\u{1EE33}'),
+    ('\u{1EE38}', '\u{1EE38}'),
+    ('\u{1EE3A}', '\u{1EE3A}'),
+    ('\u{1EE3C}', '\u{1EE41}'),
+    ('\u{1EE43}', '\u{1EE46}'),
+    ('\u{1EE48}', '\u{1EE48}'),
+    ('\u{1EE4A}', '\u{1EE4A}'),
+    ('\u{1EE4C}', '\u{1EE4C}'),
+    ('\u{1EE50}', '\u{1EE50}'),
+    ('\u{1EE53}', '\u{1EE53}'),
+    ('\u{1EE55}', '\u{1EE56}'),
+    ('\u{1EE58}', '\u{1EE58}'),
+    ('\u{1EE5A}', '\u{1EE5A}'),
+    ('\u{1EE5C}', '\u{1EE5C}'),
+    ('\u{1EE5E}', '\u{1EE5E}'),
+    ('\u{1EE60}', '\u{1EE60}'),
+    ('\u{1EE63}', '\u{1EE63}'),
+    ('\u{1EE65}', '\u{1EE66}'),
+    ('\u{1EE6B}', '\u{1EE6B}'),
+    ('\u{1EE73}', '\u{1EE73}'),
+    ('\u{1EE78}', '\u{1EE78}'),
+    ('\u{1EE7D}', '\u{1EE7D}'),
+    ('\u{1EE7F}', '\u{1EE7F}'),
+    ('\u{1EE8A}', '\u{1EE8A}'),
+    ('\u{1EE9C}', '\u{1EEA0}'),
+    ('\u{1EEA4}', '\u{1EEA4}'),
+    ('\u{1EEAA}', '\u{1EEAA}'),
+    ('\u{1EEBC}', '\u{1EEEF}'),
+    ('\u{1EEF2}', '\u{1EFFF}'),
+    ('\u{1F02C}', '\u{1F02F}'),
+    ('\u{1F094}', '\u{1F09F}'),
+    ('\u{1F0AF}', '\u{1F0B0}'),
+    ('\u{1F0C0}', '\u{1F0C0}'),
+    ('\u{1F0D0}', '\u{1F0D0}'),
+    ('\u{1F0F6}', '\u{1F0FF}'),
+    ('\u{1F1AE}', '\u{1F1E5}'),
+    ('\u{1F203}', '\u{1F20F}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F24F}'),
+    ('\u{1F252}', '\u{1F25F}'),
+    ('\u{1F266}', '\u{1F2FF}'),
+    ('\u{1F6D8}', '\u{1F6DB}'),
+    ('\u{1F6ED}', '\u{1F6EF}'),
+    ('\u{1F6FD}', '\u{1F6FF}'),
+    ('\u{1F777}', '\u{1F77A}'),
+    ('\u{1F7DA}', '\u{1F7DF}'),
+    ('\u{1F7EC}', '\u{1F7EF}'),
+    ('\u{1F7F1}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8AF}'),
+    ('\u{1F8BC}', '\u{1F8BF}'),
+    ('\u{1F8C2}', '\u{1F8FF}'),
+    ('\u{1FA54}', '\u{1FA5F}'),
+    ('\u{1FA6E}', '\u{1FA6F}'),
+    ('\u{1FA7D}', '\u{1FA7F}'),
+    ('\u{1FA8A}', '\u{1FA8E}'),
+    ('\u{1FAC7}', '\u{1FACD}'),
+    ('\u{1FADD}', '\u{1FADE}'),
+    ('\u{1FAEA}', '\u{1FAEF}'),
+    ('\u{1FAF9}', '\u{1FAFF}'),
+    ('\u{1FB93}', '\u{1FB93}'),
+    ('\u{1FBFA}', '\u{1FFFF}'),
+    ('\u{2A6E0}', '\u{2A6FF}'),
+    ('\u{2B73A}', '\u{2B73F}'),
+    ('\u{2B81E}', '\u{2B81F}'),
+    ('\u{2CEA2}', '\u{2CEAF}'),
+    ('\u{2EBE1}', '\u{2EBEF}'),
+    ('\u{2EE5E}', '\u{2F7FF}'),
+    ('\u{2FA1E}', '\u{2FFFF}'),
+    ('\u{3134B}', '\u{3134F}'),
+    ('\u{323B0}', '\u{10FFFF}'),
+];
+
+pub(super) fn is_invisible(ch: char) -> bool {
+    INVISIBLE
+        .binary_search_by(|&(start, end)| {
+            if ch < start {
+                Ordering::Greater
+            } else if ch > end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// The scripts used by [SCRIPT_EXTENSIONS], sorted by their ISO 15924 codes.
+// This list includes the scripts used to augment script sets, as described
+// in section 5.1 of Unicode Technical Standard #39.
+pub(super) const SCRIPTS: [(&str, &str); 172] = [
+    ("Adlm", "Adlam"),
+    ("Aghb", "Caucasian Albanian"),
+    ("Ahom", "Ahom"),
+    ("Arab", "Arabic"),
+    ("Armi", "Imperial Aramaic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cakm", "Chakma"),
+    ("Cans", "Canadian Aboriginal"),
+    ("Cari", "Carian"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Copt", "Coptic"),
+    ("Cpmn", "Cypro Minoan"),
+    ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Gara", "Garay"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Gong", "Gunjala Gondi"),
+    ("Gonm", "Masaram Gondi"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gukh", "Gurung Khema"),
+    ("Guru", "Gurmukhi"),
+    ("Hanb", "Han with Bopomofo"),
+    ("Hang", "Hangul"),
+    ("Hani", "Han"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Hluw", "Anatolian Hieroglyphs"),
+    ("Hmng", "Pahawh Hmong"),
+    ("Hmnp", "Nyiakeng Puachue Hmong"),
+    ("Hung", "Old Hungarian"),
+    ("Ital", "Old Italic"),
+    ("Java", "Javanese"),
+    ("Jpan", "Japanese"),
+    ("Kali", "Kayah Li"),
+    ("Kana", "Katakana"),
+    ("Kawi", "Kawi"),
+    ("Khar", "Kharoshthi"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Kits", "Khitan Small Script"),
+    ("Knda", "Kannada"),
+    ("Kore", "Korean"),
+    ("Krai", "Kirat Rai"),
+    ("Kthi", "Kaithi"),
+    ("Lana", "Tai Tham"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear A"),
+    ("Linb", "Linear B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Medf", "Medefaidrin"),
+    ("Mend", "Mende Kikakui"),
+    ("Merc", "Meroitic Cursive"),
+    ("Mero", "Meroitic Hieroglyphs"),
+    ("Mlym", "Malayalam"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mtei", "Meetei Mayek"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nagm", "Nag Mundari"),
+    ("Nand", "Nandinagari"),
+    ("Narb", "Old North Arabian"),
+    ("Nbat", "Nabataean"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol Chiki"),
+    ("Onao", "Ol Onal"),
+    ("Orkh", "Old Turkic"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Ougr", "Old Uyghur"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau Cin Hau"),
+    ("Perm", "Old Permic"),
+    ("Phag", "Phags Pa"),
+    ("Phli", "Inscriptional Pahlavi"),
+    ("Phlp", "Psalter Pahlavi"),
+    ("Phnx", "Phoenician"),
+    ("Plrd", "Miao"),
+    ("Prti", "Inscriptional Parthian"),
+    ("Rjng", "Rejang"),
+    ("Rohg", "Hanifi Rohingya"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Sarb", "Old South Arabian"),
+    ("Saur", "Saurashtra"),
+    ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"),
+    ("Shrd", "Sharada"),
+    ("Sidd", "Siddham"),
+    ("Sind", "Khudawadi"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sogo", "Old Sogdian"),
+    ("Sora", "Sora Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sunu", "Sunuwar"),
+    ("Sylo", "Syloti Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tagb", "Tagbanwa"),
+    ("Takr", "Takri"),
+    ("Tale", "Tai Le"),
+    ("Talu", "New Tai Lue"),
+    ("Taml", "Tamil"),
+    ("Tang", "Tangut"),
+    ("Tavt", "Tai Viet"),
+    ("Telu", "Telugu"),
+    ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"),
+    ("Tnsa", "Tangsa"),
+    ("Todr", "Todhri"),
+    ("Toto", "Toto"),
+    ("Tutg", "Tulu Tigalari"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wara", "Warang Citi"),
+    ("Wcho", "Wancho"),
+    ("Xpeo", "Old Persian"),
+    ("Xsux", "Cuneiform"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar Square"),
+    ("Zzzz", "Unknown"),
+];
+
+// Augmented script sets, as bit sets of indices into [SCRIPTS]. Common and
+// Inherited characters belong to all scripts.
+const SCRIPT_SETS: [[u64; 4]; 271] = [
+    [
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF,
+    ],
+    [0x0, 0x800, 0x0, 0x0],
+    [0x200515C501100040, 0x100000000C0840, 0x4, 0x0],
+    [0x18000800, 0x10800, 0x84000000, 0x0],
+    [0x1000000002000, 0x800, 0x0, 0x0],
+    [0x0, 0x10800, 0x0, 0x0],
+    [0x0, 0x800, 0x4000000, 0x0],
+    [0x1000000002000, 0x0, 0x0, 0x0],
+    [0x100009400000, 0x10000000000800, 0x21000, 0x0],
+    [0x100008400000, 0x800000000800, 0x40021000, 0x0],
+    [0x8400000, 0x800, 0x800000, 0x0],
+    [0x8000000000, 0x800, 0x4005000, 0x0],
+    [0x140009400002, 0x800000000800, 0x40804000, 0x0],
+    [0xA000048401000000, 0x800, 0x0, 0x0],
+    [0x100008000000, 0x10000000000800, 0x0, 0x0],
+    [0x20000101000000, 0x10000000000800, 0x40824000, 0x0],
+    [0x20140108000020, 0x10000000000800, 0x24000, 0x0],
+    [0x0, 0x800, 0x800000, 0x0],
+    [0x100000000, 0x800, 0x4000, 0x0],
+    [0x8400000, 0x800000000800, 0x0, 0x0],
+    [0x400000, 0x800, 0x20000, 0x0],
+    [0x0, 0x800, 0x1000, 0x0],
+    [0x1000000000, 0x800, 0x0, 0x0],
+    [0x8000000, 0x800, 0x40000000, 0x0],
+    [0x100000000000, 0x10000000000800, 0x40000000, 0x0],
+    [0x0, 0x800, 0x4000, 0x0],
+    [0xA000000100400000, 0x800, 0x4000, 0x0],
+    [0x100400000, 0x800, 0x4000, 0x0],
+    [0x0, 0x800, 0x5000, 0x0],
+    [0x400000, 0x800, 0x4000, 0x0],
+    [0x40000400002, 0x800, 0x4001000, 0x0],
+    [0x100000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x800000000800, 0x0, 0x0],
+    [0x2, 0x800, 0x40000000, 0x0],
+    [0x100001000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x80000000000, 0x0],
+    [0x1000000, 0x0, 0x0, 0x0],
+    [0x8000000, 0x0, 0x0, 0x0],
+    [0x8000000, 0x10000000000000, 0x0, 0x0],
+    [0x8008000000, 0x0, 0x0, 0x0],
+    [0x8000000, 0x800, 0x0, 0x0],
+    [0x20, 0x0, 0x0, 0x0],
+    [0xC000000020, 0x0, 0x0, 0x0],
+    [0x20000000000000, 0x0, 0x0, 0x0],
+    [0x8, 0x0, 0x0, 0x0],
+    [0x2000000008, 0x1000010000000000, 0x10002004000, 0x0],
+    [0x8, 0x0, 0x2004000, 0x0],
+    [0x2000000009, 0x1000010000000000, 0x10002004000, 0x0],
+    [0x9, 0x1082000000600000, 0x4080, 0x0],
+    [0x8, 0x0, 0x4000, 0x0],
+    [0x8, 0x0, 0x10002000000, 0x0],
+    [0x8, 0x1000000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x4000, 0x0],
+    [0x0, 0x0, 0x2000000, 0x0],
+    [0x0, 0x10000000000, 0x0, 0x0],
+    [0x0, 0x4000000000000000, 0x0, 0x0],
+    [0x0, 0x200000, 0x0, 0x0],
+    [0x10000000, 0x0, 0x0, 0x0],
+    [0xA80010000800, 0x400010000820, 0x10480008, 0x0],
+    [0xA80010000800, 0x400010000820, 0x10480000, 0x0],
+    [0xAB0050000800, 0x501010080020, 0x10492060, 0x0],
+    [0xEB0050000800, 0x501010082020, 0x10492060, 0x0],
+    [0x50000000, 0x80100, 0x0, 0x0],
+    [0x800, 0x0, 0x0, 0x0],
+    [0x40800, 0x0, 0x2000, 0x0],
+    [0x800000000000, 0x0, 0x0, 0x0],
+    [0x800000000000, 0x200000000, 0x0, 0x0],
+    [0x200000000000, 0x0, 0x0, 0x0],
+    [0x200000000000, 0x8, 0x0, 0x0],
+    [0x0, 0x400000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x80000, 0x0],
+    [0x80000000000, 0x0, 0x80000, 0x0],
+    [0x0, 0x0, 0x400000, 0x0],
+    [0x0, 0x20, 0x0, 0x0],
+    [0x0, 0x1000000020, 0x100000000, 0x0],
+    [0x0, 0x10000000, 0x0, 0x0],
+    [0x0, 0x0, 0x40, 0x0],
+    [0x0, 0x0, 0x4000000, 0x0],
+    [0x0, 0x400, 0x0, 0x0],
+    [0x0, 0x0, 0x8000000, 0x0],
+    [0x0, 0x400000000, 0x0, 0x0],
+    [0x40000, 0x400000000, 0x20000, 0x0],
+    [0x4000000000, 0x0, 0x0, 0x0],
+    [0xC000000000, 0x800, 0x0, 0x0],
+    [0x2000000000000, 0x40, 0x0, 0x0],
+    [0x1000000000, 0x0, 0x0, 0x0],
+    [0x400000, 0x0, 0x0, 0x0],
+    [0x80000, 0x0, 0x0, 0x0],
+    [0x0, 0x40000000000, 0x0, 0x0],
+    [0x0, 0x2000000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x1000000, 0x0],
+    [0x8000000000000, 0x0, 0x0, 0x0],
+    [0x8000000020000, 0x0, 0x1008000, 0x0],
+    [0x20000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x8000, 0x0],
+    [0x0, 0x4, 0x0, 0x0],
+    [0x0, 0x40000000, 0x0, 0x0],
+    [0x0, 0x20000040000000, 0x0, 0x0],
+    [0x0, 0x2000, 0x0, 0x0],
+    [0x0, 0x0, 0x20000, 0x0],
+    [0x0, 0x0, 0x40000, 0x0],
+    [0x10000, 0x0, 0x0, 0x0],
+    [0x0, 0x200, 0x0, 0x0],
+    [0x80, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x800, 0x0],
+    [0x400, 0x0, 0x0, 0x0],
+    [0x0, 0x1000, 0x0, 0x0],
+    [0x0, 0x80000000000, 0x0, 0x0],
+    [0x80010000800, 0x20, 0x0, 0x0],
+    [0x80010000000, 0x20, 0x0, 0x0],
+    [0x10000800, 0x0, 0x0, 0x0],
+    [0x10000000, 0x0, 0x8, 0x0],
+    [0x10000000, 0x400010000020, 0x480000, 0x0],
+    [0x10000000, 0x1000000000, 0x0, 0x0],
+    [0x80010000800, 0x401010000020, 0x110400040, 0x0],
+    [0x80010000000, 0x0, 0x0, 0x0],
+    [0x80010000000, 0x20, 0x100000000, 0x0],
+    [0x0, 0x1000000000, 0x0, 0x0],
+    [0x8000000, 0x800, 0x4000, 0x0],
+    [0x0, 0x20000040000800, 0x0, 0x0],
+    [0x9, 0x0, 0x0, 0x0],
+    [0x40000C000100000, 0x200000020000, 0x0, 0x0],
+    [0x400100000100000, 0x8000000, 0x0, 0x0],
+    [0x80010000000, 0x800, 0x0, 0x0],
+    [0x8000, 0x0, 0x0, 0x0],
+    [0x8000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x800000, 0x0],
+    [0x1000000, 0x800, 0x0, 0x0],
+    [0x40, 0x200000000000, 0x0, 0x0],
+    [0x400004000100040, 0x4000000000040100, 0x0, 0x0],
+    [0x100000000, 0x0, 0x0, 0x0],
+    [0x400000000000009, 0x0, 0x0, 0x0],
+    [0x2005000000000000, 0x40, 0x0, 0x0],
+    [0x2005000000000000, 0x40, 0x100000, 0x0],
+    [0xA047000000002000, 0x40000040, 0x20000000000, 0x0],
+    [0xA047000000002000, 0x20000040000040, 0x20000000000, 0x0],
+    [0xA047000000002000, 0x40, 0x0, 0x0],
+    [0xA047000000002000, 0x40000040, 0x20008000000, 0x0],
+    [0xA047000000002000, 0x40010040, 0x20008000000, 0x0],
+    [0xA047000000002000, 0x40, 0x20000000000, 0x0],
+    [0x2005000000002000, 0x40, 0x0, 0x0],
+    [0xA040000000000000, 0x0, 0x0, 0x0],
+    [0xA045000000000000, 0x40, 0x0, 0x0],
+    [0x2040000000000000, 0x0, 0x0, 0x0],
+    [0xA000000000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x20000000000, 0x0],
+    [0x0, 0x10000, 0x0, 0x0],
+    [0x0, 0x0, 0x400000000, 0x0],
+    [0x100, 0x0, 0x0, 0x0],
+    [0x2005000000000000, 0x840, 0x0, 0x0],
+    [0x0, 0x0, 0x2000, 0x0],
+    [0xA00050000000, 0x1030080128, 0x110010028, 0x0],
+    [0xA00050000000, 0x1020080128, 0x110010028, 0x0],
+    [0xA00050000000, 0x20080108, 0x10010020, 0x0],
+    [0xA00050000000, 0x20080108, 0x10010028, 0x0],
+    [0x0, 0x20000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x1, 0x0],
+    [0x10000800, 0x0, 0x100000000, 0x0],
+    [0x10000000, 0x0, 0x80000, 0x0],
+    [0x4000000000000000, 0x0, 0x0, 0x0],
+    [0x4000000000000000, 0x400000800, 0x0, 0x0],
+    [0x0, 0x800000000000000, 0x0, 0x0],
+    [0x1000000000000000, 0x0, 0x0, 0x0],
+    [0x1000000000010000, 0x0, 0x0, 0x0],
+    [0x200000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x200000, 0x0],
+    [0x0, 0x100000000, 0x0, 0x0],
+    [0x8, 0x10000000000, 0x0, 0x0],
+    [0x8, 0x0, 0x2000000, 0x0],
+    [0x0, 0x8000, 0x0, 0x0],
+    [0x6000000, 0x8000, 0x0, 0x0],
+    [0x4000000, 0x8000, 0x0, 0x0],
+    [0x4000000, 0xC000, 0x0, 0x0],
+    [0x0, 0x20000, 0x0, 0x0],
+    [0x100000, 0x0, 0x0, 0x0],
+    [0x1000008, 0x0, 0x0, 0x0],
+    [0x800000000000000, 0x0, 0x0, 0x0],
+    [0x40000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x10000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x200000000, 0x0],
+    [0x0, 0x0, 0x4000000000, 0x0],
+    [0x80000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x4, 0x0],
+    [0x0, 0x1000000000000, 0x0, 0x0],
+    [0x0, 0x800000000000, 0x0, 0x0],
+    [0x400000000, 0x0, 0x0, 0x0],
+    [0x2, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x800000000, 0x0],
+    [0x0, 0x0, 0x40000000, 0x0],
+    [0x0, 0x4000, 0x0, 0x0],
+    [0x4000000, 0x0, 0x0, 0x0],
+    [0x10, 0x0, 0x0, 0x0],
+    [0x0, 0x4000000000000, 0x0, 0x0],
+    [0x0, 0x4000000000, 0x0, 0x0],
+    [0x10000000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x100000000000000, 0x0, 0x0],
+    [0x0, 0x40000, 0x0, 0x0],
+    [0x0, 0x8000000, 0x0, 0x0],
+    [0x0, 0x4000000, 0x0, 0x0],
+    [0x0, 0x2, 0x0, 0x0],
+    [0x0, 0x8000000000000000, 0x0, 0x0],
+    [0x0, 0x2000000000, 0x0, 0x0],
+    [0x0, 0x400000, 0x0, 0x0],
+    [0x0, 0x2000000400000, 0x0, 0x0],
+    [0x40, 0x0, 0x0, 0x0],
+    [0x0, 0x400000000000000, 0x0, 0x0],
+    [0x0, 0x40000000000000, 0x0, 0x0],
+    [0x0, 0x80000000000000, 0x0, 0x0],
+    [0x0, 0x200000000000, 0x0, 0x0],
+    [0x400000000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x1000000000000000, 0x0, 0x0],
+    [0x2000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x10000000000, 0x0],
+    [0x0, 0x0, 0x100, 0x0],
+    [0x0, 0x0, 0x80, 0x0],
+    [0x0, 0x2000000000000, 0x0, 0x0],
+    [0x800000, 0x0, 0x0, 0x0],
+    [0x800000000, 0x0, 0x0, 0x0],
+    [0x4000, 0x0, 0x0, 0x0],
+    [0x0, 0x100, 0x0, 0x0],
+    [0x0, 0x0, 0x200, 0x0],
+    [0x40000, 0x0, 0x0, 0x0],
+    [0x0, 0x80000, 0x0, 0x0],
+    [0x0, 0x0, 0x8, 0x0],
+    [0x0, 0x8, 0x0, 0x0],
+    [0x0, 0x200000000, 0x0, 0x0],
+    [0x0, 0x0, 0x20, 0x0],
+    [0x80000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x100000000, 0x0],
+    [0x0, 0x8000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x10000000, 0x0],
+    [0x0, 0x0, 0x10, 0x0],
+    [0x0, 0x20000000, 0x0, 0x0],
+    [0x0, 0x0, 0x10000, 0x0],
+    [0x4, 0x0, 0x0, 0x0],
+    [0x40000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x1000000000, 0x0],
+    [0x20000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x40000000000, 0x0],
+    [0x0, 0x0, 0x400, 0x0],
+    [0x0, 0x8000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x1000, 0x0],
+    [0x1000, 0x0, 0x0, 0x0],
+    [0x0, 0x800000, 0x0, 0x0],
+    [0x20000000000, 0x0, 0x0, 0x0],
+    [0x10000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x100000, 0x0, 0x0],
+    [0x0, 0x1, 0x0, 0x0],
+    [0x0, 0x0, 0x8000000000, 0x0],
+    [0x2000000, 0x0, 0x0, 0x0],
+    [0x200000000, 0x0, 0x0, 0x0],
+    [0x80000000000000, 0x0, 0x0, 0x0],
+    [0x400000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x80000000, 0x0, 0x0],
+    [0x0, 0x0, 0x20000000, 0x0],
+    [0x200, 0x0, 0x0, 0x0],
+    [0x100000000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x80, 0x0, 0x0],
+    [0x0, 0x1000000, 0x0, 0x0],
+    [0x0, 0x200000000000000, 0x0, 0x0],
+    [0x0, 0x0, 0x100000, 0x0],
+    [0x0, 0x20000000000, 0x0, 0x0],
+    [0x0, 0x10, 0x0, 0x0],
+    [0x0, 0x0, 0x2, 0x0],
+    [0x200000000000000, 0x0, 0x0, 0x0],
+    [0x0, 0x0, 0x80000000, 0x0],
+    [0x0, 0x0, 0x2000000000, 0x0],
+    [0x0, 0x800000000, 0x0, 0x0],
+    [0x0, 0x100000000000, 0x0, 0x0],
+    [0x0, 0x2000000, 0x0, 0x0],
+    [0x1, 0x0, 0x0, 0x0],
+];
+
+// The Script_Extensions property, as the first code point of each range
+// and an index into [SCRIPT_SETS].
+const SCRIPT_EXTENSIONS: &[(u32, u16)] = &[
+    (0x0, 0),
+    (0x41, 1),
+    (0x5B, 0),
+    (0x61, 1),
+    (0x7B, 0),
+    (0xAA, 1),
+    (0xAB, 0),
+    (0xB7, 2),
+    (0xB8, 0),
+    (0xBA, 1),
+    (0xBB, 0),
+    (0xC0, 1),
+    (0xD7, 0),
+    (0xD8, 1),
+    (0xF7, 0),
+    (0xF8, 1),
+    (0x2B9, 0),
+    (0x2BC, 3),
+    (0x2BD, 0),
+    (0x2C7, 4),
+    (0x2C8, 0),
+    (0x2C9, 4),
+    (0x2CC, 0),
+    (0x2CD, 5),
+    (0x2CE, 0),
+    (0x2D7, 6),
+    (0x2D8, 0),
+    (0x2D9, 4),
+    (0x2DA, 0),
+    (0x2E0, 1),
+    (0x2E5, 0),
+    (0x2EA, 7),
+    (0x2EC, 0),
+    (0x300, 8),
+    (0x301, 9),
+    (0x302, 10),
+    (0x303, 11),
+    (0x304, 12),
+    (0x305, 13),
+    (0x306, 14),
+    (0x307, 15),
+    (0x308, 16),
+    (0x309, 17),
+    (0x30A, 18),
+    (0x30B, 19),
+    (0x30C, 20),
+    (0x30D, 21),
+    (0x30E, 22),
+    (0x30F, 0),
+    (0x310, 21),
+    (0x311, 23),
+    (0x312, 0),
+    (0x313, 24),
+    (0x314, 0),
+    (0x320, 25),
+    (0x321, 0),
+    (0x323, 26),
+    (0x324, 27),
+    (0x325, 25),
+    (0x326, 0),
+    (0x32D, 28),
+    (0x32E, 25),
+    (0x32F, 0),
+    (0x330, 29),
+    (0x331, 30),
+    (0x332, 0),
+    (0x342, 31),
+    (0x343, 0),
+    (0x345, 31),
+    (0x346, 0),
+    (0x358, 32),
+    (0x359, 0),
+    (0x35E, 33),
+    (0x35F, 0),
+    (0x363, 1),
+    (0x370, 31),
+    (0x374, 34),
+    (0x376, 31),
+    (0x378, 35),
+    (0x37A, 31),
+    (0x37E, 0),
+    (0x37F, 31),
+    (0x380, 35),
+    (0x384, 31),
+    (0x385, 0),
+    (0x386, 31),
+    (0x387, 0),
+    (0x388, 31),
+    (0x38B, 35),
+    (0x38C, 31),
+    (0x38D, 35),
+    (0x38E, 31),
+    (0x3A2, 35),
+    (0x3A3, 31),
+    (0x3E2, 36),
+    (0x3F0, 31),
+    (0x400, 37),
+    (0x483, 38),
+    (0x484, 39),
+    (0x485, 40),
+    (0x487, 39),
+    (0x488, 37),
+    (0x530, 35),
+    (0x531, 41),
+    (0x557, 35),
+    (0x559, 41),
+    (0x589, 42),
+    (0x58A, 41),
+    (0x58B, 35),
+    (0x58D, 41),
+    (0x590, 35),
+    (0x591, 43),
+    (0x5C8, 35),
+    (0x5D0, 43),
+    (0x5EB, 35),
+    (0x5EF, 43),
+    (0x5F5, 35),
+    (0x600, 44),
+    (0x605, 0),
+    (0x606, 44),
+    (0x60C, 45),
+    (0x60D, 44),
+    (0x61B, 45),
+    (0x61C, 46),
+    (0x61D, 44),
+    (0x61F, 47),
+    (0x620, 44),
+    (0x640, 48),
+    (0x641, 44),
+    (0x64B, 49),
+    (0x656, 44),
+    (0x660, 50),
+    (0x66A, 44),
+    (0x670, 49),
+    (0x671, 44),
+    (0x6D4, 51),
+    (0x6D5, 44),
+    (0x6DD, 0),
+    (0x6DE, 44),
+    (0x700, 52),
+    (0x70E, 35),
+    (0x70F, 52),
+    (0x74B, 35),
+    (0x74D, 52),
+    (0x750, 44),
+    (0x780, 53),
+    (0x7B2, 35),
+    (0x7C0, 54),
+    (0x7FB, 35),
+    (0x7FD, 54),
+    (0x800, 55),
+    (0x82E, 35),
+    (0x830, 55),
+    (0x83F, 35),
+    (0x840, 56),
+    (0x85C, 35),
+    (0x85E, 56),
+    (0x85F, 35),
+    (0x860, 52),
+    (0x86B, 35),
+    (0x870, 44),
+    (0x88F, 35),
+    (0x890, 44),
+    (0x892, 35),
+    (0x897, 44),
+    (0x8E2, 0),
+    (0x8E3, 44),
+    (0x900, 57),
+    (0x951, 58),
+    (0x952, 59),
+    (0x953, 0),
+    (0x955, 57),
+    (0x964, 60),
+    (0x965, 61),
+    (0x966, 62),
+    (0x970, 57),
+    (0x980, 63),
+    (0x984, 35),
+    (0x985, 63),
+    (0x98D, 35),
+    (0x98F, 63),
+    (0x991, 35),
+    (0x993, 63),
+    (0x9A9, 35),
+    (0x9AA, 63),
+    (0x9B1, 35),
+    (0x9B2, 63),
+    (0x9B3, 35),
+    (0x9B6, 63),
+    (0x9BA, 35),
+    (0x9BC, 63),
+    (0x9C5, 35),
+    (0x9C7, 63),
+    (0x9C9, 35),
+    (0x9CB, 63),
+    (0x9CF, 35),
+    (0x9D7, 63),
+    (0x9D8, 35),
+    (0x9DC, 63),
+    (0x9DE, 35),
+    (0x9DF, 63),
+    (0x9E4, 35),
+    (0x9E6, 64),
+    (0x9F0, 63),
+    (0x9FF, 35),
+    (0xA01, 65),
+    (0xA04, 35),
+    (0xA05, 65),
+    (0xA0B, 35),
+    (0xA0F, 65),
+    (0xA11, 35),
+    (0xA13, 65),
+    (0xA29, 35),
+    (0xA2A, 65),
+    (0xA31, 35),
+    (0xA32, 65),
+    (0xA34, 35),
+    (0xA35, 65),
+    (0xA37, 35),
+    (0xA38, 65),
+    (0xA3A, 35),
+    (0xA3C, 65),
+    (0xA3D, 35),
+    (0xA3E, 65),
+    (0xA43, 35),
+    (0xA47, 65),
+    (0xA49, 35),
+    (0xA4B, 65),
+    (0xA4E, 35),
+    (0xA51, 65),
+    (0xA52, 35),
+    (0xA59, 65),
+    (0xA5D, 35),
+    (0xA5E, 65),
+    (0xA5F, 35),
+    (0xA66, 66),
+    (0xA70, 65),
+    (0xA77, 35),
+    (0xA81, 67),
+    (0xA84, 35),
+    (0xA85, 67),
+    (0xA8E, 35),
+    (0xA8F, 67),
+    (0xA92, 35),
+    (0xA93, 67),
+    (0xAA9, 35),
+    (0xAAA, 67),
+    (0xAB1, 35),
+    (0xAB2, 67),
+    (0xAB4, 35),
+    (0xAB5, 67),
+    (0xABA, 35),
+    (0xABC, 67),
+    (0xAC6, 35),
+    (0xAC7, 67),
+    (0xACA, 35),
+    (0xACB, 67),
+    (0xACE, 35),
+    (0xAD0, 67),
+    (0xAD1, 35),
+    (0xAE0, 67),
+    (0xAE4, 35),
+    (0xAE6, 68),
+    (0xAF0, 67),
+    (0xAF2, 35),
+    (0xAF9, 67),
+    (0xB00, 35),
+    (0xB01, 69),
+    (0xB04, 35),
+    (0xB05, 69),
+    (0xB0D, 35),
+    (0xB0F, 69),
+    (0xB11, 35),
+    (0xB13, 69),
+    (0xB29, 35),
+    (0xB2A, 69),
+    (0xB31, 35),
+    (0xB32, 69),
+    (0xB34, 35),
+    (0xB35, 69),
+    (0xB3A, 35),
+    (0xB3C, 69),
+    (0xB45, 35),
+    (0xB47, 69),
+    (0xB49, 35),
+    (0xB4B, 69),
+    (0xB4E, 35),
+    (0xB55, 69),
+    (0xB58, 35),
+    (0xB5C, 69),
+    (0xB5E, 35),
+    (0xB5F, 69),
+    (0xB64, 35),
+    (0xB66, 69),
+    (0xB78, 35),
+    (0xB82, 70),
+    (0xB84, 35),
+    (0xB85, 70),
+    (0xB8B, 35),
+    (0xB8E, 70),
+    (0xB91, 35),
+    (0xB92, 70),
+    (0xB96, 35),
+    (0xB99, 70),
+    (0xB9B, 35),
+    (0xB9C, 70),
+    (0xB9D, 35),
+    (0xB9E, 70),
+    (0xBA0, 35),
+    (0xBA3, 70),
+    (0xBA5, 35),
+    (0xBA8, 70),
+    (0xBAB, 35),
+    (0xBAE, 70),
+    (0xBBA, 35),
+    (0xBBE, 70),
+    (0xBC3, 35),
+    (0xBC6, 70),
+    (0xBC9, 35),
+    (0xBCA, 70),
+    (0xBCE, 35),
+    (0xBD0, 70),
+    (0xBD1, 35),
+    (0xBD7, 70),
+    (0xBD8, 35),
+    (0xBE6, 71),
+    (0xBF4, 70),
+    (0xBFB, 35),
+    (0xC00, 72),
+    (0xC0D, 35),
+    (0xC0E, 72),
+    (0xC11, 35),
+    (0xC12, 72),
+    (0xC29, 35),
+    (0xC2A, 72),
+    (0xC3A, 35),
+    (0xC3C, 72),
+    (0xC45, 35),
+    (0xC46, 72),
+    (0xC49, 35),
+    (0xC4A, 72),
+    (0xC4E, 35),
+    (0xC55, 72),
+    (0xC57, 35),
+    (0xC58, 72),
+    (0xC5B, 35),
+    (0xC5D, 72),
+    (0xC5E, 35),
+    (0xC60, 72),
+    (0xC64, 35),
+    (0xC66, 72),
+    (0xC70, 35),
+    (0xC77, 72),
+    (0xC80, 73),
+    (0xC8D, 35),
+    (0xC8E, 73),
+    (0xC91, 35),
+    (0xC92, 73),
+    (0xCA9, 35),
+    (0xCAA, 73),
+    (0xCB4, 35),
+    (0xCB5, 73),
+    (0xCBA, 35),
+    (0xCBC, 73),
+    (0xCC5, 35),
+    (0xCC6, 73),
+    (0xCC9, 35),
+    (0xCCA, 73),
+    (0xCCE, 35),
+    (0xCD5, 73),
+    (0xCD7, 35),
+    (0xCDD, 73),
+    (0xCDF, 35),
+    (0xCE0, 73),
+    (0xCE4, 35),
+    (0xCE6, 74),
+    (0xCF0, 35),
+    (0xCF1, 73),
+    (0xCF4, 35),
+    (0xD00, 75),
+    (0xD0D, 35),
+    (0xD0E, 75),
+    (0xD11, 35),
+    (0xD12, 75),
+    (0xD45, 35),
+    (0xD46, 75),
+    (0xD49, 35),
+    (0xD4A, 75),
+    (0xD50, 35),
+    (0xD54, 75),
+    (0xD64, 35),
+    (0xD66, 75),
+    (0xD80, 35),
+    (0xD81, 76),
+    (0xD84, 35),
+    (0xD85, 76),
+    (0xD97, 35),
+    (0xD9A, 76),
+    (0xDB2, 35),
+    (0xDB3, 76),
+    (0xDBC, 35),
+    (0xDBD, 76),
+    (0xDBE, 35),
+    (0xDC0, 76),
+    (0xDC7, 35),
+    (0xDCA, 76),
+    (0xDCB, 35),
+    (0xDCF, 76),
+    (0xDD5, 35),
+    (0xDD6, 76),
+    (0xDD7, 35),
+    (0xDD8, 76),
+    (0xDE0, 35),
+    (0xDE6, 76),
+    (0xDF0, 35),
+    (0xDF2, 76),
+    (0xDF5, 35),
+    (0xE01, 77),
+    (0xE3B, 35),
+    (0xE3F, 0),
+    (0xE40, 77),
+    (0xE5C, 35),
+    (0xE81, 78),
+    (0xE83, 35),
+    (0xE84, 78),
+    (0xE85, 35),
+    (0xE86, 78),
+    (0xE8B, 35),
+    (0xE8C, 78),
+    (0xEA4, 35),
+    (0xEA5, 78),
+    (0xEA6, 35),
+    (0xEA7, 78),
+    (0xEBE, 35),
+    (0xEC0, 78),
+    (0xEC5, 35),
+    (0xEC6, 78),
+    (0xEC7, 35),
+    (0xEC8, 78),
+    (0xECF, 35),
+    (0xED0, 78),
+    (0xEDA, 35),
+    (0xEDC, 78),
+    (0xEE0, 35),
+    (0xF00, 79),
+    (0xF48, 35),
+    (0xF49, 79),
+    (0xF6D, 35),
+    (0xF71, 79),
+    (0xF98, 35),
+    (0xF99, 79),
+    (0xFBD, 35),
+    (0xFBE, 79),
+    (0xFCD, 35),
+    (0xFCE, 79),
+    (0xFD5, 0),
+    (0xFD9, 79),
+    (0xFDB, 35),
+    (0x1000, 80),
+    (0x1040, 81),
+    (0x104A, 80),
+    (0x10A0, 82),
+    (0x10C6, 35),
+    (0x10C7, 82),
+    (0x10C8, 35),
+    (0x10CD, 82),
+    (0x10CE, 35),
+    (0x10D0, 82),
+    (0x10FB, 83),
+    (0x10FC, 82),
+    (0x1100, 84),
+    (0x1200, 85),
+    (0x1249, 35),
+    (0x124A, 85),
+    (0x124E, 35),
+    (0x1250, 85),
+    (0x1257, 35),
+    (0x1258, 85),
+    (0x1259, 35),
+    (0x125A, 85),
+    (0x125E, 35),
+    (0x1260, 85),
+    (0x1289, 35),
+    (0x128A, 85),
+    (0x128E, 35),
+    (0x1290, 85),
+    (0x12B1, 35),
+    (0x12B2, 85),
+    (0x12B6, 35),
+    (0x12B8, 85),
+    (0x12BF, 35),
+    (0x12C0, 85),
+    (0x12C1, 35),
+    (0x12C2, 85),
+    (0x12C6, 35),
+    (0x12C8, 85),
+    (0x12D7, 35),
+    (0x12D8, 85),
+    (0x1311, 35),
+    (0x1312, 85),
+    (0x1316, 35),
+    (0x1318, 85),
+    (0x135B, 35),
+    (0x135D, 85),
+    (0x137D, 35),
+    (0x1380, 85),
+    (0x139A, 35),
+    (0x13A0, 86),
+    (0x13F6, 35),
+    (0x13F8, 86),
+    (0x13FE, 35),
+    (0x1400, 87),
+    (0x1680, 88),
+    (0x169D, 35),
+    (0x16A0, 89),
+    (0x16F9, 35),
+    (0x1700, 90),
+    (0x1716, 35),
+    (0x171F, 90),
+    (0x1720, 91),
+    (0x1735, 92),
+    (0x1737, 35),
+    (0x1740, 93),
+    (0x1754, 35),
+    (0x1760, 94),
+    (0x176D, 35),
+    (0x176E, 94),
+    (0x1771, 35),
+    (0x1772, 94),
+    (0x1774, 35),
+    (0x1780, 95),
+    (0x17DE, 35),
+    (0x17E0, 95),
+    (0x17EA, 35),
+    (0x17F0, 95),
+    (0x17FA, 35),
+    (0x1800, 96),
+    (0x1802, 97),
+    (0x1804, 96),
+    (0x1805, 97),
+    (0x1806, 96),
+    (0x181A, 35),
+    (0x1820, 96),
+    (0x1879, 35),
+    (0x1880, 96),
+    (0x18AB, 35),
+    (0x18B0, 87),
+    (0x18F6, 35),
+    (0x1900, 98),
+    (0x191F, 35),
+    (0x1920, 98),
+    (0x192C, 35),
+    (0x1930, 98),
+    (0x193C, 35),
+    (0x1940, 98),
+    (0x1941, 35),
+    (0x1944, 98),
+    (0x1950, 99),
+    (0x196E, 35),
+    (0x1970, 99),
+    (0x1975, 35),
+    (0x1980, 100),
+    (0x19AC, 35),
+    (0x19B0, 100),
+    (0x19CA, 35),
+    (0x19D0, 100),
+    (0x19DB, 35),
+    (0x19DE, 100),
+    (0x19E0, 95),
+    (0x1A00, 101),
+    (0x1A1C, 35),
+    (0x1A1E, 101),
+    (0x1A20, 102),
+    (0x1A5F, 35),
+    (0x1A60, 102),
+    (0x1A7D, 35),
+    (0x1A7F, 102),
+    (0x1A8A, 35),
+    (0x1A90, 102),
+    (0x1A9A, 35),
+    (0x1AA0, 102),
+    (0x1AAE, 35),
+    (0x1AB0, 0),
+    (0x1ACF, 35),
+    (0x1B00, 103),
+    (0x1B4D, 35),
+    (0x1B4E, 103),
+    (0x1B80, 104),
+    (0x1BC0, 105),
+    (0x1BF4, 35),
+    (0x1BFC, 105),
+    (0x1C00, 106),
+    (0x1C38, 35),
+    (0x1C3B, 106),
+    (0x1C4A, 35),
+    (0x1C4D, 106),
+    (0x1C50, 107),
+    (0x1C80, 37),
+    (0x1C8B, 35),
+    (0x1C90, 82),
+    (0x1CBB, 35),
+    (0x1CBD, 82),
+    (0x1CC0, 104),
+    (0x1CC8, 35),
+    (0x1CD0, 108),
+    (0x1CD1, 57),
+    (0x1CD2, 108),
+    (0x1CD3, 109),
+    (0x1CD4, 57),
+    (0x1CD5, 110),
+    (0x1CD7, 111),
+    (0x1CD8, 110),
+    (0x1CD9, 111),
+    (0x1CDA, 112),
+    (0x1CDB, 57),
+    (0x1CDC, 111),
+    (0x1CDE, 57),
+    (0x1CE0, 111),
+    (0x1CE1, 110),
+    (0x1CE2, 57),
+    (0x1CE9, 113),
+    (0x1CEA, 110),
+    (0x1CEB, 57),
+    (0x1CED, 110),
+    (0x1CEE, 57),
+    (0x1CF2, 114),
+    (0x1CF3, 115),
+    (0x1CF4, 116),
+    (0x1CF5, 110),
+    (0x1CF7, 63),
+    (0x1CF8, 115),
+    (0x1CFA, 117),
+    (0x1CFB, 35),
+    (0x1D00, 1),
+    (0x1D26, 31),
+    (0x1D2B, 37),
+    (0x1D2C, 1),
+    (0x1D5D, 31),
+    (0x1D62, 1),
+    (0x1D66, 31),
+    (0x1D6B, 1),
+    (0x1D78, 37),
+    (0x1D79, 1),
+    (0x1DBF, 31),
+    (0x1DC2, 0),
+    (0x1DF8, 118),
+    (0x1DF9, 0),
+    (0x1DFA, 52),
+    (0x1DFB, 0),
+    (0x1E00, 1),
+    (0x1F00, 31),
+    (0x1F16, 35),
+    (0x1F18, 31),
+    (0x1F1E, 35),
+    (0x1F20, 31),
+    (0x1F46, 35),
+    (0x1F48, 31),
+    (0x1F4E, 35),
+    (0x1F50, 31),
+    (0x1F58, 35),
+    (0x1F59, 31),
+    (0x1F5A, 35),
+    (0x1F5B, 31),
+    (0x1F5C, 35),
+    (0x1F5D, 31),
+    (0x1F5E, 35),
+    (0x1F5F, 31),
+    (0x1F7E, 35),
+    (0x1F80, 31),
+    (0x1FB5, 35),
+    (0x1FB6, 31),
+    (0x1FC5, 35),
+    (0x1FC6, 31),
+    (0x1FD4, 35),
+    (0x1FD6, 31),
+    (0x1FDC, 35),
+    (0x1FDD, 31),
+    (0x1FF0, 35),
+    (0x1FF2, 31),
+    (0x1FF5, 35),
+    (0x1FF6, 31),
+    (0x1FFF, 35),
+    (0x2000, 0),
+    (0x202F, 119),
+    (0x2030, 0),
+    (0x204F, 120),
+    (0x2050, 0),
+    (0x205A, 121),
+    (0x205B, 0),
+    (0x205D, 122),
+    (0x205E, 0),
+    (0x2065, 35),
+    (0x2066, 0),
+    (0x2071, 1),
+    (0x2072, 35),
+    (0x2074, 0),
+    (0x207F, 1),
+    (0x2080, 0),
+    (0x208F, 35),
+    (0x2090, 1),
+    (0x209D, 35),
+    (0x20A0, 0),
+    (0x20C1, 35),
+    (0x20D0, 0),
+    (0x20F0, 123),
+    (0x20F1, 35),
+    (0x2100, 0),
+    (0x2126, 31),
+    (0x2127, 0),
+    (0x212A, 1),
+    (0x212C, 0),
+    (0x2132, 1),
+    (0x2133, 0),
+    (0x214E, 1),
+    (0x214F, 0),
+    (0x2160, 1),
+    (0x2189, 0),
+    (0x218C, 35),
+    (0x2190, 0),
+    (0x242A, 35),
+    (0x2440, 0),
+    (0x244B, 35),
+    (0x2460, 0),
+    (0x2800, 124),
+    (0x2900, 0),
+    (0x2B74, 35),
+    (0x2B76, 0),
+    (0x2B96, 35),
+    (0x2B97, 0),
+    (0x2C00, 125),
+    (0x2C60, 1),
+    (0x2C80, 36),
+    (0x2CF4, 35),
+    (0x2CF9, 36),
+    (0x2D00, 82),
+    (0x2D26, 35),
+    (0x2D27, 82),
+    (0x2D28, 35),
+    (0x2D2D, 82),
+    (0x2D2E, 35),
+    (0x2D30, 126),
+    (0x2D68, 35),
+    (0x2D6F, 126),
+    (0x2D71, 35),
+    (0x2D7F, 126),
+    (0x2D80, 85),
+    (0x2D97, 35),
+    (0x2DA0, 85),
+    (0x2DA7, 35),
+    (0x2DA8, 85),
+    (0x2DAF, 35),
+    (0x2DB0, 85),
+    (0x2DB7, 35),
+    (0x2DB8, 85),
+    (0x2DBF, 35),
+    (0x2DC0, 85),
+    (0x2DC7, 35),
+    (0x2DC8, 85),
+    (0x2DCF, 35),
+    (0x2DD0, 85),
+    (0x2DD7, 35),
+    (0x2DD8, 85),
+    (0x2DDF, 35),
+    (0x2DE0, 37),
+    (0x2E00, 0),
+    (0x2E17, 127),
+    (0x2E18, 0),
+    (0x2E30, 128),
+    (0x2E31, 129),
+    (0x2E32, 0),
+    (0x2E3C, 130),
+    (0x2E3D, 0),
+    (0x2E41, 131),
+    (0x2E42, 0),
+    (0x2E43, 39),
+    (0x2E44, 0),
+    (0x2E5E, 35),
+    (0x2E80, 132),
+    (0x2E9A, 35),
+    (0x2E9B, 132),
+    (0x2EF4, 35),
+    (0x2F00, 132),
+    (0x2FD6, 35),
+    (0x2FF0, 133),
+    (0x3000, 0),
+    (0x3001, 134),
+    (0x3002, 135),
+    (0x3003, 136),
+    (0x3004, 0),
+    (0x3005, 132),
+    (0x3008, 137),
+    (0x300A, 138),
+    (0x300C, 139),
+    (0x3012, 0),
+    (0x3013, 136),
+    (0x3014, 139),
+    (0x301C, 136),
+    (0x3020, 0),
+    (0x3021, 132),
+    (0x302A, 140),
+    (0x302E, 84),
+    (0x3030, 136),
+    (0x3031, 141),
+    (0x3036, 0),
+    (0x3037, 136),
+    (0x3038, 132),
+    (0x303C, 142),
+    (0x303E, 132),
+    (0x3040, 35),
+    (0x3041, 143),
+    (0x3097, 35),
+    (0x3099, 141),
+    (0x309D, 143),
+    (0x30A0, 141),
+    (0x30A1, 144),
+    (0x30FB, 139),
+    (0x30FC, 141),
+    (0x30FD, 144),
+    (0x3100, 35),
+    (0x3105, 7),
+    (0x3130, 35),
+    (0x3131, 84),
+    (0x318F, 35),
+    (0x3190, 132),
+    (0x31A0, 7),
+    (0x31C0, 132),
+    (0x31E6, 35),
+    (0x31EF, 133),
+    (0x31F0, 144),
+    (0x3200, 84),
+    (0x321F, 35),
+    (0x3220, 132),
+    (0x3248, 0),
+    (0x3260, 84),
+    (0x327F, 0),
+    (0x3280, 132),
+    (0x32B1, 0),
+    (0x32C0, 132),
+    (0x32CC, 0),
+    (0x32D0, 144),
+    (0x32FF, 132),
+    (0x3300, 144),
+    (0x3358, 132),
+    (0x3371, 0),
+    (0x337B, 132),
+    (0x3380, 0),
+    (0x33E0, 132),
+    (0x33FF, 0),
+    (0x3400, 132),
+    (0x4DC0, 0),
+    (0x4E00, 132),
+    (0xA000, 145),
+    (0xA48D, 35),
+    (0xA490, 145),
+    (0xA4C7, 35),
+    (0xA4D0, 146),
+    (0xA500, 147),
+    (0xA62C, 35),
+    (0xA640, 37),
+    (0xA66F, 39),
+    (0xA670, 37),
+    (0xA6A0, 148),
+    (0xA6F8, 35),
+    (0xA700, 149),
+    (0xA708, 0),
+    (0xA722, 1),
+    (0xA788, 0),
+    (0xA78B, 1),
+    (0xA7CE, 35),
+    (0xA7D0, 1),
+    (0xA7D2, 35),
+    (0xA7D3, 1),
+    (0xA7D4, 35),
+    (0xA7D5, 1),
+    (0xA7DD, 35),
+    (0xA7F2, 1),
+    (0xA800, 150),
+    (0xA82D, 35),
+    (0xA830, 151),
+    (0xA833, 152),
+    (0xA836, 153),
+    (0xA838, 154),
+    (0xA839, 153),
+    (0xA83A, 35),
+    (0xA840, 155),
+    (0xA878, 35),
+    (0xA880, 156),
+    (0xA8C6, 35),
+    (0xA8CE, 156),
+    (0xA8DA, 35),
+    (0xA8E0, 57),
+    (0xA8F1, 157),
+    (0xA8F2, 57),
+    (0xA8F3, 158),
+    (0xA8F4, 57),
+    (0xA900, 159),
+    (0xA92E, 160),
+    (0xA92F, 159),
+    (0xA930, 161),
+    (0xA954, 35),
+    (0xA95F, 161),
+    (0xA960, 84),
+    (0xA97D, 35),
+    (0xA980, 162),
+    (0xA9CE, 35),
+    (0xA9CF, 163),
+    (0xA9D0, 162),
+    (0xA9DA, 35),
+    (0xA9DE, 162),
+    (0xA9E0, 80),
+    (0xA9FF, 35),
+    (0xAA00, 164),
+    (0xAA37, 35),
+    (0xAA40, 164),
+    (0xAA4E, 35),
+    (0xAA50, 164),
+    (0xAA5A, 35),
+    (0xAA5C, 164),
+    (0xAA60, 80),
+    (0xAA80, 165),
+    (0xAAC3, 35),
+    (0xAADB, 165),
+    (0xAAE0, 166),
+    (0xAAF7, 35),
+    (0xAB01, 85),
+    (0xAB07, 35),
+    (0xAB09, 85),
+    (0xAB0F, 35),
+    (0xAB11, 85),
+    (0xAB17, 35),
+    (0xAB20, 85),
+    (0xAB27, 35),
+    (0xAB28, 85),
+    (0xAB2F, 35),
+    (0xAB30, 1),
+    (0xAB5B, 0),
+    (0xAB5C, 1),
+    (0xAB65, 31),
+    (0xAB66, 1),
+    (0xAB6A, 0),
+    (0xAB6C, 35),
+    (0xAB70, 86),
+    (0xABC0, 166),
+    (0xABEE, 35),
+    (0xABF0, 166),
+    (0xABFA, 35),
+    (0xAC00, 84),
+    (0xD7A4, 35),
+    (0xD7B0, 84),
+    (0xD7C7, 35),
+    (0xD7CB, 84),
+    (0xD7FC, 35),
+    (0xF900, 132),
+    (0xFA6E, 35),
+    (0xFA70, 132),
+    (0xFADA, 35),
+    (0xFB00, 1),
+    (0xFB07, 35),
+    (0xFB13, 41),
+    (0xFB18, 35),
+    (0xFB1D, 43),
+    (0xFB37, 35),
+    (0xFB38, 43),
+    (0xFB3D, 35),
+    (0xFB3E, 43),
+    (0xFB3F, 35),
+    (0xFB40, 43),
+    (0xFB42, 35),
+    (0xFB43, 43),
+    (0xFB45, 35),
+    (0xFB46, 43),
+    (0xFB50, 44),
+    (0xFBC3, 35),
+    (0xFBD3, 44),
+    (0xFD3E, 167),
+    (0xFD40, 44),
+    (0xFD90, 35),
+    (0xFD92, 44),
+    (0xFDC8, 35),
+    (0xFDCF, 44),
+    (0xFDD0, 35),
+    (0xFDF0, 44),
+    (0xFDF2, 168),
+    (0xFDF3, 44),
+    (0xFDFD, 168),
+    (0xFDFE, 44),
+    (0xFE00, 0),
+    (0xFE1A, 35),
+    (0xFE20, 0),
+    (0xFE2E, 37),
+    (0xFE30, 0),
+    (0xFE45, 136),
+    (0xFE47, 0),
+    (0xFE53, 35),
+    (0xFE54, 0),
+    (0xFE67, 35),
+    (0xFE68, 0),
+    (0xFE6C, 35),
+    (0xFE70, 44),
+    (0xFE75, 35),
+    (0xFE76, 44),
+    (0xFEFD, 35),
+    (0xFEFF, 0),
+    (0xFF00, 35),
+    (0xFF01, 0),
+    (0xFF21, 1),
+    (0xFF3B, 0),
+    (0xFF41, 1),
+    (0xFF5B, 0),
+    (0xFF61, 139),
+    (0xFF66, 144),
+    (0xFF70, 141),
+    (0xFF71, 144),
+    (0xFF9E, 141),
+    (0xFFA0, 84),
+    (0xFFBF, 35),
+    (0xFFC2, 84),
+    (0xFFC8, 35),
+    (0xFFCA, 84),
+    (0xFFD0, 35),
+    (0xFFD2, 84),
+    (0xFFD8, 35),
+    (0xFFDA, 84),
+    (0xFFDD, 35),
+    (0xFFE0, 0),
+    (0xFFE7, 35),
+    (0xFFE8, 0),
+    (0xFFEF, 35),
+    (0xFFF9, 0),
+    (0xFFFE, 35),
+    (0x10000, 169),
+    (0x1000C, 35),
+    (0x1000D, 169),
+    (0x10027, 35),
+    (0x10028, 169),
+    (0x1003B, 35),
+    (0x1003C, 169),
+    (0x1003E, 35),
+    (0x1003F, 169),
+    (0x1004E, 35),
+    (0x10050, 169),
+    (0x1005E, 35),
+    (0x10080, 169),
+    (0x100FB, 35),
+    (0x10100, 170),
+    (0x10102, 171),
+    (0x10103, 35),
+    (0x10107, 172),
+    (0x10134, 35),
+    (0x10137, 171),
+    (0x10140, 31),
+    (0x1018F, 35),
+    (0x10190, 0),
+    (0x1019D, 35),
+    (0x101A0, 31),
+    (0x101A1, 35),
+    (0x101D0, 0),
+    (0x101FE, 35),
+    (0x10280, 173),
+    (0x1029D, 35),
+    (0x102A0, 174),
+    (0x102D1, 35),
+    (0x102E0, 175),
+    (0x102FC, 35),
+    (0x10300, 176),
+    (0x10324, 35),
+    (0x1032D, 176),
+    (0x10330, 177),
+    (0x1034B, 35),
+    (0x10350, 178),
+    (0x1037B, 35),
+    (0x10380, 179),
+    (0x1039E, 35),
+    (0x1039F, 179),
+    (0x103A0, 180),
+    (0x103C4, 35),
+    (0x103C8, 180),
+    (0x103D6, 35),
+    (0x10400, 181),
+    (0x10450, 182),
+    (0x10480, 183),
+    (0x1049E, 35),
+    (0x104A0, 183),
+    (0x104AA, 35),
+    (0x104B0, 184),
+    (0x104D4, 35),
+    (0x104D8, 184),
+    (0x104FC, 35),
+    (0x10500, 185),
+    (0x10528, 35),
+    (0x10530, 186),
+    (0x10564, 35),
+    (0x1056F, 186),
+    (0x10570, 187),
+    (0x1057B, 35),
+    (0x1057C, 187),
+    (0x1058B, 35),
+    (0x1058C, 187),
+    (0x10593, 35),
+    (0x10594, 187),
+    (0x10596, 35),
+    (0x10597, 187),
+    (0x105A2, 35),
+    (0x105A3, 187),
+    (0x105B2, 35),
+    (0x105B3, 187),
+    (0x105BA, 35),
+    (0x105BB, 187),
+    (0x105BD, 35),
+    (0x105C0, 188),
+    (0x105F4, 35),
+    (0x10600, 189),
+    (0x10737, 35),
+    (0x10740, 189),
+    (0x10756, 35),
+    (0x10760, 189),
+    (0x10768, 35),
+    (0x10780, 1),
+    (0x10786, 35),
+    (0x10787, 1),
+    (0x107B1, 35),
+    (0x107B2, 1),
+    (0x107BB, 35),
+    (0x10800, 190),
+    (0x10806, 35),
+    (0x10808, 190),
+    (0x10809, 35),
+    (0x1080A, 190),
+    (0x10836, 35),
+    (0x10837, 190),
+    (0x10839, 35),
+    (0x1083C, 190),
+    (0x1083D, 35),
+    (0x1083F, 190),
+    (0x10840, 191),
+    (0x10856, 35),
+    (0x10857, 191),
+    (0x10860, 192),
+    (0x10880, 193),
+    (0x1089F, 35),
+    (0x108A7, 193),
+    (0x108B0, 35),
+    (0x108E0, 194),
+    (0x108F3, 35),
+    (0x108F4, 194),
+    (0x108F6, 35),
+    (0x108FB, 194),
+    (0x10900, 195),
+    (0x1091C, 35),
+    (0x1091F, 195),
+    (0x10920, 196),
+    (0x1093A, 35),
+    (0x1093F, 196),
+    (0x10940, 35),
+    (0x10980, 197),
+    (0x109A0, 198),
+    (0x109B8, 35),
+    (0x109BC, 198),
+    (0x109D0, 35),
+    (0x109D2, 198),
+    (0x10A00, 199),
+    (0x10A04, 35),
+    (0x10A05, 199),
+    (0x10A07, 35),
+    (0x10A0C, 199),
+    (0x10A14, 35),
+    (0x10A15, 199),
+    (0x10A18, 35),
+    (0x10A19, 199),
+    (0x10A36, 35),
+    (0x10A38, 199),
+    (0x10A3B, 35),
+    (0x10A3F, 199),
+    (0x10A49, 35),
+    (0x10A50, 199),
+    (0x10A59, 35),
+    (0x10A60, 200),
+    (0x10A80, 201),
+    (0x10AA0, 35),
+    (0x10AC0, 202),
+    (0x10AE7, 35),
+    (0x10AEB, 202),
+    (0x10AF2, 203),
+    (0x10AF3, 202),
+    (0x10AF7, 35),
+    (0x10B00, 204),
+    (0x10B36, 35),
+    (0x10B39, 204),
+    (0x10B40, 205),
+    (0x10B56, 35),
+    (0x10B58, 205),
+    (0x10B60, 206),
+    (0x10B73, 35),
+    (0x10B78, 206),
+    (0x10B80, 207),
+    (0x10B92, 35),
+    (0x10B99, 207),
+    (0x10B9D, 35),
+    (0x10BA9, 207),
+    (0x10BB0, 35),
+    (0x10C00, 208),
+    (0x10C49, 35),
+    (0x10C80, 209),
+    (0x10CB3, 35),
+    (0x10CC0, 209),
+    (0x10CF3, 35),
+    (0x10CFA, 209),
+    (0x10D00, 210),
+    (0x10D28, 35),
+    (0x10D30, 210),
+    (0x10D3A, 35),
+    (0x10D40, 211),
+    (0x10D66, 35),
+    (0x10D69, 211),
+    (0x10D86, 35),
+    (0x10D8E, 211),
+    (0x10D90, 35),
+    (0x10E60, 44),
+    (0x10E7F, 35),
+    (0x10E80, 212),
+    (0x10EAA, 35),
+    (0x10EAB, 212),
+    (0x10EAE, 35),
+    (0x10EB0, 212),
+    (0x10EB2, 35),
+    (0x10EC2, 44),
+    (0x10EC5, 35),
+    (0x10EFC, 44),
+    (0x10F00, 213),
+    (0x10F28, 35),
+    (0x10F30, 214),
+    (0x10F5A, 35),
+    (0x10F70, 215),
+    (0x10F8A, 35),
+    (0x10FB0, 216),
+    (0x10FCC, 35),
+    (0x10FE0, 217),
+    (0x10FF7, 35),
+    (0x11000, 218),
+    (0x1104E, 35),
+    (0x11052, 218),
+    (0x11076, 35),
+    (0x1107F, 218),
+    (0x11080, 219),
+    (0x110C3, 35),
+    (0x110CD, 219),
+    (0x110CE, 35),
+    (0x110D0, 220),
+    (0x110E9, 35),
+    (0x110F0, 220),
+    (0x110FA, 35),
+    (0x11100, 221),
+    (0x11135, 35),
+    (0x11136, 221),
+    (0x11148, 35),
+    (0x11150, 222),
+    (0x11177, 35),
+    (0x11180, 223),
+    (0x111E0, 35),
+    (0x111E1, 76),
+    (0x111F5, 35),
+    (0x11200, 224),
+    (0x11212, 35),
+    (0x11213, 224),
+    (0x11242, 35),
+    (0x11280, 225),
+    (0x11287, 35),
+    (0x11288, 225),
+    (0x11289, 35),
+    (0x1128A, 225),
+    (0x1128E, 35),
+    (0x1128F, 225),
+    (0x1129E, 35),
+    (0x1129F, 225),
+    (0x112AA, 35),
+    (0x112B0, 226),
+    (0x112EB, 35),
+    (0x112F0, 226),
+    (0x112FA, 35),
+    (0x11300, 227),
+    (0x11301, 71),
+    (0x11302, 227),
+    (0x11303, 71),
+    (0x11304, 35),
+    (0x11305, 227),
+    (0x1130D, 35),
+    (0x1130F, 227),
+    (0x11311, 35),
+    (0x11313, 227),
+    (0x11329, 35),
+    (0x1132A, 227),
+    (0x11331, 35),
+    (0x11332, 227),
+    (0x11334, 35),
+    (0x11335, 227),
+    (0x1133A, 35),
+    (0x1133B, 71),
+    (0x1133D, 227),
+    (0x11345, 35),
+    (0x11347, 227),
+    (0x11349, 35),
+    (0x1134B, 227),
+    (0x1134E, 35),
+    (0x11350, 227),
+    (0x11351, 35),
+    (0x11357, 227),
+    (0x11358, 35),
+    (0x1135D, 227),
+    (0x11364, 35),
+    (0x11366, 227),
+    (0x1136D, 35),
+    (0x11370, 227),
+    (0x11375, 35),
+    (0x11380, 228),
+    (0x1138A, 35),
+    (0x1138B, 228),
+    (0x1138C, 35),
+    (0x1138E, 228),
+    (0x1138F, 35),
+    (0x11390, 228),
+    (0x113B6, 35),
+    (0x113B7, 228),
+    (0x113C1, 35),
+    (0x113C2, 228),
+    (0x113C3, 35),
+    (0x113C5, 228),
+    (0x113C6, 35),
+    (0x113C7, 228),
+    (0x113CB, 35),
+    (0x113CC, 228),
+    (0x113D6, 35),
+    (0x113D7, 228),
+    (0x113D9, 35),
+    (0x113E1, 228),
+    (0x113E3, 35),
+    (0x11400, 229),
+    (0x1145C, 35),
+    (0x1145D, 229),
+    (0x11462, 35),
+    (0x11480, 230),
+    (0x114C8, 35),
+    (0x114D0, 230),
+    (0x114DA, 35),
+    (0x11580, 231),
+    (0x115B6, 35),
+    (0x115B8, 231),
+    (0x115DE, 35),
+    (0x11600, 232),
+    (0x11645, 35),
+    (0x11650, 232),
+    (0x1165A, 35),
+    (0x11660, 96),
+    (0x1166D, 35),
+    (0x11680, 233),
+    (0x116BA, 35),
+    (0x116C0, 233),
+    (0x116CA, 35),
+    (0x116D0, 80),
+    (0x116E4, 35),
+    (0x11700, 234),
+    (0x1171B, 35),
+    (0x1171D, 234),
+    (0x1172C, 35),
+    (0x11730, 234),
+    (0x11747, 35),
+    (0x11800, 235),
+    (0x1183C, 35),
+    (0x118A0, 236),
+    (0x118F3, 35),
+    (0x118FF, 236),
+    (0x11900, 237),
+    (0x11907, 35),
+    (0x11909, 237),
+    (0x1190A, 35),
+    (0x1190C, 237),
+    (0x11914, 35),
+    (0x11915, 237),
+    (0x11917, 35),
+    (0x11918, 237),
+    (0x11936, 35),
+    (0x11937, 237),
+    (0x11939, 35),
+    (0x1193B, 237),
+    (0x11947, 35),
+    (0x11950, 237),
+    (0x1195A, 35),
+    (0x119A0, 117),
+    (0x119A8, 35),
+    (0x119AA, 117),
+    (0x119D8, 35),
+    (0x119DA, 117),
+    (0x119E5, 35),
+    (0x11A00, 238),
+    (0x11A48, 35),
+    (0x11A50, 239),
+    (0x11AA3, 35),
+    (0x11AB0, 87),
+    (0x11AC0, 240),
+    (0x11AF9, 35),
+    (0x11B00, 57),
+    (0x11B0A, 35),
+    (0x11BC0, 241),
+    (0x11BE2, 35),
+    (0x11BF0, 241),
+    (0x11BFA, 35),
+    (0x11C00, 242),
+    (0x11C09, 35),
+    (0x11C0A, 242),
+    (0x11C37, 35),
+    (0x11C38, 242),
+    (0x11C46, 35),
+    (0x11C50, 242),
+    (0x11C6D, 35),
+    (0x11C70, 243),
+    (0x11C90, 35),
+    (0x11C92, 243),
+    (0x11CA8, 35),
+    (0x11CA9, 243),
+    (0x11CB7, 35),
+    (0x11D00, 244),
+    (0x11D07, 35),
+    (0x11D08, 244),
+    (0x11D0A, 35),
+    (0x11D0B, 244),
+    (0x11D37, 35),
+    (0x11D3A, 244),
+    (0x11D3B, 35),
+    (0x11D3C, 244),
+    (0x11D3E, 35),
+    (0x11D3F, 244),
+    (0x11D48, 35),
+    (0x11D50, 244),
+    (0x11D5A, 35),
+    (0x11D60, 245),
+    (0x11D66, 35),
+    (0x11D67, 245),
+    (0x11D69, 35),
+    (0x11D6A, 245),
+    (0x11D8F, 35),
+    (0x11D90, 245),
+    (0x11D92, 35),
+    (0x11D93, 245),
+    (0x11D99, 35),
+    (0x11DA0, 245),
+    (0x11DAA, 35),
+    (0x11EE0, 246),
+    (0x11EF9, 35),
+    (0x11F00, 247),
+    (0x11F11, 35),
+    (0x11F12, 247),
+    (0x11F3B, 35),
+    (0x11F3E, 247),
+    (0x11F5B, 35),
+    (0x11FB0, 146),
+    (0x11FB1, 35),
+    (0x11FC0, 70),
+    (0x11FD0, 71),
+    (0x11FD2, 70),
+    (0x11FD3, 71),
+    (0x11FD4, 70),
+    (0x11FF2, 35),
+    (0x11FFF, 70),
+    (0x12000, 248),
+    (0x1239A, 35),
+    (0x12400, 248),
+    (0x1246F, 35),
+    (0x12470, 248),
+    (0x12475, 35),
+    (0x12480, 248),
+    (0x12544, 35),
+    (0x12F90, 249),
+    (0x12FF3, 35),
+    (0x13000, 250),
+    (0x13456, 35),
+    (0x13460, 250),
+    (0x143FB, 35),
+    (0x14400, 251),
+    (0x14647, 35),
+    (0x16100, 252),
+    (0x1613A, 35),
+    (0x16800, 148),
+    (0x16A39, 35),
+    (0x16A40, 253),
+    (0x16A5F, 35),
+    (0x16A60, 253),
+    (0x16A6A, 35),
+    (0x16A6E, 253),
+    (0x16A70, 254),
+    (0x16ABF, 35),
+    (0x16AC0, 254),
+    (0x16ACA, 35),
+    (0x16AD0, 255),
+    (0x16AEE, 35),
+    (0x16AF0, 255),
+    (0x16AF6, 35),
+    (0x16B00, 256),
+    (0x16B46, 35),
+    (0x16B50, 256),
+    (0x16B5A, 35),
+    (0x16B5B, 256),
+    (0x16B62, 35),
+    (0x16B63, 256),
+    (0x16B78, 35),
+    (0x16B7D, 256),
+    (0x16B90, 35),
+    (0x16D40, 257),
+    (0x16D7A, 35),
+    (0x16E40, 258),
+    (0x16E9B, 35),
+    (0x16F00, 259),
+    (0x16F4B, 35),
+    (0x16F4F, 259),
+    (0x16F88, 35),
+    (0x16F8F, 259),
+    (0x16FA0, 35),
+    (0x16FE0, 260),
+    (0x16FE1, 261),
+    (0x16FE2, 132),
+    (0x16FE4, 262),
+    (0x16FE5, 35),
+    (0x16FF0, 132),
+    (0x16FF2, 35),
+    (0x17000, 260),
+    (0x187F8, 35),
+    (0x18800, 260),
+    (0x18B00, 262),
+    (0x18CD6, 35),
+    (0x18CFF, 262),
+    (0x18D00, 260),
+    (0x18D09, 35),
+    (0x1AFF0, 144),
+    (0x1AFF4, 35),
+    (0x1AFF5, 144),
+    (0x1AFFC, 35),
+    (0x1AFFD, 144),
+    (0x1AFFF, 35),
+    (0x1B000, 144),
+    (0x1B001, 143),
+    (0x1B120, 144),
+    (0x1B123, 35),
+    (0x1B132, 143),
+    (0x1B133, 35),
+    (0x1B150, 143),
+    (0x1B153, 35),
+    (0x1B155, 144),
+    (0x1B156, 35),
+    (0x1B164, 144),
+    (0x1B168, 35),
+    (0x1B170, 261),
+    (0x1B2FC, 35),
+    (0x1BC00, 130),
+    (0x1BC6B, 35),
+    (0x1BC70, 130),
+    (0x1BC7D, 35),
+    (0x1BC80, 130),
+    (0x1BC89, 35),
+    (0x1BC90, 130),
+    (0x1BC9A, 35),
+    (0x1BC9C, 130),
+    (0x1BCA4, 35),
+    (0x1CC00, 0),
+    (0x1CCFA, 35),
+    (0x1CD00, 0),
+    (0x1CEB4, 35),
+    (0x1CF00, 0),
+    (0x1CF2E, 35),
+    (0x1CF30, 0),
+    (0x1CF47, 35),
+    (0x1CF50, 0),
+    (0x1CFC4, 35),
+    (0x1D000, 0),
+    (0x1D0F6, 35),
+    (0x1D100, 0),
+    (0x1D127, 35),
+    (0x1D129, 0),
+    (0x1D1EB, 35),
+    (0x1D200, 31),
+    (0x1D246, 35),
+    (0x1D2C0, 0),
+    (0x1D2D4, 35),
+    (0x1D2E0, 0),
+    (0x1D2F4, 35),
+    (0x1D300, 0),
+    (0x1D357, 35),
+    (0x1D360, 132),
+    (0x1D372, 0),
+    (0x1D379, 35),
+    (0x1D400, 0),
+    (0x1D455, 35),
+    (0x1D456, 0),
+    (0x1D49D, 35),
+    (0x1D49E, 0),
+    (0x1D4A0, 35),
+    (0x1D4A2, 0),
+    (0x1D4A3, 35),
+    (0x1D4A5, 0),
+    (0x1D4A7, 35),
+    (0x1D4A9, 0),
+    (0x1D4AD, 35),
+    (0x1D4AE, 0),
+    (0x1D4BA, 35),
+    (0x1D4BB, 0),
+    (0x1D4BC, 35),
+    (0x1D4BD, 0),
+    (0x1D4C4, 35),
+    (0x1D4C5, 0),
+    (0x1D506, 35),
+    (0x1D507, 0),
+    (0x1D50B, 35),
+    (0x1D50D, 0),
+    (0x1D515, 35),
+    (0x1D516, 0),
+    (0x1D51D, 35),
+    (0x1D51E, 0),
+    (0x1D53A, 35),
+    (0x1D53B, 0),
+    (0x1D53F, 35),
+    (0x1D540, 0),
+    (0x1D545, 35),
+    (0x1D546, 0),
+    (0x1D547, 35),
+    (0x1D54A, 0),
+    (0x1D551, 35),
+    (0x1D552, 0),
+    (0x1D6A6, 35),
+    (0x1D6A8, 0),
+    (0x1D7CC, 35),
+    (0x1D7CE, 0),
+    (0x1D800, 263),
+    (0x1DA8C, 35),
+    (0x1DA9B, 263),
+    (0x1DAA0, 35),
+    (0x1DAA1, 263),
+    (0x1DAB0, 35),
+    (0x1DF00, 1),
+    (0x1DF1F, 35),
+    (0x1DF25, 1),
+    (0x1DF2B, 35),
+    (0x1E000, 125),
+    (0x1E007, 35),
+    (0x1E008, 125),
+    (0x1E019, 35),
+    (0x1E01B, 125),
+    (0x1E022, 35),
+    (0x1E023, 125),
+    (0x1E025, 35),
+    (0x1E026, 125),
+    (0x1E02B, 35),
+    (0x1E030, 37),
+    (0x1E06E, 35),
+    (0x1E08F, 37),
+    (0x1E090, 35),
+    (0x1E100, 264),
+    (0x1E12D, 35),
+    (0x1E130, 264),
+    (0x1E13E, 35),
+    (0x1E140, 264),
+    (0x1E14A, 35),
+    (0x1E14E, 264),
+    (0x1E150, 35),
+    (0x1E290, 265),
+    (0x1E2AF, 35),
+    (0x1E2C0, 266),
+    (0x1E2FA, 35),
+    (0x1E2FF, 266),
+    (0x1E300, 35),
+    (0x1E4D0, 267),
+    (0x1E4FA, 35),
+    (0x1E5D0, 268),
+    (0x1E5FB, 35),
+    (0x1E5FF, 268),
+    (0x1E600, 35),
+    (0x1E7E0, 85),
+    (0x1E7E7, 35),
+    (0x1E7E8, 85),
+    (0x1E7EC, 35),
+    (0x1E7ED, 85),
+    (0x1E7EF, 35),
+    (0x1E7F0, 85),
+    (0x1E7FF, 35),
+    (0x1E800, 269),
+    (0x1E8C5, 35),
+    (0x1E8C7, 269),
+    (0x1E8D7, 35),
+    (0x1E900, 270),
+    (0x1E94C, 35),
+    (0x1E950, 270),
+    (0x1E95A, 35),
+    (0x1E95E, 270),
+    (0x1E960, 35),
+    (0x1EC71, 0),
+    (0x1ECB5, 35),
+    (0x1ED01, 0),
+    (0x1ED3E, 35),
+    (0x1EE00, 44),
+    (0x1EE04, 35),
+    (0x1EE05, 44),
+    (0x1EE20, 35),
+    (0x1EE21, 44),
+    (0x1EE23, 35),
+    (0x1EE24, 44),
+    (0x1EE25, 35),
+    (0x1EE27, 44),
+    (0x1EE28, 35),
+    (0x1EE29, 44),
+    (0x1EE33, 35),
+    (0x1EE34, 44),
+    (0x1EE38, 35),
+    (0x1EE39, 44),
+    (0x1EE3A, 35),
+    (0x1EE3B, 44),
+    (0x1EE3C, 35),
+    (0x1EE42, 44),
+    (0x1EE43, 35),
+    (0x1EE47, 44),
+    (0x1EE48, 35),
+    (0x1EE49, 44),
+    (0x1EE4A, 35),
+    (0x1EE4B, 44),
+    (0x1EE4C, 35),
+    (0x1EE4D, 44),
+    (0x1EE50, 35),
+    (0x1EE51, 44),
+    (0x1EE53, 35),
+    (0x1EE54, 44),
+    (0x1EE55, 35),
+    (0x1EE57, 44),
+    (0x1EE58, 35),
+    (0x1EE59, 44),
+    (0x1EE5A, 35),
+    (0x1EE5B, 44),
+    (0x1EE5C, 35),
+    (0x1EE5D, 44),
+    (0x1EE5E, 35),
+    (0x1EE5F, 44),
+    (0x1EE60, 35),
+    (0x1EE61, 44),
+    (0x1EE63, 35),
+    (0x1EE64, 44),
+    (0x1EE65, 35),
+    (0x1EE67, 44),
+    (0x1EE6B, 35),
+    (0x1EE6C, 44),
+    (0x1EE73, 35),
+    (0x1EE74, 44),
+    (0x1EE78, 35),
+    (0x1EE79, 44),
+    (0x1EE7D, 35),
+    (0x1EE7E, 44),
+    (0x1EE7F, 35),
+    (0x1EE80, 44),
+    (0x1EE8A, 35),
+    (0x1EE8B, 44),
+    (0x1EE9C, 35),
+    (0x1EEA1, 44),
+    (0x1EEA4, 35),
+    (0x1EEA5, 44),
+    (0x1EEAA, 35),
+    (0x1EEAB, 44),
+    (0x1EEBC, 35),
+    (0x1EEF0, 44),
+    (0x1EEF2, 35),
+    (0x1F000, 0),
+    (0x1F02C, 35),
+    (0x1F030, 0),
+    (0x1F094, 35),
+    (0x1F0A0, 0),
+    (0x1F0AF, 35),
+    (0x1F0B1, 0),
+    (0x1F0C0, 35),
+    (0x1F0C1, 0),
+    (0x1F0D0, 35),
+    (0x1F0D1, 0),
+    (0x1F0F6, 35),
+    (0x1F100, 0),
+    (0x1F1AE, 35),
+    (0x1F1E6, 0),
+    (0x1F200, 143),
+    (0x1F201, 0),
+    (0x1F203, 35),
+    (0x1F210, 0),
+    (0x1F23C, 35),
+    (0x1F240, 0),
+    (0x1F249, 35),
+    (0x1F250, 132),
+    (0x1F252, 35),
+    (0x1F260, 0),
+    (0x1F266, 35),
+    (0x1F300, 0),
+    (0x1F6D8, 35),
+    (0x1F6DC, 0),
+    (0x1F6ED, 35),
+    (0x1F6F0, 0),
+    (0x1F6FD, 35),
+    (0x1F700, 0),
+    (0x1F777, 35),
+    (0x1F77B, 0),
+    (0x1F7DA, 35),
+    (0x1F7E0, 0),
+    (0x1F7EC, 35),
+    (0x1F7F0, 0),
+    (0x1F7F1, 35),
+    (0x1F800, 0),
+    (0x1F80C, 35),
+    (0x1F810, 0),
+    (0x1F848, 35),
+    (0x1F850, 0),
+    (0x1F85A, 35),
+    (0x1F860, 0),
+    (0x1F888, 35),
+    (0x1F890, 0),
+    (0x1F8AE, 35),
+    (0x1F8B0, 0),
+    (0x1F8BC, 35),
+    (0x1F8C0, 0),
+    (0x1F8C2, 35),
+    (0x1F900, 0),
+    (0x1FA54, 35),
+    (0x1FA60, 0),
+    (0x1FA6E, 35),
+    (0x1FA70, 0),
+    (0x1FA7D, 35),
+    (0x1FA80, 0),
+    (0x1FA8A, 35),
+    (0x1FA8F, 0),
+    (0x1FAC7, 35),
+    (0x1FACE, 0),
+    (0x1FADD, 35),
+    (0x1FADF, 0),
+    (0x1FAEA, 35),
+    (0x1FAF0, 0),
+    (0x1FAF9, 35),
+    (0x1FB00, 0),
+    (0x1FB93, 35),
+    (0x1FB94, 0),
+    (0x1FBFA, 35),
+    (0x20000, 132),
+    (0x2A6E0, 35),
+    (0x2A700, 132),
+    (0x2B73A, 35),
+    (0x2B740, 132),
+    (0x2B81E, 35),
+    (0x2B820, 132),
+    (0x2CEA2, 35),
+    (0x2CEB0, 132),
+    (0x2EBE1, 35),
+    (0x2EBF0, 132),
+    (0x2EE5E, 35),
+    (0x2F800, 132),
+    (0x2FA1E, 35),
+    (0x30000, 132),
+    (0x3134B, 35),
+    (0x31350, 132),
+    (0x323B0, 35),
+    (0xE0001, 0),
+    (0xE0002, 35),
+    (0xE0020, 0),
+    (0xE0080, 35),
+    (0xE0100, 0),
+    (0xE01F0, 35),
+];
+
+// Non-ASCII characters that can be confused with ASCII strings. This table
+// includes each mapping from confusables.txt of Unicode Technical Standard
+// #39 whose source is not ASCII and whose prototype is ASCII. Mappings to
+// other prototypes are excluded.
+const CONFUSABLES: &[(char, &str)] = &[
+    ('\u{A0}', " "),
+    ('\u{B4}', "'"),
+    ('\u{B8}', ","),
+    ('\u{C6}', "AE"),
+    ('\u{D7}', "x"),
+    ('\u{E6}', "ae"),
+    ('\u{131}', "i"),
+    ('\u{132}', "lJ"),
+    ('\u{133}', "ij"),
+    ('\u{149}', "'n"),
+    ('\u{152}', "OE"),
+    ('\u{153}', "oe"),
+    ('\u{17F}', "f"),
+    ('\u{181}', "'B"),
+    ('\u{184}', "b"),
+    ('\u{187}', "C'"),
+    ('\u{18A}', "'D"),
+    ('\u{18D}', "g"),
+    ('\u{193}', "G'"),
+    ('\u{196}', "l"),
+    ('\u{198}', "K'"),
+    ('\u{1A0}', "O'"),
+    ('\u{1A1}', "o'"),
+    ('\u{1A4}', "'P"),
+    ('\u{1A6}', "R"),
+    ('\u{1A7}', "2"),
+    ('\u{1AC}', "'T"),
+    ('\u{1B3}', "'Y"),
+    ('\u{1B7}', "3"),
+    ('\u{1BC}', "5"),
+    ('\u{1BD}', "s"),
+    ('\u{1C0}', "l"),
+    ('\u{1C1}', "ll"),
+    ('\u{1C3}', "!"),
+    ('\u{1C7}', "LJ"),
+    ('\u{1C8}', "Lj"),
+    ('\u{1C9}', "lj"),
+    ('\u{1CA}', "NJ"),
+    ('\u{1CB}', "Nj"),
+    ('\u{1CC}', "nj"),
+    ('\u{1F1}', "DZ"),
+    ('\u{1F2}', "Dz"),
+    ('\u{1F3}', "dz"),
+    ('\u{21C}', "3"),
+    ('\u{222}', "8"),
+    ('\u{223}', "8"),
+    ('\u{241}', "?"),
+    ('\u{251}', "a"),
+    ('\u{261}', "g"),
+    ('\u{263}', "y"),
+    ('\u{269}', "i"),
+    ('\u{26A}', "i"),
+    ('\u{26F}', "w"),
+    ('\u{28B}', "u"),
+    ('\u{28F}', "y"),
+    ('\u{294}', "?"),
+    ('\u{2A3}', "dz"),
+    ('\u{2A6}', "ts"),
+    ('\u{2AA}', "ls"),
+    ('\u{2AB}', "lz"),
+    ('\u{2B9}', "'"),
+    ('\u{2BA}', "''"),
+    ('\u{2BB}', "'"),
+    ('\u{2BC}', "'"),
+    ('\u{2BD}', "'"),
+    ('\u{2BE}', "'"),
+    ('\u{2C2}', "<"),
+    ('\u{2C3}', ">"),
+    ('\u{2C4}', "^"),
+    ('\u{2C6}', "^"),
+    ('\u{2C8}', "'"),
+    ('\u{2CA}', "'"),
+    ('\u{2CB}', "'"),
+    ('\u{2D0}', ":"),
+    ('\u{2D7}', "-"),
+    ('\u{2DB}', "i"),
+    ('\u{2DC}', "~"),
+    ('\u{2DD}', "''"),
+    ('\u{2EE}', "''"),
+    ('\u{2F4}', "'"),
+    ('\u{2F6}', "''"),
+    ('\u{2F8}', ":"),
+    ('\u{374}', "'"),
+    ('\u{37A}', "i"),
+    ('\u{37E}', ";"),
+    ('\u{37F}', "J"),
+    ('\u{384}', "'"),
+    ('\u{391}', "A"),
+    ('\u{392}', "B"),
+    ('\u{395}', "E"),
+    ('\u{396}', "Z"),
+    ('\u{397}', "H"),
+    ('\u{399}', "l"),
+    ('\u{39A}', "K"),
+    ('\u{39C}', "M"),
+    ('\u{39D}', "N"),
+    ('\u{39F}', "O"),
+    ('\u{3A1}', "P"),
+    ('\u{3A4}', "T"),
+    ('\u{3A5}', "Y"),
+    ('\u{3A7}', "X"),
+    ('\u{3B1}', "a"),
+    ('\u{3B3}', "y"),
+    ('\u{3B9}', "i"),
+    ('\u{3BD}', "v"),
+    ('\u{3BF}', "o"),
+    ('\u{3C1}', "p"),
+    ('\u{3C3}', "o"),
+    ('\u{3C5}', "u"),
+    ('\u{3D2}', "Y"),
+    ('\u{3DC}', "F"),
+    ('\u{3E8}', "2"),
+    ('\u{3F1}', "p"),
+    ('\u{3F2}', "c"),
+    ('\u{3F3}', "j"),
+    ('\u{3F9}', "C"),
+    ('\u{3FA}', "M"),
+    ('\u{405}', "S"),
+    ('\u{406}', "l"),
+    ('\u{408}', "J"),
+    ('\u{410}', "A"),
+    ('\u{412}', "B"),
+    ('\u{415}', "E"),
+    ('\u{417}', "3"),
+    ('\u{41A}', "K"),
+    ('\u{41C}', "M"),
+    ('\u{41D}', "H"),
+    ('\u{41E}', "O"),
+    ('\u{420}', "P"),
+    ('\u{421}', "C"),
+    ('\u{422}', "T"),
+    ('\u{423}', "Y"),
+    ('\u{425}', "X"),
+    ('\u{42B}', "bl"),
+    ('\u{42C}', "b"),
+    ('\u{42E}', "lO"),
+    ('\u{430}', "a"),
+    ('\u{431}', "6"),
+    ('\u{433}', "r"),
+    ('\u{435}', "e"),
+    ('\u{43E}', "o"),
+    ('\u{440}', "p"),
+    ('\u{441}', "c"),
+    ('\u{443}', "y"),
+    ('\u{445}', "x"),
+    ('\u{455}', "s"),
+    ('\u{456}', "i"),
+    ('\u{458}', "j"),
+    ('\u{461}', "w"),
+    ('\u{474}', "V"),
+    ('\u{475}', "v"),
+    ('\u{491}', "r'"),
+    ('\u{4AE}', "Y"),
+    ('\u{4AF}', "y"),
+    ('\u{4BB}', "h"),
+    ('\u{4BD}', "e"),
+    ('\u{4C0}', "l"),
+    ('\u{4CF}', "i"),
+    ('\u{4D4}', "AE"),
+    ('\u{4D5}', "ae"),
+    ('\u{4E0}', "3"),
+    ('\u{501}', "d"),
+    ('\u{50C}', "G"),
+    ('\u{51B}', "q"),
+    ('\u{51C}', "W"),
+    ('\u{51D}', "w"),
+    ('\u{54D}', "U"),
+    ('\u{54F}', "S"),
+    ('\u{555}', "O"),
+    ('\u{55A}', "'"),
+    ('\u{55D}', "'"),
+    ('\u{561}', "w"),
+    ('\u{563}', "q"),
+    ('\u{566}', "q"),
+    ('\u{570}', "h"),
+    ('\u{578}', "n"),
+    ('\u{57C}', "n"),
+    ('\u{57D}', "u"),
+    ('\u{581}', "g"),
+    ('\u{584}', "f"),
+    ('\u{585}', "o"),
+    ('\u{589}', ":"),
+    ('\u{5C0}', "l"),
+    ('\u{5C3}', ":"),
+    ('\u{5D5}', "l"),
+    ('\u{5D8}', "v"),
+    ('\u{5D9}', "'"),
+    ('\u{5DF}', "l"),
+    ('\u{5E1}', "o"),
+    ('\u{5F0}', "ll"),
+    ('\u{5F1}', "l'"),
+    ('\u{5F2}', "''"),
+    ('\u{5F3}', "'"),
+    ('\u{5F4}', "''"),
+    ('\u{60D}', ","),
+    ('\u{627}', "l"),
+    ('\u{647}', "o"),
+    ('\u{660}', "."),
+    ('\u{661}', "l"),
+    ('\u{665}', "o"),
+    ('\u{667}', "V"),
+    ('\u{66B}', ","),
+    ('\u{66D}', "*"),
+    ('\u{6BE}', "o"),
+    ('\u{6C1}', "o"),
+    ('\u{6D4}', "-"),
+    ('\u{6D5}', "o"),
+    ('\u{6F0}', "."),
+    ('\u{6F1}', "l"),
+    ('\u{6F5}', "o"),
+    ('\u{6F7}', "V"),
+    ('\u{701}', "."),
+    ('\u{702}', "."),
+    ('\u{703}', ":"),
+    ('\u{704}', ":"),
+    ('\u{7C0}', "O"),
+    ('\u{7CA}', "l"),
+    ('\u{7F4}', "'"),
+    ('\u{7F5}', "'"),
+    ('\u{7FA}', "_"),
+    ('\u{903}', ":"),
+    ('\u{966}', "o"),
+    ('\u{97D}', "?"),
+    ('\u{9E6}', "O"),
+    ('\u{9EA}', "8"),
+    ('\u{9ED}', "9"),
+    ('\u{A66}', "o"),
+    ('\u{A67}', "9"),
+    ('\u{A6A}', "8"),
+    ('\u{A83}', ":"),
+    ('\u{AE6}', "o"),
+    ('\u{B03}', "8"),
+    ('\u{B20}', "O"),
+    ('\u{B66}', "O"),
+    ('\u{B68}', "9"),
+    ('\u{BE6}', "o"),
+    ('\u{C02}', "o"),
+    ('\u{C66}', "o"),
+    ('\u{C82}', "o"),
+    ('\u{CE6}', "o"),
+    ('\u{D02}', "o"),
+    ('\u{D20}', "o"),
+    ('\u{D66}', "o"),
+    ('\u{D6D}', "9"),
+    ('\u{D82}', "o"),
+    ('\u{E50}', "o"),
+    ('\u{ED0}', "o"),
+    ('\u{101D}', "o"),
+    ('\u{1040}', "o"),
+    ('\u{10E7}', "y"),
+    ('\u{10FF}', "o"),
+    ('\u{1200}', "U"),
+    ('\u{12D0}', "O"),
+    ('\u{13A0}', "D"),
+    ('\u{13A1}', "R"),
+    ('\u{13A2}', "T"),
+    ('\u{13A4}', "O'"),
+    ('\u{13A5}', "i"),
+    ('\u{13A9}', "Y"),
+    ('\u{13AA}', "A"),
+    ('\u{13AB}', "J"),
+    ('\u{13AC}', "E"),
+    ('\u{13AE}', "?"),
+    ('\u{13B3}', "W"),
+    ('\u{13B7}', "M"),
+    ('\u{13BB}', "H"),
+    ('\u{13BD}', "Y"),
+    ('\u{13C0}', "G"),
+    ('\u{13C2}', "h"),
+    ('\u{13C3}', "Z"),
+    ('\u{13CE}', "4"),
+    ('\u{13CF}', "b"),
+    ('\u{13D2}', "R"),
+    ('\u{13D4}', "W"),
+    ('\u{13D5}', "S"),
+    ('\u{13D9}', "V"),
+    ('\u{13DA}', "S"),
+    ('\u{13DE}', "L"),
+    ('\u{13DF}', "C"),
+    ('\u{13E2}', "P"),
+    ('\u{13E6}', "K"),
+    ('\u{13E7}', "d"),
+    ('\u{13EE}', "6"),
+    ('\u{13F3}', "G"),
+    ('\u{13F4}', "B"),
+    ('\u{1400}', "="),
+    ('\u{142F}', "V"),
+    ('\u{1433}', ">"),
+    ('\u{1438}', "<"),
+    ('\u{144A}', "'"),
+    ('\u{144C}', "U"),
+    ('\u{1467}', "U'"),
+    ('\u{146D}', "P"),
+    ('\u{146F}', "d"),
+    ('\u{1472}', "b"),
+    ('\u{1486}', "P'"),
+    ('\u{1487}', "d'"),
+    ('\u{1488}', "b'"),
+    ('\u{148D}', "J"),
+    ('\u{14AA}', "L"),
+    ('\u{14BF}', "2"),
+    ('\u{1541}', "x"),
+    ('\u{157C}', "H"),
+    ('\u{157D}', "x"),
+    ('\u{1587}', "R"),
+    ('\u{15AF}', "b"),
+    ('\u{15B4}', "F"),
+    ('\u{15C5}', "A"),
+    ('\u{15DE}', "D"),
+    ('\u{15EA}', "D"),
+    ('\u{15F0}', "M"),
+    ('\u{15F7}', "B"),
+    ('\u{166D}', "X"),
+    ('\u{166E}', "x"),
+    ('\u{1680}', " "),
+    ('\u{16B2}', "<"),
+    ('\u{16B7}', "X"),
+    ('\u{16C1}', "l"),
+    ('\u{16CC}', "'"),
+    ('\u{16D5}', "K"),
+    ('\u{16D6}', "M"),
+    ('\u{16EC}', ":"),
+    ('\u{16ED}', "+"),
+    ('\u{1735}', "/"),
+    ('\u{1803}', ":"),
+    ('\u{1809}', ":"),
+    ('\u{1CD3}', "''"),
+    ('\u{1D04}', "c"),
+    ('\u{1D0F}', "o"),
+    ('\u{1D11}', "o"),
+    ('\u{1D1C}', "u"),
+    ('\u{1D20}', "v"),
+    ('\u{1D21}', "w"),
+    ('\u{1D22}', "z"),
+    ('\u{1D26}', "r"),
+    ('\u{1D6B}', "ue"),
+    ('\u{1D83}', "g"),
+    ('\u{1D8C}', "y"),
+    ('\u{1E9D}', "f"),
+    ('\u{1EFF}', "y"),
+    ('\u{1FBD}', "'"),
+    ('\u{1FBE}', "i"),
+    ('\u{1FBF}', "'"),
+    ('\u{1FC0}', "~"),
+    ('\u{1FEF}', "'"),
+    ('\u{1FFD}', "'"),
+    ('\u{1FFE}', "'"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2016}', "ll"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', ","),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "''"),
+    ('\u{201D}', "''"),
+    ('\u{201F}', "''"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2028}', " "),
+    ('\u{2029}', " "),
+    ('\u{202F}', " "),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "''"),
+    ('\u{2034}', "'''"),
+    ('\u{2035}', "'"),
+    ('\u{2036}', "''"),
+    ('\u{2037}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{2041}', "/"),
+    ('\u{2043}', "-"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{204E}', "*"),
+    ('\u{2053}', "~"),
+    ('\u{2057}', "''''"),
+    ('\u{205A}', ":"),
+    ('\u{205F}', " "),
+    ('\u{20A8}', "Rs"),
+    ('\u{20B6}', "lt"),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2102}', "C"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{210A}', "g"),
+    ('\u{210B}', "H"),
+    ('\u{210C}', "H"),
+    ('\u{210D}', "H"),
+    ('\u{210E}', "h"),
+    ('\u{2110}', "l"),
+    ('\u{2111}', "l"),
+    ('\u{2112}', "L"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "N"),
+    ('\u{2116}', "No"),
+    ('\u{2119}', "P"),
+    ('\u{211A}', "Q"),
+    ('\u{211B}', "R"),
+    ('\u{211C}', "R"),
+    ('\u{211D}', "R"),
+    ('\u{2121}', "TEL"),
+    ('\u{2124}', "Z"),
+    ('\u{2128}', "Z"),
+    ('\u{212A}', "K"),
+    ('\u{212C}', "B"),
+    ('\u{212D}', "C"),
+    ('\u{212E}', "e"),
+    ('\u{212F}', "e"),
+    ('\u{2130}', "E"),
+    ('\u{2131}', "F"),
+    ('\u{2133}', "M"),
+    ('\u{2134}', "o"),
+    ('\u{2139}', "i"),
+    ('\u{213B}', "FAX"),
+    ('\u{213D}', "y"),
+    ('\u{2145}', "D"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2160}', "l"),
+    ('\u{2161}', "ll"),
+    ('\u{2162}', "lll"),
+    ('\u{2163}', "lV"),
+    ('\u{2164}', "V"),
+    ('\u{2165}', "Vl"),
+    ('\u{2166}', "Vll"),
+    ('\u{2167}', "Vlll"),
+    ('\u{2168}', "lX"),
+    ('\u{2169}', "X"),
+    ('\u{216A}', "Xl"),
+    ('\u{216B}', "Xll"),
+    ('\u{216C}', "L"),
+    ('\u{216D}', "C"),
+    ('\u{216E}', "D"),
+    ('\u{216F}', "M"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{217C}', "l"),
+    ('\u{217D}', "c"),
+    ('\u{217E}', "d"),
+    ('\u{217F}', "rn"),
+    ('\u{2212}', "-"),
+    ('\u{2215}', "/"),
+    ('\u{2216}', "\\"),
+    ('\u{2217}', "*"),
+    ('\u{221E}', "oo"),
+    ('\u{2223}', "l"),
+    ('\u{2225}', "ll"),
+    ('\u{2228}', "v"),
+    ('\u{222A}', "U"),
+    ('\u{2236}', ":"),
+    ('\u{223C}', "~"),
+    ('\u{226A}', "<<"),
+    ('\u{226B}', ">>"),
+    ('\u{22A4}', "T"),
+    ('\u{22C1}', "v"),
+    ('\u{22C3}', "U"),
+    ('\u{22D8}', "<<<"),
+    ('\u{22D9}', ">>>"),
+    ('\u{22FF}', "E"),
+    ('\u{2373}', "i"),
+    ('\u{2374}', "p"),
+    ('\u{237A}', "a"),
+    ('\u{23FD}', "l"),
+    ('\u{244A}', "\\\\"),
+    ('\u{2474}', "(l)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(lO)"),
+    ('\u{247E}', "(ll)"),
+    ('\u{247F}', "(l2)"),
+    ('\u{2480}', "(l3)"),
+    ('\u{2481}', "(l4)"),
+    ('\u{2482}', "(l5)"),
+    ('\u{2483}', "(l6)"),
+    ('\u{2484}', "(l7)"),
+    ('\u{2485}', "(l8)"),
+    ('\u{2486}', "(l9)"),
+    ('\u{2487}', "(2O)"),
+    ('\u{2488}', "l."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "lO."),
+    ('\u{2492}', "ll."),
+    ('\u{2493}', "l2."),
+    ('\u{2494}', "l3."),
+    ('\u{2495}', "l4."),
+    ('\u{2496}', "l5."),
+    ('\u{2497}', "l6."),
+    ('\u{2498}', "l7."),
+    ('\u{2499}', "l8."),
+    ('\u{249A}', "l9."),
+    ('\u{249B}', "2O."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(rn)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{2571}', "/"),
+    ('\u{2573}', "X"),
+    ('\u{2768}', "("),
+    ('\u{2769}', ")"),
+    ('\u{276E}', "<"),
+    ('\u{276F}', ">"),
+    ('\u{2772}', "("),
+    ('\u{2773}', ")"),
+    ('\u{2774}', "{"),
+    ('\u{2775}', "}"),
+    ('\u{2795}', "+"),
+    ('\u{2796}', "-"),
+    ('\u{27CB}', "/"),
+    ('\u{27CD}', "\\"),
+    ('\u{27D9}', "T"),
+    ('\u{292B}', "x"),
+    ('\u{292C}', "x"),
+    ('\u{29F5}', "\\"),
+    ('\u{29F8}', "/"),
+    ('\u{29F9}', "\\"),
+    ('\u{2A20}', ">>"),
+    ('\u{2A2F}', "x"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2AA5}', "><"),
+    ('\u{2AFB}', "///"),
+    ('\u{2AFD}', "//"),
+    ('\u{2C85}', "r"),
+    ('\u{2C8E}', "H"),
+    ('\u{2C92}', "l"),
+    ('\u{2C94}', "K"),
+    ('\u{2C98}', "M"),
+    ('\u{2C9A}', "N"),
+    ('\u{2C9E}', "O"),
+    ('\u{2C9F}', "o"),
+    ('\u{2CA2}', "P"),
+    ('\u{2CA3}', "p"),
+    ('\u{2CA4}', "C"),
+    ('\u{2CA5}', "c"),
+    ('\u{2CA6}', "T"),
+    ('\u{2CA8}', "Y"),
+    ('\u{2CAC}', "X"),
+    ('\u{2CBA}', "-"),
+    ('\u{2CC6}', "/"),
+    ('\u{2CCA}', "9"),
+    ('\u{2CCC}', "3"),
+    ('\u{2CD0}', "L"),
+    ('\u{2CD2}', "6"),
+    ('\u{2CF9}', "\\\\"),
+    ('\u{2D38}', "V"),
+    ('\u{2D39}', "E"),
+    ('\u{2D4F}', "l"),
+    ('\u{2D51}', "!"),
+    ('\u{2D54}', "O"),
+    ('\u{2D55}', "Q"),
+    ('\u{2D5D}', "X"),
+    ('\u{2E28}', "(("),
+    ('\u{2E29}', "))"),
+    ('\u{2E40}', "="),
+    ('\u{2F02}', "\\"),
+    ('\u{2F03}', "/"),
+    ('\u{3003}', "''"),
+    ('\u{3007}', "O"),
+    ('\u{3014}', "("),
+    ('\u{3015}', ")"),
+    ('\u{3033}', "/"),
+    ('\u{30A0}', "="),
+    ('\u{30CE}', "/"),
+    ('\u{31D3}', "/"),
+    ('\u{31D4}', "\\"),
+    ('\u{4E36}', "\\"),
+    ('\u{4E3F}', "/"),
+    ('\u{A4D0}', "B"),
+    ('\u{A4D1}', "P"),
+    ('\u{A4D2}', "d"),
+    ('\u{A4D3}', "D"),
+    ('\u{A4D4}', "T"),
+    ('\u{A4D6}', "G"),
+    ('\u{A4D7}', "K"),
+    ('\u{A4D9}', "J"),
+    ('\u{A4DA}', "C"),
+    ('\u{A4DC}', "Z"),
+    ('\u{A4DD}', "F"),
+    ('\u{A4DF}', "M"),
+    ('\u{A4E0}', "N"),
+    ('\u{A4E1}', "L"),
+    ('\u{A4E2}', "S"),
+    ('\u{A4E3}', "R"),
+    ('\u{A4E6}', "V"),
+    ('\u{A4E7}', "H"),
+    ('\u{A4EA}', "W"),
+    ('\u{A4EB}', "X"),
+    ('\u{A4EC}', "Y"),
+    ('\u{A4EE}', "A"),
+    ('\u{A4F0}', "E"),
+    ('\u{A4F2}', "l"),
+    ('\u{A4F3}', "O"),
+    ('\u{A4F4}', "U"),
+    ('\u{A4F8}', "."),
+    ('\u{A4F9}', ","),
+    ('\u{A4FA}', ".."),
+    ('\u{A4FB}', ".,"),
+    ('\u{A4FD}', ":"),
+    ('\u{A4FE}', "-."),
+    ('\u{A4FF}', "="),
+    ('\u{A60E}', "."),
+    ('\u{A644}', "2"),
+    ('\u{A647}', "i"),
+    ('\u{A698}', "OO"),
+    ('\u{A699}', "oo"),
+    ('\u{A6DF}', "V"),
+    ('\u{A6EB}', "?"),
+    ('\u{A6EF}', "2"),
+    ('\u{A728}', "T3"),
+    ('\u{A731}', "s"),
+    ('\u{A732}', "AA"),
+    ('\u{A733}', "aa"),
+    ('\u{A734}', "AO"),
+    ('\u{A735}', "ao"),
+    ('\u{A736}', "AU"),
+    ('\u{A737}', "au"),
+    ('\u{A738}', "AV"),
+    ('\u{A739}', "av"),
+    ('\u{A73A}', "AV"),
+    ('\u{A73B}', "av"),
+    ('\u{A73C}', "AY"),
+    ('\u{A73D}', "ay"),
+    ('\u{A74E}', "OO"),
+    ('\u{A74F}', "oo"),
+    ('\u{A75A}', "2"),
+    ('\u{A76A}', "3"),
+    ('\u{A76E}', "9"),
+    ('\u{A777}', "tf"),
+    ('\u{A778}', "&"),
+    ('\u{A789}', ":"),
+    ('\u{A78C}', "'"),
+    ('\u{A798}', "F"),
+    ('\u{A799}', "f"),
+    ('\u{A79F}', "u"),
+    ('\u{A7AB}', "3"),
+    ('\u{A7B2}', "J"),
+    ('\u{A7B3}', "X"),
+    ('\u{A7B4}', "B"),
+    ('\u{AB32}', "e"),
+    ('\u{AB35}', "f"),
+    ('\u{AB3D}', "o"),
+    ('\u{AB47}', "r"),
+    ('\u{AB48}', "r"),
+    ('\u{AB4E}', "u"),
+    ('\u{AB52}', "u"),
+    ('\u{AB5A}', "y"),
+    ('\u{AB63}', "uo"),
+    ('\u{AB75}', "i"),
+    ('\u{AB81}', "r"),
+    ('\u{AB83}', "w"),
+    ('\u{AB93}', "z"),
+    ('\u{ABA9}', "v"),
+    ('\u{ABAA}', "s"),
+    ('\u{ABAF}', "c"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB06}', "st"),
+    ('\u{FBA6}', "o"),
+    ('\u{FBA7}', "o"),
+    ('\u{FBA8}', "o"),
+    ('\u{FBA9}', "o"),
+    ('\u{FBAA}', "o"),
+    ('\u{FBAB}', "o"),
+    ('\u{FBAC}', "o"),
+    ('\u{FBAD}', "o"),
+    ('\u{FD3E}', "("),
+    ('\u{FD3F}', ")"),
+    ('\u{FE30}', ":"),
+    ('\u{FE4D}', "_"),
+    ('\u{FE4E}', "_"),
+    ('\u{FE4F}', "_"),
+    ('\u{FE58}', "-"),
+    ('\u{FE68}', "\\"),
+    ('\u{FE8D}', "l"),
+    ('\u{FE8E}', "l"),
+    ('\u{FEE9}', "o"),
+    ('\u{FEEA}', "o"),
+    ('\u{FEEB}', "o"),
+    ('\u{FEEC}', "o"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "''"),
+    ('\u{FF07}', "'"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF25}', "E"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "l"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "("),
+    ('\u{FF3C}', "\\"),
+    ('\u{FF3D}', ")"),
+    ('\u{FF40}', "'"),
+    ('\u{FF41}', "a"),
+    ('\u{FF43}', "c"),
+    ('\u{FF45}', "e"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF53}', "s"),
+    ('\u{FF56}', "v"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FFE8}', "l"),
+    ('\u{10282}', "B"),
+    ('\u{10286}', "E"),
+    ('\u{10287}', "F"),
+    ('\u{1028A}', "l"),
+    ('\u{10290}', "X"),
+    ('\u{10292}', "O"),
+    ('\u{10295}', "P"),
+    ('\u{10296}', "S"),
+    ('\u{10297}', "T"),
+    ('\u{1029B}', "+"),
+    ('\u{102A0}', "A"),
+    ('\u{102A1}', "B"),
+    ('\u{102A2}', "C"),
+    ('\u{102A5}', "F"),
+    ('\u{102AB}', "O"),
+    ('\u{102B0}', "M"),
+    ('\u{102B1}', "T"),
+    ('\u{102B2}', "Y"),
+    ('\u{102B4}', "X"),
+    ('\u{102CF}', "H"),
+    ('\u{102F5}', "Z"),
+    ('\u{10301}', "B"),
+    ('\u{10302}', "C"),
+    ('\u{10309}', "l"),
+    ('\u{10311}', "M"),
+    ('\u{10315}', "T"),
+    ('\u{10317}', "X"),
+    ('\u{1031A}', "8"),
+    ('\u{1031F}', "*"),
+    ('\u{10320}', "l"),
+    ('\u{10322}', "X"),
+    ('\u{10404}', "O"),
+    ('\u{10415}', "C"),
+    ('\u{1041B}', "L"),
+    ('\u{10420}', "S"),
+    ('\u{1042C}', "o"),
+    ('\u{1043D}', "c"),
+    ('\u{10448}', "s"),
+    ('\u{104B4}', "R"),
+    ('\u{104C2}', "O"),
+    ('\u{104CE}', "U"),
+    ('\u{104D2}', "7"),
+    ('\u{104EA}', "o"),
+    ('\u{104F6}', "u"),
+    ('\u{10513}', "N"),
+    ('\u{10516}', "O"),
+    ('\u{10518}', "K"),
+    ('\u{1051C}', "C"),
+    ('\u{1051D}', "V"),
+    ('\u{10525}', "F"),
+    ('\u{10526}', "L"),
+    ('\u{10527}', "X"),
+    ('\u{10A50}', "."),
+    ('\u{114D0}', "O"),
+    ('\u{11700}', "rn"),
+    ('\u{11706}', "v"),
+    ('\u{1170A}', "w"),
+    ('\u{1170E}', "w"),
+    ('\u{1170F}', "w"),
+    ('\u{118A0}', "V"),
+    ('\u{118A2}', "F"),
+    ('\u{118A3}', "L"),
+    ('\u{118A4}', "Y"),
+    ('\u{118A6}', "E"),
+    ('\u{118A9}', "Z"),
+    ('\u{118AC}', "9"),
+    ('\u{118AE}', "E"),
+    ('\u{118AF}', "4"),
+    ('\u{118B2}', "L"),
+    ('\u{118B5}', "O"),
+    ('\u{118B8}', "U"),
+    ('\u{118BB}', "5"),
+    ('\u{118BC}', "T"),
+    ('\u{118C0}', "v"),
+    ('\u{118C1}', "s"),
+    ('\u{118C2}', "F"),
+    ('\u{118C3}', "i"),
+    ('\u{118C4}', "z"),
+    ('\u{118C6}', "7"),
+    ('\u{118C8}', "o"),
+    ('\u{118CA}', "3"),
+    ('\u{118CC}', "9"),
+    ('\u{118D5}', "6"),
+    ('\u{118D6}', "9"),
+    ('\u{118D7}', "o"),
+    ('\u{118D8}', "u"),
+    ('\u{118DC}', "y"),
+    ('\u{118E0}', "O"),
+    ('\u{118E3}', "rn"),
+    ('\u{118E5}', "Z"),
+    ('\u{118E6}', "W"),
+    ('\u{118E9}', "C"),
+    ('\u{118EC}', "X"),
+    ('\u{118EF}', "W"),
+    ('\u{118F2}', "C"),
+    ('\u{16F08}', "V"),
+    ('\u{16F0A}', "T"),
+    ('\u{16F16}', "L"),
+    ('\u{16F28}', "l"),
+    ('\u{16F35}', "R"),
+    ('\u{16F3A}', "S"),
+    ('\u{16F3B}', "3"),
+    ('\u{16F3F}', ">"),
+    ('\u{16F40}', "A"),
+    ('\u{16F42}', "U"),
+    ('\u{16F43}', "Y"),
+    ('\u{16F51}', "'"),
+    ('\u{16F52}', "'"),
+    ('\u{1CCD6}', "A"),
+    ('\u{1CCD7}', "B"),
+    ('\u{1CCD8}', "C"),
+    ('\u{1CCD9}', "D"),
+    ('\u{1CCDA}', "E"),
+    ('\u{1CCDB}', "F"),
+    ('\u{1CCDC}', "G"),
+    ('\u{1CCDD}', "H"),
+    ('\u{1CCDE}', "l"),
+    ('\u{1CCDF}', "J"),
+    ('\u{1CCE0}', "K"),
+    ('\u{1CCE1}', "L"),
+    ('\u{1CCE2}', "M"),
+    ('\u{1CCE3}', "N"),
+    ('\u{1CCE4}', "O"),
+    ('\u{1CCE5}', "P"),
+    ('\u{1CCE6}', "Q"),
+    ('\u{1CCE7}', "R"),
+    ('\u{1CCE8}', "S"),
+    ('\u{1CCE9}', "T"),
+    ('\u{1CCEA}', "U"),
+    ('\u{1CCEB}', "V"),
+    ('\u{1CCEC}', "W"),
+    ('\u{1CCED}', "X"),
+    ('\u{1CCEE}', "Y"),
+    ('\u{1CCEF}', "Z"),
+    ('\u{1CCF0}', "O"),
+    ('\u{1CCF1}', "l"),
+    ('\u{1CCF2}', "2"),
+    ('\u{1CCF3}', "3"),
+    ('\u{1CCF4}', "4"),
+    ('\u{1CCF5}', "5"),
+    ('\u{1CCF6}', "6"),
+    ('\u{1CCF7}', "7"),
+    ('\u{1CCF8}', "8"),
+    ('\u{1CCF9}', "9"),
+    ('\u{1D114}', "{"),
+    ('\u{1D16D}', "."),
+    ('\u{1D206}', "3"),
+    ('\u{1D20D}', "V"),
+    ('\u{1D20F}', "\\"),
+    ('\u{1D212}', "7"),
+    ('\u{1D213}', "F"),
+    ('\u{1D216}', "R"),
+    ('\u{1D22A}', "L"),
+    ('\u{1D236}', "<"),
+    ('\u{1D237}', ">"),
+    ('\u{1D23A}', "/"),
+    ('\u{1D23B}', "\\"),
+    ('\u{1D400}', "A"),
+    ('\u{1D401}', "B"),
+    ('\u{1D402}', "C"),
+    ('\u{1D403}', "D"),
+    ('\u{1D404}', "E"),
+    ('\u{1D405}', "F"),
+    ('\u{1D406}', "G"),
+    ('\u{1D407}', "H"),
+    ('\u{1D408}', "l"),
+    ('\u{1D409}', "J"),
+    ('\u{1D40A}', "K"),
+    ('\u{1D40B}', "L"),
+    ('\u{1D40C}', "M"),
+    ('\u{1D40D}', "N"),
+    ('\u{1D40E}', "O"),
+    ('\u{1D40F}', "P"),
+    ('\u{1D410}', "Q"),
+    ('\u{1D411}', "R"),
+    ('\u{1D412}', "S"),
+    ('\u{1D413}', "T"),
+    ('\u{1D414}', "U"),
+    ('\u{1D415}', "V"),
+    ('\u{1D416}', "W"),
+    ('\u{1D417}', "X"),
+    ('\u{1D418}', "Y"),
+    ('\u{1D419}', "Z"),
+    ('\u{1D41A}', "a"),
+    ('\u{1D41B}', "b"),
+    ('\u{1D41C}', "c"),
+    ('\u{1D41D}', "d"),
+    ('\u{1D41E}', "e"),
+    ('\u{1D41F}', "f"),
+    ('\u{1D420}', "g"),
+    ('\u{1D421}', "h"),
+    ('\u{1D422}', "i"),
+    ('\u{1D423}', "j"),
+    ('\u{1D424}', "k"),
+    ('\u{1D425}', "l"),
+    ('\u{1D426}', "rn"),
+    ('\u{1D427}', "n"),
+    ('\u{1D428}', "o"),
+    ('\u{1D429}', "p"),
+    ('\u{1D42A}', "q"),
+    ('\u{1D42B}', "r"),
+    ('\u{1D42C}', "s"),
+    ('\u{1D42D}', "t"),
+    ('\u{1D42E}', "u"),
+    ('\u{1D42F}', "v"),
+    ('\u{1D430}', "w"),
+    ('\u{1D431}', "x"),
+    ('\u{1D432}', "y"),
+    ('\u{1D433}', "z"),
+    ('\u{1D434}', "A"),
+    ('\u{1D435}', "B"),
+    ('\u{1D436}', "C"),
+    ('\u{1D437}', "D"),
+    ('\u{1D438}', "E"),
+    ('\u{1D439}', "F"),
+    ('\u{1D43A}', "G"),
+    ('\u{1D43B}', "H"),
+    ('\u{1D43C}', "l"),
+    ('\u{1D43D}', "J"),
+    ('\u{1D43E}', "K"),
+    ('\u{1D43F}', "L"),
+    ('\u{1D440}', "M"),
+    ('\u{1D441}', "N"),
+    ('\u{1D442}', "O"),
+    ('\u{1D443}', "P"),
+    ('\u{1D444}', "Q"),
+    ('\u{1D445}', "R"),
+    ('\u{1D446}', "S"),
+    ('\u{1D447}', "T"),
+    ('\u{1D448}', "U"),
+    ('\u{1D449}', "V"),
+    ('\u{1D44A}', "W"),
+    ('\u{1D44B}', "X"),
+    ('\u{1D44C}', "Y"),
+    ('\u{1D44D}', "Z"),
+    ('\u{1D44E}', "a"),
+    ('\u{1D44F}', "b"),
+    ('\u{1D450}', "c"),
+    ('\u{1D451}', "d"),
+    ('\u{1D452}', "e"),
+    ('\u{1D453}', "f"),
+    ('\u{1D454}', "g"),
+    ('\u{1D456}', "i"),
+    ('\u{1D457}', "j"),
+    ('\u{1D458}', "k"),
+    ('\u{1D459}', "l"),
+    ('\u{1D45A}', "rn"),
+    ('\u{1D45B}', "n"),
+    ('\u{1D45C}', "o"),
+    ('\u{1D45D}', "p"),
+    ('\u{1D45E}', "q"),
+    ('\u{1D45F}', "r"),
+    ('\u{1D460}', "s"),
+    ('\u{1D461}', "t"),
+    ('\u{1D462}', "u"),
+    ('\u{1D463}', "v"),
+    ('\u{1D464}', "w"),
+    ('\u{1D465}', "x"),
+    ('\u{1D466}', "y"),
+    ('\u{1D467}', "z"),
+    ('\u{1D468}', "A"),
+    ('\u{1D469}', "B"),
+    ('\u{1D46A}', "C"),
+    ('\u{1D46B}', "D"),
+    ('\u{1D46C}', "E"),
+    ('\u{1D46D}', "F"),
+    ('\u{1D46E}', "G"),
+    ('\u{1D46F}', "H"),
+    ('\u{1D470}', "l"),
+    ('\u{1D471}', "J"),
+    ('\u{1D472}', "K"),
+    ('\u{1D473}', "L"),
+    ('\u{1D474}', "M"),
+    ('\u{1D475}', "N"),
+    ('\u{1D476}', "O"),
+    ('\u{1D477}', "P"),
+    ('\u{1D478}', "Q"),
+    ('\u{1D479}', "R"),
+    ('\u{1D47A}', "S"),
+    ('\u{1D47B}', "T"),
+    ('\u{1D47C}', "U"),
+    ('\u{1D47D}', "V"),
+    ('\u{1D47E}', "W"),
+    ('\u{1D47F}', "X"),
+    ('\u{1D480}', "Y"),
+    ('\u{1D481}', "Z"),
+    ('\u{1D482}', "a"),
+    ('\u{1D483}', "b"),
+    ('\u{1D484}', "c"),
+    ('\u{1D485}', "d"),
+    ('\u{1D486}', "e"),
+    ('\u{1D487}', "f"),
+    ('\u{1D488}', "g"),
+    ('\u{1D489}', "h"),
+    ('\u{1D48A}', "i"),
+    ('\u{1D48B}', "j"),
+    ('\u{1D48C}', "k"),
+    ('\u{1D48D}', "l"),
+    ('\u{1D48E}', "rn"),
+    ('\u{1D48F}', "n"),
+    ('\u{1D490}', "o"),
+    ('\u{1D491}', "p"),
+    ('\u{1D492}', "q"),
+    ('\u{1D493}', "r"),
+    ('\u{1D494}', "s"),
+    ('\u{1D495}', "t"),
+    ('\u{1D496}', "u"),
+    ('\u{1D497}', "v"),
+    ('\u{1D498}', "w"),
+    ('\u{1D499}', "x"),
+    ('\u{1D49A}', "y"),
+    ('\u{1D49B}', "z"),
+    ('\u{1D49C}', "A"),
+    ('\u{1D49E}', "C"),
+    ('\u{1D49F}', "D"),
+    ('\u{1D4A2}', "G"),
+    ('\u{1D4A5}', "J"),
+    ('\u{1D4A6}', "K"),
+    ('\u{1D4A9}', "N"),
+    ('\u{1D4AA}', "O"),
+    ('\u{1D4AB}', "P"),
+    ('\u{1D4AC}', "Q"),
+    ('\u{1D4AE}', "S"),
+    ('\u{1D4AF}', "T"),
+    ('\u{1D4B0}', "U"),
+    ('\u{1D4B1}', "V"),
+    ('\u{1D4B2}', "W"),
+    ('\u{1D4B3}', "X"),
+    ('\u{1D4B4}', "Y"),
+    ('\u{1D4B5}', "Z"),
+    ('\u{1D4B6}', "a"),
+    ('\u{1D4B7}', "b"),
+    ('\u{1D4B8}', "c"),
+    ('\u{1D4B9}', "d"),
+    ('\u{1D4BB}', "f"),
+    ('\u{1D4BD}', "h"),
+    ('\u{1D4BE}', "i"),
+    ('\u{1D4BF}', "j"),
+    ('\u{1D4C0}', "k"),
+    ('\u{1D4C1}', "l"),
+    ('\u{1D4C2}', "rn"),
+    ('\u{1D4C3}', "n"),
+    ('\u{1D4C5}', "p"),
+    ('\u{1D4C6}', "q"),
+    ('\u{1D4C7}', "r"),
+    ('\u{1D4C8}', "s"),
+    ('\u{1D4C9}', "t"),
+    ('\u{1D4CA}', "u"),
+    ('\u{1D4CB}', "v"),
+    ('\u{1D4CC}', "w"),
+    ('\u{1D4CD}', "x"),
+    ('\u{1D4CE}', "y"),
+    ('\u{1D4CF}', "z"),
+    ('\u{1D4D0}', "A"),
+    ('\u{1D4D1}', "B"),
+    ('\u{1D4D2}', "C"),
+    ('\u{1D4D3}', "D"),
+    ('\u{1D4D4}', "E"),
+    ('\u{1D4D5}', "F"),
+    ('\u{1D4D6}', "G"),
+    ('\u{1D4D7}', "H"),
+    ('\u{1D4D8}', "l"),
+    ('\u{1D4D9}', "J"),
+    ('\u{1D4DA}', "K"),
+    ('\u{1D4DB}', "L"),
+    ('\u{1D4DC}', "M"),
+    ('\u{1D4DD}', "N"),
+    ('\u{1D4DE}', "O"),
+    ('\u{1D4DF}', "P"),
+    ('\u{1D4E0}', "Q"),
+    ('\u{1D4E1}', "R"),
+    ('\u{1D4E2}', "S"),
+    ('\u{1D4E3}', "T"),
+    ('\u{1D4E4}', "U"),
+    ('\u{1D4E5}', "V"),
+    ('\u{1D4E6}', "W"),
+    ('\u{1D4E7}', "X"),
+    ('\u{1D4E8}', "Y"),
+    ('\u{1D4E9}', "Z"),
+    ('\u{1D4EA}', "a"),
+    ('\u{1D4EB}', "b"),
+    ('\u{1D4EC}', "c"),
+    ('\u{1D4ED}', "d"),
+    ('\u{1D4EE}', "e"),
+    ('\u{1D4EF}', "f"),
+    ('\u{1D4F0}', "g"),
+    ('\u{1D4F1}', "h"),
+    ('\u{1D4F2}', "i"),
+    ('\u{1D4F3}', "j"),
+    ('\u{1D4F4}', "k"),
+    ('\u{1D4F5}', "l"),
+    ('\u{1D4F6}', "rn"),
+    ('\u{1D4F7}', "n"),
+    ('\u{1D4F8}', "o"),
+    ('\u{1D4F9}', "p"),
+    ('\u{1D4FA}', "q"),
+    ('\u{1D4FB}', "r"),
+    ('\u{1D4FC}', "s"),
+    ('\u{1D4FD}', "t"),
+    ('\u{1D4FE}', "u"),
+    ('\u{1D4FF}', "v"),
+    ('\u{1D500}', "w"),
+    ('\u{1D501}', "x"),
+    ('\u{1D502}', "y"),
+    ('\u{1D503}', "z"),
+    ('\u{1D504}', "A"),
+    ('\u{1D505}', "B"),
+    ('\u{1D507}', "D"),
+    ('\u{1D508}', "E"),
+    ('\u{1D509}', "F"),
+    ('\u{1D50A}', "G"),
+    ('\u{1D50D}', "J"),
+    ('\u{1D50E}', "K"),
+    ('\u{1D50F}', "L"),
+    ('\u{1D510}', "M"),
+    ('\u{1D511}', "N"),
+    ('\u{1D512}', "O"),
+    ('\u{1D513}', "P"),
+    ('\u{1D514}', "Q"),
+    ('\u{1D516}', "S"),
+    ('\u{1D517}', "T"),
+    ('\u{1D518}', "U"),
+    ('\u{1D519}', "V"),
+    ('\u{1D51A}', "W"),
+    ('\u{1D51B}', "X"),
+    ('\u{1D51C}', "Y"),
+    ('\u{1D51E}', "a"),
+    ('\u{1D51F}', "b"),
+    ('\u{1D520}', "c"),
+    ('\u{1D521}', "d"),
+    ('\u{1D522}', "e"),
+    ('\u{1D523}', "f"),
+    ('\u{1D524}', "g"),
+    ('\u{1D525}', "h"),
+    ('\u{1D526}', "i"),
+    ('\u{1D527}', "j"),
+    ('\u{1D528}', "k"),
+    ('\u{1D529}', "l"),
+    ('\u{1D52A}', "rn"),
+    ('\u{1D52B}', "n"),
+    ('\u{1D52C}', "o"),
+    ('\u{1D52D}', "p"),
+    ('\u{1D52E}', "q"),
+    ('\u{1D52F}', "r"),
+    ('\u{1D530}', "s"),
+    ('\u{1D531}', "t"),
+    ('\u{1D532}', "u"),
+    ('\u{1D533}', "v"),
+    ('\u{1D534}', "w"),
+    ('\u{1D535}', "x"),
+    ('\u{1D536}', "y"),
+    ('\u{1D537}', "z"),
+    ('\u{1D538}', "A"),
+    ('\u{1D539}', "B"),
+    ('\u{1D53B}', "D"),
+    ('\u{1D53C}', "E"),
+    ('\u{1D53D}', "F"),
+    ('\u{1D53E}', "G"),
+    ('\u{1D540}', "l"),
+    ('\u{1D541}', "J"),
+    ('\u{1D542}', "K"),
+    ('\u{1D543}', "L"),
+    ('\u{1D544}', "M"),
+    ('\u{1D546}', "O"),
+    ('\u{1D54A}', "S"),
+    ('\u{1D54B}', "T"),
+    ('\u{1D54C}', "U"),
+    ('\u{1D54D}', "V"),
+    ('\u{1D54E}', "W"),
+    ('\u{1D54F}', "X"),
+    ('\u{1D550}', "Y"),
+    ('\u{1D552}', "a"),
+    ('\u{1D553}', "b"),
+    ('\u{1D554}', "c"),
+    ('\u{1D555}', "d"),
+    ('\u{1D556}', "e"),
+    ('\u{1D557}', "f"),
+    ('\u{1D558}', "g"),
+    ('\u{1D559}', "h"),
+    ('\u{1D55A}', "i"),
+    ('\u{1D55B}', "j"),
+    ('\u{1D55C}', "k"),
+    ('\u{1D55D}', "l"),
+    ('\u{1D55E}', "rn"),
+    ('\u{1D55F}', "n"),
+    ('\u{1D560}', "o"),
+    ('\u{1D561}', "p"),
+    ('\u{1D562}', "q"),
+    ('\u{1D563}', "r"),
+    ('\u{1D564}', "s"),
+    ('\u{1D565}', "t"),
+    ('\u{1D566}', "u"),
+    ('\u{1D567}', "v"),
+    ('\u{1D568}', "w"),
+    ('\u{1D569}', "x"),
+    ('\u{1D56A}', "y"),
+    ('\u{1D56B}', "z"),
+    ('\u{1D56C}', "A"),
+    ('\u{1D56D}', "B"),
+    ('\u{1D56E}', "C"),
+    ('\u{1D56F}', "D"),
+    ('\u{1D570}', "E"),
+    ('\u{1D571}', "F"),
+    ('\u{1D572}', "G"),
+    ('\u{1D573}', "H"),
+    ('\u{1D574}', "l"),
+    ('\u{1D575}', "J"),
+    ('\u{1D576}', "K"),
+    ('\u{1D577}', "L"),
+    ('\u{1D578}', "M"),
+    ('\u{1D579}', "N"),
+    ('\u{1D57A}', "O"),
+    ('\u{1D57B}', "P"),
+    ('\u{1D57C}', "Q"),
+    ('\u{1D57D}', "R"),
+    ('\u{1D57E}', "S"),
+    ('\u{1D57F}', "T"),
+    ('\u{1D580}', "U"),
+    ('\u{1D581}', "V"),
+    ('\u{1D582}', "W"),
+    ('\u{1D583}', "X"),
+    ('\u{1D584}', "Y"),
+    ('\u{1D585}', "Z"),
+    ('\u{1D586}', "a"),
+    ('\u{1D587}', "b"),
+    ('\u{1D588}', "c"),
+    ('\u{1D589}', "d"),
+    ('\u{1D58A}', "e"),
+    ('\u{1D58B}', "f"),
+    ('\u{1D58C}', "g"),
+    ('\u{1D58D}', "h"),
+    ('\u{1D58E}', "i"),
+    ('\u{1D58F}', "j"),
+    ('\u{1D590}', "k"),
+    ('\u{1D591}', "l"),
+    ('\u{1D592}', "rn"),
+    ('\u{1D593}', "n"),
+    ('\u{1D594}', "o"),
+    ('\u{1D595}', "p"),
+    ('\u{1D596}', "q"),
+    ('\u{1D597}', "r"),
+    ('\u{1D598}', "s"),
+    ('\u{1D599}', "t"),
+    ('\u{1D59A}', "u"),
+    ('\u{1D59B}', "v"),
+    ('\u{1D59C}', "w"),
+    ('\u{1D59D}', "x"),
+    ('\u{1D59E}', "y"),
+    ('\u{1D59F}', "z"),
+    ('\u{1D5A0}', "A"),
+    ('\u{1D5A1}', "B"),
+    ('\u{1D5A2}', "C"),
+    ('\u{1D5A3}', "D"),
+    ('\u{1D5A4}', "E"),
+    ('\u{1D5A5}', "F"),
+    ('\u{1D5A6}', "G"),
+    ('\u{1D5A7}', "H"),
+    ('\u{1D5A8}', "l"),
+    ('\u{1D5A9}', "J"),
+    ('\u{1D5AA}', "K"),
+    ('\u{1D5AB}', "L"),
+    ('\u{1D5AC}', "M"),
+    ('\u{1D5AD}', "N"),
+    ('\u{1D5AE}', "O"),
+    ('\u{1D5AF}', "P"),
+    ('\u{1D5B0}', "Q"),
+    ('\u{1D5B1}', "R"),
+    ('\u{1D5B2}', "S"),
+    ('\u{1D5B3}', "T"),
+    ('\u{1D5B4}', "U"),
+    ('\u{1D5B5}', "V"),
+    ('\u{1D5B6}', "W"),
+    ('\u{1D5B7}', "X"),
+    ('\u{1D5B8}', "Y"),
+    ('\u{1D5B9}', "Z"),
+    ('\u{1D5BA}', "a"),
+    ('\u{1D5BB}', "b"),
+    ('\u{1D5BC}', "c"),
+    ('\u{1D5BD}', "d"),
+    ('\u{1D5BE}', "e"),
+    ('\u{1D5BF}', "f"),
+    ('\u{1D5C0}', "g"),
+    ('\u{1D5C1}', "h"),
+    ('\u{1D5C2}', "i"),
+    ('\u{1D5C3}', "j"),
+    ('\u{1D5C4}', "k"),
+    ('\u{1D5C5}', "l"),
+    ('\u{1D5C6}', "rn"),
+    ('\u{1D5C7}', "n"),
+    ('\u{1D5C8}', "o"),
+    ('\u{1D5C9}', "p"),
+    ('\u{1D5CA}', "q"),
+    ('\u{1D5CB}', "r"),
+    ('\u{1D5CC}', "s"),
+    ('\u{1D5CD}', "t"),
+    ('\u{1D5CE}', "u"),
+    ('\u{1D5CF}', "v"),
+    ('\u{1D5D0}', "w"),
+    ('\u{1D5D1}', "x"),
+    ('\u{1D5D2}', "y"),
+    ('\u{1D5D3}', "z"),
+    ('\u{1D5D4}', "A"),
+    ('\u{1D5D5}', "B"),
+    ('\u{1D5D6}', "C"),
+    ('\u{1D5D7}', "D"),
+    ('\u{1D5D8}', "E"),
+    ('\u{1D5D9}', "F"),
+    ('\u{1D5DA}', "G"),
+    ('\u{1D5DB}', "H"),
+    ('\u{1D5DC}', "l"),
+    ('\u{1D5DD}', "J"),
+    ('\u{1D5DE}', "K"),
+    ('\u{1D5DF}', "L"),
+    ('\u{1D5E0}', "M"),
+    ('\u{1D5E1}', "N"),
+    ('\u{1D5E2}', "O"),
+    ('\u{1D5E3}', "P"),
+    ('\u{1D5E4}', "Q"),
+    ('\u{1D5E5}', "R"),
+    ('\u{1D5E6}', "S"),
+    ('\u{1D5E7}', "T"),
+    ('\u{1D5E8}', "U"),
+    ('\u{1D5E9}', "V"),
+    ('\u{1D5EA}', "W"),
+    ('\u{1D5EB}', "X"),
+    ('\u{1D5EC}', "Y"),
+    ('\u{1D5ED}', "Z"),
+    ('\u{1D5EE}', "a"),
+    ('\u{1D5EF}', "b"),
+    ('\u{1D5F0}', "c"),
+    ('\u{1D5F1}', "d"),
+    ('\u{1D5F2}', "e"),
+    ('\u{1D5F3}', "f"),
+    ('\u{1D5F4}', "g"),
+    ('\u{1D5F5}', "h"),
+    ('\u{1D5F6}', "i"),
+    ('\u{1D5F7}', "j"),
+    ('\u{1D5F8}', "k"),
+    ('\u{1D5F9}', "l"),
+    ('\u{1D5FA}', "rn"),
+    ('\u{1D5FB}', "n"),
+    ('\u{1D5FC}', "o"),
+    ('\u{1D5FD}', "p"),
+    ('\u{1D5FE}', "q"),
+    ('\u{1D5FF}', "r"),
+    ('\u{1D600}', "s"),
+    ('\u{1D601}', "t"),
+    ('\u{1D602}', "u"),
+    ('\u{1D603}', "v"),
+    ('\u{1D604}', "w"),
+    ('\u{1D605}', "x"),
+    ('\u{1D606}', "y"),
+    ('\u{1D607}', "z"),
+    ('\u{1D608}', "A"),
+    ('\u{1D609}', "B"),
+    ('\u{1D60A}', "C"),
+    ('\u{1D60B}', "D"),
+    ('\u{1D60C}', "E"),
+    ('\u{1D60D}', "F"),
+    ('\u{1D60E}', "G"),
+    ('\u{1D60F}', "H"),
+    ('\u{1D610}', "l"),
+    ('\u{1D611}', "J"),
+    ('\u{1D612}', "K"),
+    ('\u{1D613}', "L"),
+    ('\u{1D614}', "M"),
+    ('\u{1D615}', "N"),
+    ('\u{1D616}', "O"),
+    ('\u{1D617}', "P"),
+    ('\u{1D618}', "Q"),
+    ('\u{1D619}', "R"),
+    ('\u{1D61A}', "S"),
+    ('\u{1D61B}', "T"),
+    ('\u{1D61C}', "U"),
+    ('\u{1D61D}', "V"),
+    ('\u{1D61E}', "W"),
+    ('\u{1D61F}', "X"),
+    ('\u{1D620}', "Y"),
+    ('\u{1D621}', "Z"),
+    ('\u{1D622}', "a"),
+    ('\u{1D623}', "b"),
+    ('\u{1D624}', "c"),
+    ('\u{1D625}', "d"),
+    ('\u{1D626}', "e"),
+    ('\u{1D627}', "f"),
+    ('\u{1D628}', "g"),
+    ('\u{1D629}', "h"),
+    ('\u{1D62A}', "i"),
+    ('\u{1D62B}', "j"),
+    ('\u{1D62C}', "k"),
+    ('\u{1D62D}', "l"),
+    ('\u{1D62E}', "rn"),
+    ('\u{1D62F}', "n"),
+    ('\u{1D630}', "o"),
+    ('\u{1D631}', "p"),
+    ('\u{1D632}', "q"),
+    ('\u{1D633}', "r"),
+    ('\u{1D634}', "s"),
+    ('\u{1D635}', "t"),
+    ('\u{1D636}', "u"),
+    ('\u{1D637}', "v"),
+    ('\u{1D638}', "w"),
+    ('\u{1D639}', "x"),
+    ('\u{1D63A}', "y"),
+    ('\u{1D63B}', "z"),
+    ('\u{1D63C}', "A"),
+    ('\u{1D63D}', "B"),
+    ('\u{1D63E}', "C"),
+    ('\u{1D63F}', "D"),
+    ('\u{1D640}', "E"),
+    ('\u{1D641}', "F"),
+    ('\u{1D642}', "G"),
+    ('\u{1D643}', "H"),
+    ('\u{1D644}', "l"),
+    ('\u{1D645}', "J"),
+    ('\u{1D646}', "K"),
+    ('\u{1D647}', "L"),
+    ('\u{1D648}', "M"),
+    ('\u{1D649}', "N"),
+    ('\u{1D64A}', "O"),
+    ('\u{1D64B}', "P"),
+    ('\u{1D64C}', "Q"),
+    ('\u{1D64D}', "R"),
+    ('\u{1D64E}', "S"),
+    ('\u{1D64F}', "T"),
+    ('\u{1D650}', "U"),
+    ('\u{1D651}', "V"),
+    ('\u{1D652}', "W"),
+    ('\u{1D653}', "X"),
+    ('\u{1D654}', "Y"),
+    ('\u{1D655}', "Z"),
+    ('\u{1D656}', "a"),
+    ('\u{1D657}', "b"),
+    ('\u{1D658}', "c"),
+    ('\u{1D659}', "d"),
+    ('\u{1D65A}', "e"),
+    ('\u{1D65B}', "f"),
+    ('\u{1D65C}', "g"),
+    ('\u{1D65D}', "h"),
+    ('\u{1D65E}', "i"),
+    ('\u{1D65F}', "j"),
+    ('\u{1D660}', "k"),
+    ('\u{1D661}', "l"),
+    ('\u{1D662}', "rn"),
+    ('\u{1D663}', "n"),
+    ('\u{1D664}', "o"),
+    ('\u{1D665}', "p"),
+    ('\u{1D666}', "q"),
+    ('\u{1D667}', "r"),
+    ('\u{1D668}', "s"),
+    ('\u{1D669}', "t"),
+    ('\u{1D66A}', "u"),
+    ('\u{1D66B}', "v"),
+    ('\u{1D66C}', "w"),
+    ('\u{1D66D}', "x"),
+    ('\u{1D66E}', "y"),
+    ('\u{1D66F}', "z"),
+    ('\u{1D670}', "A"),
+    ('\u{1D671}', "B"),
+    ('\u{1D672}', "C"),
+    ('\u{1D673}', "D"),
+    ('\u{1D674}', "E"),
+    ('\u{1D675}', "F"),
+    ('\u{1D676}', "G"),
+    ('\u{1D677}', "H"),
+    ('\u{1D678}', "l"),
+    ('\u{1D679}', "J"),
+    ('\u{1D67A}', "K"),
+    ('\u{1D67B}', "L"),
+    ('\u{1D67C}', "M"),
+    ('\u{1D67D}', "N"),
+    ('\u{1D67E}', "O"),
+    ('\u{1D67F}', "P"),
+    ('\u{1D680}', "Q"),
+    ('\u{1D681}', "R"),
+    ('\u{1D682}', "S"),
+    ('\u{1D683}', "T"),
+    ('\u{1D684}', "U"),
+    ('\u{1D685}', "V"),
+    ('\u{1D686}', "W"),
+    ('\u{1D687}', "X"),
+    ('\u{1D688}', "Y"),
+    ('\u{1D689}', "Z"),
+    ('\u{1D68A}', "a"),
+    ('\u{1D68B}', "b"),
+    ('\u{1D68C}', "c"),
+    ('\u{1D68D}', "d"),
+    ('\u{1D68E}', "e"),
+    ('\u{1D68F}', "f"),
+    ('\u{1D690}', "g"),
+    ('\u{1D691}', "h"),
+    ('\u{1D692}', "i"),
+    ('\u{1D693}', "j"),
+    ('\u{1D694}', "k"),
+    ('\u{1D695}', "l"),
+    ('\u{1D696}', "rn"),
+    ('\u{1D697}', "n"),
+    ('\u{1D698}', "o"),
+    ('\u{1D699}', "p"),
+    ('\u{1D69A}', "q"),
+    ('\u{1D69B}', "r"),
+    ('\u{1D69C}', "s"),
+    ('\u{1D69D}', "t"),
+    ('\u{1D69E}', "u"),
+    ('\u{1D69F}', "v"),
+    ('\u{1D6A0}', "w"),
+    ('\u{1D6A1}', "x"),
+    ('\u{1D6A2}', "y"),
+    ('\u{1D6A3}', "z"),
+    ('\u{1D6A4}', "i"),
+    ('\u{1D6A8}', "A"),
+    ('\u{1D6A9}', "B"),
+    ('\u{1D6AC}', "E"),
+    ('\u{1D6AD}', "Z"),
+    ('\u{1D6AE}', "H"),
+    ('\u{1D6B0}', "l"),
+    ('\u{1D6B1}', "K"),
+    ('\u{1D6B3}', "M"),
+    ('\u{1D6B4}', "N"),
+    ('\u{1D6B6}', "O"),
+    ('\u{1D6B8}', "P"),
+    ('\u{1D6BB}', "T"),
+    ('\u{1D6BC}', "Y"),
+    ('\u{1D6BE}', "X"),
+    ('\u{1D6C2}', "a"),
+    ('\u{1D6C4}', "y"),
+    ('\u{1D6CA}', "i"),
+    ('\u{1D6CE}', "v"),
+    ('\u{1D6D0}', "o"),
+    ('\u{1D6D2}', "p"),
+    ('\u{1D6D4}', "o"),
+    ('\u{1D6D6}', "u"),
+    ('\u{1D6E0}', "p"),
+    ('\u{1D6E2}', "A"),
+    ('\u{1D6E3}', "B"),
+    ('\u{1D6E6}', "E"),
+    ('\u{1D6E7}', "Z"),
+    ('\u{1D6E8}', "H"),
+    ('\u{1D6EA}', "l"),
+    ('\u{1D6EB}', "K"),
+    ('\u{1D6ED}', "M"),
+    ('\u{1D6EE}', "N"),
+    ('\u{1D6F0}', "O"),
+    ('\u{1D6F2}', "P"),
+    ('\u{1D6F5}', "T"),
+    ('\u{1D6F6}', "Y"),
+    ('\u{1D6F8}', "X"),
+    ('\u{1D6FC}', "a"),
+    ('\u{1D6FE}', "y"),
+    ('\u{1D704}', "i"),
+    ('\u{1D708}', "v"),
+    ('\u{1D70A}', "o"),
+    ('\u{1D70C}', "p"),
+    ('\u{1D70E}', "o"),
+    ('\u{1D710}', "u"),
+    ('\u{1D71A}', "p"),
+    ('\u{1D71C}', "A"),
+    ('\u{1D71D}', "B"),
+    ('\u{1D720}', "E"),
+    ('\u{1D721}', "Z"),
+    ('\u{1D722}', "H"),
+    ('\u{1D724}', "l"),
+    ('\u{1D725}', "K"),
+    ('\u{1D727}', "M"),
+    ('\u{1D728}', "N"),
+    ('\u{1D72A}', "O"),
+    ('\u{1D72C}', "P"),
+    ('\u{1D72F}', "T"),
+    ('\u{1D730}', "Y"),
+    ('\u{1D732}', "X"),
+    ('\u{1D736}', "a"),
+    ('\u{1D738}', "y"),
+    ('\u{1D73E}', "i"),
+    ('\u{1D742}', "v"),
+    ('\u{1D744}', "o"),
+    ('\u{1D746}', "p"),
+    ('\u{1D748}', "o"),
+    ('\u{1D74A}', "u"),
+    ('\u{1D754}', "p"),
+    ('\u{1D756}', "A"),
+    ('\u{1D757}', "B"),
+    ('\u{1D75A}', "E"),
+    ('\u{1D75B}', "Z"),
+    ('\u{1D75C}', "H"),
+    ('\u{1D75E}', "l"),
+    ('\u{1D75F}', "K"),
+    ('\u{1D761}', "M"),
+    ('\u{1D762}', "N"),
+    ('\u{1D764}', "O"),
+    ('\u{1D766}', "P"),
+    ('\u{1D769}', "T"),
+    ('\u{1D76A}', "Y"),
+    ('\u{1D76C}', "X"),
+    ('\u{1D770}', "a"),
+    ('\u{1D772}', "y"),
+    ('\u{1D778}', "i"),
+    ('\u{1D77C}', "v"),
+    ('\u{1D77E}', "o"),
+    ('\u{1D780}', "p"),
+    ('\u{1D782}', "o"),
+    ('\u{1D784}', "u"),
+    ('\u{1D78E}', "p"),
+    ('\u{1D790}', "A"),
+    ('\u{1D791}', "B"),
+    ('\u{1D794}', "E"),
+    ('\u{1D795}', "Z"),
+    ('\u{1D796}', "H"),
+    ('\u{1D798}', "l"),
+    ('\u{1D799}', "K"),
+    ('\u{1D79B}', "M"),
+    ('\u{1D79C}', "N"),
+    ('\u{1D79E}', "O"),
+    ('\u{1D7A0}', "P"),
+    ('\u{1D7A3}', "T"),
+    ('\u{1D7A4}', "Y"),
+    ('\u{1D7A6}', "X"),
+    ('\u{1D7AA}', "a"),
+    ('\u{1D7AC}', "y"),
+    ('\u{1D7B2}', "i"),
+    ('\u{1D7B6}', "v"),
+    ('\u{1D7B8}', "o"),
+    ('\u{1D7BA}', "p"),
+    ('\u{1D7BC}', "o"),
+    ('\u{1D7BE}', "u"),
+    ('\u{1D7C8}', "p"),
+    ('\u{1D7CA}', "F"),
+    ('\u{1D7CE}', "O"),
+    ('\u{1D7CF}', "l"),
+    ('\u{1D7D0}', "2"),
+    ('\u{1D7D1}', "3"),
+    ('\u{1D7D2}', "4"),
+    ('\u{1D7D3}', "5"),
+    ('\u{1D7D4}', "6"),
+    ('\u{1D7D5}', "7"),
+    ('\u{1D7D6}', "8"),
+    ('\u{1D7D7}', "9"),
+    ('\u{1D7D8}', "O"),
+    ('\u{1D7D9}', "l"),
+    ('\u{1D7DA}', "2"),
+    ('\u{1D7DB}', "3"),
+    ('\u{1D7DC}', "4"),
+    ('\u{1D7DD}', "5"),
+    ('\u{1D7DE}', "6"),
+    ('\u{1D7DF}', "7"),
+    ('\u{1D7E0}', "8"),
+    ('\u{1D7E1}', "9"),
+    ('\u{1D7E2}', "O"),
+    ('\u{1D7E3}', "l"),
+    ('\u{1D7E4}', "2"),
+    ('\u{1D7E5}', "3"),
+    ('\u{1D7E6}', "4"),
+    ('\u{1D7E7}', "5"),
+    ('\u{1D7E8}', "6"),
+    ('\u{1D7E9}', "7"),
+    ('\u{1D7EA}', "8"),
+    ('\u{1D7EB}', "9"),
+    ('\u{1D7EC}', "O"),
+    ('\u{1D7ED}', "l"),
+    ('\u{1D7EE}', "2"),
+    ('\u{1D7EF}', "3"),
+    ('\u{1D7F0}', "4"),
+    ('\u{1D7F1}', "5"),
+    ('\u{1D7F2}', "6"),
+    ('\u{1D7F3}', "7"),
+    ('\u{1D7F4}', "8"),
+    ('\u{1D7F5}', "9"),
+    ('\u{1D7F6}', "O"),
+    ('\u{1D7F7}', "l"),
+    ('\u{1D7F8}', "2"),
+    ('\u{1D7F9}', "3"),
+    ('\u{1D7FA}', "4"),
+    ('\u{1D7FB}', "5"),
+    ('\u{1D7FC}', "6"),
+    ('\u{1D7FD}', "7"),
+    ('\u{1D7FE}', "8"),
+    ('\u{1D7FF}', "9"),
+    ('\u{1E8C7}', "l"),
+    ('\u{1E8CB}', "8"),
+    ('\u{1EE00}', "l"),
+    ('\u{1EE24}', "o"),
+    ('\u{1EE64}', "o"),
+    ('\u{1EE80}', "l"),
+    ('\u{1EE84}', "o"),
+    ('\u{1F100}', "O."),
+    ('\u{1F101}', "O,"),
+    ('\u{1F102}', "l,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(l)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12A}', "(S)"),
+    ('\u{1F700}', "QE"),
+    ('\u{1F707}', "AR"),
+    ('\u{1F74C}', "C"),
+    ('\u{1F75C}', "sss"),
+    ('\u{1F768}', "T"),
+    ('\u{1F76B}', "MB"),
+    ('\u{1F76C}', "VB"),
+    ('\u{1FBF0}', "O"),
+    ('\u{1FBF1}', "l"),
+    ('\u{1FBF2}', "2"),
+    ('\u{1FBF3}', "3"),
+    ('\u{1FBF4}', "4"),
+    ('\u{1FBF5}', "5"),
+    ('\u{1FBF6}', "6"),
+    ('\u{1FBF7}', "7"),
+    ('\u{1FBF8}', "8"),
+    ('\u{1FBF9}', "9"),
+];
+
+pub(super) fn script_set(ch: char) -> [u64; 4] {
+    let ch = u32::from(ch);
+    let index = SCRIPT_EXTENSIONS.partition_point(|&(start, _)| start <= ch);
+    SCRIPT_SETS[usize::from(SCRIPT_EXTENSIONS[index - 1].1)]
+}
+
+pub(super) fn confusable_prototype(ch: char) -> Option<&'static str> {
+    CONFUSABLES
+        .binary_search_by_key(&ch, |&(x, _)| x)
+        .ok()
+        .map(|x| CONFUSABLES[x].1)
+}
@@ -0,0 +1,529 @@
+use alloc::borrow::Cow;
+#[cfg(feature = "os_str_bytes")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
+#[cfg(feature = "os_str_bytes")]
+use std::ffi::OsStr;
+#[cfg(feature = "os_str_bytes")]
+use std::ffi::OsString;
+#[cfg(all(feature = "os_str_bytes", windows))]
+use std::os::windows::ffi::OsStrExt;
+#[cfg(all(feature = "os_str_bytes", windows))]
+use std::os::windows::ffi::OsStringExt;
+#[cfg(feature = "os_str_bytes")]
+use std::path::Component;
+#[cfg(feature = "os_str_bytes")]
+use std::path::Path;
+#[cfg(feature = "os_str_bytes")]
+use std::path::PathBuf;
+#[cfg(all(feature = "os_str_bytes", windows))]
+use std::path::Prefix;
+
+#[cfg(all(feature = "os_str_bytes", not(windows)))]
+use os_str_bytes::OsStrBytes;
+#[cfg(all(feature = "os_str_bytes", not(windows)))]
+use os_str_bytes::OsStringBytes;
+
+use super::ToBytes;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~".contains(&byte)
+}
+
+fn is_sub_delim(byte: u8) -> bool {
+    b"!$&'()*+,;=".contains(&byte)
+}
+
+// Returns whether the byte can be used in a path segment without encoding,
+// according to the "pchar" rule of RFC 3986.
+fn is_pchar(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte) || b":@".contains(&byte)
+}
+
+// Returns whether the byte can be used in a host name without encoding,
+// according to the "reg-name" rule of RFC 3986.
+fn is_reg_name_char(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte)
+}
+
+fn write_encoded<F, W>(
+    writer: &mut W,
+    value: &[u8],
+    is_allowed: F,
+) -> fmt::Result
+where
+    F: Fn(u8) -> bool,
+    W: Write,
+{
+    for &byte in value {
+        if is_allowed(byte) {
+            writer.write_char(byte.into())?;
+        } else {
+            writer.write_char('%')?;
+            writer.write_char(HEX_DIGITS[usize::from(byte >> 4)].into())?;
+            writer.write_char(HEX_DIGITS[usize::from(byte & 0xF)].into())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "os_str_bytes")]
+fn push_encoded<F>(string: &mut String, value: &[u8], is_allowed: F)
+where
+    F: Fn(u8) -> bool,
+{
+    write_encoded(string, value, is_allowed)
+        .expect("failed writing to string");
+}
+
+// Unpaired surrogates are encoded using WTF-8, so that platform strings can
+// be converted to and from bytes losslessly on Windows.
+#[cfg(windows)]
+mod wtf8 {
+    use alloc::vec::Vec;
+    use core::str;
+
+    pub(super) fn encode<I>(string: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let mut result = Vec::new();
+        for ch in char::decode_utf16(string) {
+            match ch {
+                Ok(ch) => result
+                    .extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+                Err(error) => {
+                    let surrogate = error.unpaired_surrogate();
+                    result.extend_from_slice(&[
+                        0xE0 | (surrogate >> 12) as u8,
+                        0x80 | ((surrogate >> 6) & 0x3F) as u8,
+                        0x80 | (surrogate & 0x3F) as u8,
+                    ]);
+                }
+            }
+        }
+        result
+    }
+
+    #[cfg(feature = "os_str_bytes")]
+    pub(super) fn decode(mut string: &[u8]) -> Option<Vec<u16>> {
+        let mut result = Vec::new();
+        loop {
+            let error = match str::from_utf8(string) {
+                Ok(string) => {
+                    result.extend(string.encode_utf16());
+                    return Some(result);
+                }
+                Err(error) => error,
+            };
+            let (valid, invalid) = string.split_at(error.valid_up_to());
+            result.extend(str::from_utf8(valid).ok()?.encode_utf16());
+            let [0xED, byte_1 @ 0xA0..=0xBF, byte_2 @ 0x80..=0xBF, ..] =
+                *invalid
+            else {
+                return None;
+            };
+            result.push(
+                0xD000
+                    | (u16::from(byte_1 & 0x3F) << 6)
+                    | u16::from(byte_2 & 0x3F),
+            );
+            string = &invalid[3..];
+        }
+    }
+}
+
+/// The sets of characters encoded by [`percent_encode`].
+///
+/// Each set is based on the grammar in [RFC 3986]. Bytes that are not
+/// ASCII, control characters, spaces, `%`, and `#` are always encoded.
+///
+/// [RFC 3986]: https://www.rfc-editor.org/rfc/rfc3986#appendix-A
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EncodeSet {
+    /// Encode all characters that cannot be used in a single segment of a
+    /// path, including `/` and `?`.
+    PathSegment,
+
+    /// Encode all characters that cannot be used in a query, as well as `&`,
+    /// `+`, and `=`.
+    ///
+    /// These additional characters are often used to separate parameters,
+    /// so encoding them allows the result to be used as a parameter name or
+    /// value.
+    Query,
+
+    /// Encode all characters that cannot be used in user information, as
+    /// well as `:`.
+    ///
+    /// Encoding `:` allows the result to be used as a user name or password.
+    Userinfo,
+}
+
+impl EncodeSet {
+    fn is_allowed(self, byte: u8) -> bool {
+        match self {
+            Self::PathSegment => is_pchar(byte),
+            Self::Query => {
+                (is_pchar(byte) || b"/?".contains(&byte))
+                    && !b"&+=".contains(&byte)
+            }
+            Self::Userinfo => is_reg_name_char(byte),
+        }
+    }
+}
+
+/// A value returned by [`percent_encode`].
+pub struct PercentEncode<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    encode_set: EncodeSet,
+}
+
+impl<T> fmt::Display for PercentEncode<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_allowed = |x| self.encode_set.is_allowed(x);
+        #[cfg(windows)]
+        if let Some(string) = self.value.to_wide() {
+            return write_encoded(f, &wtf8::encode(string.0), is_allowed);
+        }
+        write_encoded(f, self.value.to_bytes().0.as_bytes(), is_allowed)
+    }
+}
+
+/// Wraps a value to be displayed as a percent-encoded URI component.
+///
+/// The returned struct implements [`fmt::Display`] by writing each byte of
+/// the value that is in the given set as `%` followed by two uppercase
+/// hexadecimal digits. Other bytes are written unchanged, so the output is
+/// always ASCII. Since invalid UTF-8 is encoded like any other byte, the
+/// value can be recovered using [`percent_decode`].
+///
+/// On Windows, wide character strings are encoded using [WTF-8], so unpaired
+/// surrogates are preserved.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::percent_encode;
+/// use print_bytes::EncodeSet;
+///
+/// assert_eq!(
+///     "foo%20bar%2Fbaz%FF",
+///     percent_encode(b"foo bar/baz\xFF", EncodeSet::PathSegment).to_string(),
+/// );
+/// assert_eq!(
+///     "a%3Db%26c/d?",
+///     percent_encode(b"a=b&c/d?", EncodeSet::Query).to_string(),
+/// );
+/// ```
+///
+/// [WTF-8]: https://simonsapin.github.io/wtf-8/
+#[inline]
+#[must_use]
+pub fn percent_encode<T>(
+    value: &T,
+    encode_set: EncodeSet,
+) -> PercentEncode<'_, T>
+where
+    T: ?Sized + ToBytes,
+{
+    PercentEncode { value, encode_set }
+}
+
+/// The error returned when parsing a URI fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UriError(());
+
+impl fmt::Display for UriError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid URI")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UriError {}
+
+fn hex_digit(byte: u8) -> Result<u8, UriError> {
+    char::from(byte)
+        .to_digit(16)
+        .map(|x| x as u8)
+        .ok_or(UriError(()))
+}
+
+/// Reverses the encoding performed by [`percent_encode`].
+///
+/// Strings that do not contain `%` are returned unchanged. Since every
+/// encoded byte is decoded, the result can contain any bytes, including
+/// invalid UTF-8.
+///
+/// # Errors
+///
+/// Returns an error if a `%` is not followed by two hexadecimal digits.
+///
+/// # Examples
+///
+/// ```
+/// use print_bytes::percent_decode;
+///
+/// assert_eq!(b"foo bar\xFF", &*percent_decode("foo%20bar%ff")?);
+/// assert!(percent_decode("100%").is_err());
+/// #
+/// # Ok::<_, print_bytes::UriError>(())
+/// ```
+#[inline]
+pub fn percent_decode(string: &str) -> Result<Cow<'_, [u8]>, UriError> {
+    let string = string.as_bytes();
+    if !string.contains(&b'%') {
+        return Ok(Cow::Borrowed(string));
+    }
+
+    let mut result = Vec::with_capacity(string.len());
+    let mut bytes = string.iter().copied();
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            result.push(byte);
+            continue;
+        }
+        let mut digit =
+            || bytes.next().ok_or(UriError(())).and_then(hex_digit);
+        result.push((digit()? << 4) | digit()?);
+    }
+    Ok(Cow::Owned(result))
+}
+
+#[cfg(feature = "os_str_bytes")]
+fn os_str_to_bytes(string: &OsStr) -> Option<Cow<'_, [u8]>> {
+    #[cfg(windows)]
+    {
+        Some(Cow::Owned(wtf8::encode(string.encode_wide())))
+    }
+    #[cfg(not(windows))]
+    string.to_io_bytes().map(Cow::Borrowed)
+}
+
+#[cfg(feature = "os_str_bytes")]
+fn os_string_from_bytes(string: Vec<u8>) -> Option<OsString> {
+    #[cfg(windows)]
+    {
+        wtf8::decode(&string).map(|x| OsString::from_wide(&x))
+    }
+    #[cfg(not(windows))]
+    OsString::from_io_vec(string)
+}
+
+// Returns the URI for an absolute path, or [None] if it cannot be
+// represented.
+#[cfg(feature = "os_str_bytes")]
+pub(super) fn file_uri(path: &Path, hostname: &str) -> Option<String> {
+    #[cfg_attr(not(windows), allow(unused_mut))]
+    let mut host = Cow::Borrowed(hostname.as_bytes());
+    #[cfg_attr(not(windows), allow(unused_mut))]
+    let mut prefix = String::new();
+    let mut components = path.components();
+    #[cfg(windows)]
+    if let Some(Component::Prefix(component)) = components.clone().next() {
+        let _ = components.next();
+        match component.kind() {
+            Prefix::Disk(drive) | Prefix::VerbatimDisk(drive) => {
+                prefix.push('/');
+                prefix.push(drive.into());
+                prefix.push(':');
+            }
+            Prefix::UNC(server, share)
+            | Prefix::VerbatimUNC(server, share) => {
+                host = os_str_to_bytes(server)?;
+                prefix.push('/');
+                push_encoded(&mut prefix, &os_str_to_bytes(share)?, is_pchar);
+            }
+            _ => return None,
+        }
+    }
+    if components.next() != Some(Component::RootDir) {
+        return None;
+    }
+
+    let mut string = String::from("file://");
+    push_encoded(&mut string, &host, is_reg_name_char);
+    string.push_str(&prefix);
+    let start = string.len();
+    for component in components {
+        string.push('/');
+        match component {
+            Component::Normal(name) => {
+                push_encoded(&mut string, &os_str_to_bytes(name)?, is_pchar);
+            }
+            _ => return None,
+        }
+    }
+    if string.len() == start {
+        string.push('/');
+    }
+    Some(string)
+}
+
+/// Converts an absolute path to a `file://` URI.
+///
+/// Each byte of the path that cannot be used in a URI is percent-encoded,
+/// so the path can be recovered using [`file_uri_to_path`]. The URI does
+/// not include a host name, except for UNC paths on Windows. On that
+/// platform, paths are encoded using [WTF-8], and drive letters are written
+/// as the first segment of the path, as in `file:///C:/foo`.
+///
+/// The path is normalized as by [`Path::components`], so redundant
+/// separators and `.` components are not preserved. Paths containing `..`
+/// components cannot be represented, since consumers of URIs remove them
+/// along with the preceding segment, even when they are percent-encoded.
+/// That would change the file referred to when the preceding segment is a
+/// symbolic link.
+///
+/// Returns [`None`] if the path is not absolute, if it contains `..`
+/// components, or if it uses a Windows prefix that cannot be represented in
+/// a URI, such as `\\.\COM1`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(unix)]
+/// # {
+/// use std::ffi::OsStr;
+/// use std::os::unix::ffi::OsStrExt;
+/// use std::path::Path;
+///
+/// use print_bytes::file_uri_to_path;
+/// use print_bytes::path_to_file_uri;
+///
+/// let path = Path::new(OsStr::from_bytes(b"/tmp/foo bar\xFF"));
+/// let uri = path_to_file_uri(path).expect("path is absolute");
+/// assert_eq!("file:///tmp/foo%20bar%FF", uri);
+/// assert_eq!(path, file_uri_to_path(&uri)?);
+///
+/// assert_eq!(None, path_to_file_uri(Path::new("foo")));
+/// # }
+/// #
+/// # Ok::<_, print_bytes::UriError>(())
+/// ```
+///
+/// [WTF-8]: https://simonsapin.github.io/wtf-8/
+#[cfg(feature = "os_str_bytes")]
+#[inline]
+#[must_use]
+pub fn path_to_file_uri(path: &Path) -> Option<String> {
+    file_uri(path, "")
+}
+
+/// Converts a `file://` URI to a path.
+///
+/// This function reverses the conversion performed by
+/// [`path_to_file_uri`]. The scheme is matched case-insensitively, and URIs
+/// without an authority, such as `file:/tmp`, are also accepted.
+///
+/// On Windows, a URI with a host name other than `localhost` is converted to
+/// a UNC path. Otherwise, the first segment of the path must be a drive,
+/// such as `C:`.
+///
+/// # Errors
+///
+/// Returns an error if the URI is not a valid `file://` URI, or if it cannot
+/// be converted to a path on this platform. This includes URIs with queries
+/// or fragments, encoded path separators, and, on platforms other than
+/// Windows, host names other than `localhost`, since those paths would not
+/// refer to files on this host.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use print_bytes::file_uri_to_path;
+///
+/// # #[cfg(unix)]
+/// # {
+/// let path = file_uri_to_path("file://localhost/tmp/foo%20bar")?;
+/// assert_eq!(Path::new("/tmp/foo bar"), path);
+///
+/// assert!(file_uri_to_path("file://example.com/tmp").is_err());
+/// # }
+/// # #[cfg(windows)]
+/// # {
+/// let path = file_uri_to_path("file:///C:/foo%20bar")?;
+/// assert_eq!(Path::new(r"C:\foo bar"), path);
+/// # }
+/// assert!(file_uri_to_path("http://example.com/").is_err());
+/// #
+/// # Ok::<_, print_bytes::UriError>(())
+/// ```
+#[cfg(feature = "os_str_bytes")]
+#[inline]
+pub fn file_uri_to_path(uri: &str) -> Result<PathBuf, UriError> {
+    let string = uri
+        .get(..5)
+        .filter(|x| x.eq_ignore_ascii_case("file:"))
+        .map(|_| &uri[5..])
+        .ok_or(UriError(()))?;
+    if string.contains(['?', '#']) {
+        return Err(UriError(()));
+    }
+    let (host, path) = if let Some(string) = string.strip_prefix("//") {
+        string.split_at(string.find('/').ok_or(UriError(()))?)
+    } else {
+        ("", string)
+    };
+    let path = path.strip_prefix('/').ok_or(UriError(()))?;
+
+    let host = percent_decode(host)?;
+    let is_local = host.is_empty() || host.eq_ignore_ascii_case(b"localhost");
+    #[cfg(not(windows))]
+    let separator = b'/';
+    #[cfg(windows)]
+    let separator = b'\\';
+
+    #[cfg_attr(not(windows), allow(unused_mut))]
+    let mut segments = path.split('/');
+    let mut string = Vec::with_capacity(uri.len());
+    #[cfg(windows)]
+    if is_local {
+        let drive = segments.next().unwrap_or_default();
+        if !matches!(*drive.as_bytes(), [x, b':'] if x.is_ascii_alphabetic()) {
+            return Err(UriError(()));
+        }
+        string.extend_from_slice(drive.as_bytes());
+    } else {
+        if host.contains(&b'/') || host.contains(&b'\\') {
+            return Err(UriError(()));
+        }
+        string.extend_from_slice(br"\\");
+        string.extend_from_slice(&host);
+    }
+    #[cfg(not(windows))]
+    if !is_local {
+        return Err(UriError(()));
+    }
+
+    for segment in segments {
+        let segment = percent_decode(segment)?;
+        if segment
+            .iter()
+            .any(|&x| x == 0 || x == b'/' || x == separator)
+        {
+            return Err(UriError(()));
+        }
+        string.push(separator);
+        string.extend_from_slice(&segment);
+    }
+    os_string_from_bytes(string)
+        .map(PathBuf::from)
+        .ok_or(UriError(()))
+}
@@ -1,5 +1,8 @@
 #![cfg_attr(windows, allow(private_interfaces))]
 
+use alloc::boxed::Box;
+#[cfg(any(doc, not(feature = "specialization")))]
+use alloc::vec::Vec;
 use std::io::BufWriter;
 use std::io::LineWriter;
 #[cfg(any(doc, not(feature = "specialization")))]
@@ -35,7 +38,7 @@ where
 #[cfg(all(feature = "specialization", windows))]
 impl<T> ToConsole for T
 where
-    T: AsHandle + ?Sized + Write,
+    T: AsHandle + ?Sized,
 {
     fn to_console(&self) -> Option<Console<'_>> {
         Console::from_handle(self)
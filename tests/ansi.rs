@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::strip_ansi;
+use print_bytes::write_lossy;
+use print_bytes::StripAnsi;
+
+fn to_vec(value: &StripAnsi<'_, [u8]>) -> io::Result<Vec<u8>> {
+    let mut string = Vec::new();
+    write_lossy(&mut string, value)?;
+    Ok(string)
+}
+
+#[test]
+fn test_strip() -> io::Result<()> {
+    #[rustfmt::skip]
+    let fixtures: [(&[u8], &[u8]); 14] = [
+        (b"foo\tbar\r\n\x07", b"foo\tbar\r\n\x07"),
+        (b"\x1B[1;31mfoo\x1B[0m", b"foo"),
+        (b"\x1B[2J\x1B[?25l\x1B[1 qfoo", b"foo"),
+        (b"\x1B]0;title\x07foo", b"foo"),
+        (b"\x1B]8;;file:///foo\x1B\\foo\x1B]8;;\x1B\\", b"foo"),
+        (b"\x1BP1$r0m\x1B\\foo\x1B_bar\x1B\\", b"foo"),
+        (b"\x1B(B\x1B7foo\x1B8\x1Bc", b"foo"),
+        ("\u{9B}31mfoo\u{9D}0;title\u{9C}".as_bytes(), b"foo"),
+        ("\u{85}foo".as_bytes(), "\u{85}foo".as_bytes()),
+        (b"foo\x1B[31\x80bar", b"foo\x80bar"),
+        (b"foo\x1B[31\x1B[0mbar", b"foobar"),
+        (b"foo\x1B]0;title", b"foo"),
+        (b"foo\x1B", b"foo"),
+        (b"\xFF\x1B[m\xFF", b"\xFF\xFF"),
+    ];
+    for (value, expected) in fixtures {
+        assert_eq!(expected, &*to_vec(&strip_ansi(value))?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_keep_sgr() -> io::Result<()> {
+    #[rustfmt::skip]
+    let fixtures: [(&[u8], &[u8]); 5] = [
+        (b"\x1B[1;31mfoo\x1B[m", b"\x1B[1;31mfoo\x1B[m"),
+        (b"\x1B[38:5:196mfoo\x1B[2K", b"\x1B[38:5:196mfoo"),
+        ("\u{9B}0m".as_bytes(), "\u{9B}0m".as_bytes()),
+        (b"\x1B[?1m\x1B[1 m\x1B]0;m\x07", b""),
+        (b"\x1B[1;31", b""),
+    ];
+    for (value, expected) in fixtures {
+        assert_eq!(expected, &*to_vec(&strip_ansi(value).keep_sgr(true))?);
+    }
+    Ok(())
+}
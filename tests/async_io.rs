@@ -0,0 +1,52 @@
+#![cfg(any(feature = "futures-io", feature = "tokio"))]
+
+use std::io;
+
+const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz";
+
+#[cfg(feature = "futures-io")]
+#[tokio::test]
+async fn test_futures_io() -> io::Result<()> {
+    use print_bytes::futures_io::write_lossy_async;
+
+    let mut writer = Vec::new();
+    write_lossy_async(&mut writer, INVALID_STRING).await?;
+    write_lossy_async(&mut writer, b"").await?;
+    assert_eq!(INVALID_STRING, writer);
+
+    Ok(())
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_tokio() -> io::Result<()> {
+    use print_bytes::tokio::write_lossy_async;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::io::BufWriter;
+
+    let mut writer = BufWriter::new(Vec::new());
+    write_lossy_async(&mut writer, INVALID_STRING).await?;
+    write_lossy_async(&mut writer, b"").await?;
+    writer.flush().await?;
+    assert_eq!(INVALID_STRING, writer.into_inner());
+
+    // The value is larger than the buffer, so it requires multiple writes.
+    let value = INVALID_STRING.repeat(16);
+
+    let (mut writer, mut reader) = tokio::io::duplex(8);
+    let mut string = Vec::new();
+    let (written, read) = tokio::join!(
+        async {
+            write_lossy_async(&mut writer, &value).await?;
+            drop(writer);
+            Ok::<_, io::Error>(())
+        },
+        reader.read_to_end(&mut string),
+    );
+    written?;
+    let _ = read?;
+    assert_eq!(value, string);
+
+    Ok(())
+}
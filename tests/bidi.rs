@@ -0,0 +1,88 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::bidi_safe;
+use print_bytes::write_lossy;
+use print_bytes::BidiPolicy;
+
+fn assert_written(
+    expected: &str,
+    value: &str,
+    policy: BidiPolicy,
+) -> io::Result<()> {
+    let mut string = Vec::new();
+    write_lossy(&mut string, &bidi_safe(value.as_bytes(), policy))?;
+    assert_eq!(expected.as_bytes(), string);
+    Ok(())
+}
+
+#[test]
+fn test_escape() -> io::Result<()> {
+    assert_written("foo.txt", "foo.txt", BidiPolicy::Escape)?;
+    assert_written(
+        r"invoice\u{202e}fdp.exe",
+        "invoice\u{202E}fdp.exe",
+        BidiPolicy::Escape,
+    )?;
+    assert_written(
+        r"\u{61c}\u{200e}\u{200f}\u{202a}\u{202b}\u{202c}\u{202d}",
+        "\u{61C}\u{200E}\u{200F}\u{202A}\u{202B}\u{202C}\u{202D}",
+        BidiPolicy::Escape,
+    )?;
+    assert_written(
+        r"\u{2066}\u{2067}\u{2068}\u{2069}\",
+        "\u{2066}\u{2067}\u{2068}\u{2069}\\",
+        BidiPolicy::Escape,
+    )?;
+    assert_written(
+        "\u{5D0}\u{200B}\u{FEFF}",
+        "\u{5D0}\u{200B}\u{FEFF}",
+        BidiPolicy::Escape,
+    )?;
+
+    let mut string = Vec::new();
+    let value = b"\xE2\x80\xAE\xE2\x80\xF1";
+    write_lossy(&mut string, &bidi_safe(value, BidiPolicy::Escape))?;
+    assert_eq!(b"\\u{202e}\xE2\x80\xF1", &*string);
+    Ok(())
+}
+
+#[test]
+fn test_isolate() -> io::Result<()> {
+    assert_written("\u{2068}\u{2069}", "", BidiPolicy::Isolate)?;
+    assert_written(
+        "\u{2068}invoice\u{202E}fdp.exe\u{2069}",
+        "invoice\u{202E}fdp.exe",
+        BidiPolicy::Isolate,
+    )?;
+    assert_written(
+        "\u{2068}\u{2067}a\u{2069}\u{2066}b\u{2069}\u{2069}",
+        "\u{2067}a\u{2069}\u{2066}b",
+        BidiPolicy::Isolate,
+    )?;
+    assert_written(
+        "\u{2068}\\u{2069}\u{202E}a\u{2069}",
+        "\u{2069}\u{202E}a",
+        BidiPolicy::Isolate,
+    )
+}
+
+#[test]
+fn test_warn() -> io::Result<()> {
+    assert_written("a\u{202E}b", "a\u{202E}b", BidiPolicy::Warn)?;
+
+    let value = bidi_safe("a\u{200F}b\u{202E}".as_bytes(), BidiPolicy::Warn);
+    let warning = value.check().unwrap_err();
+    assert_eq!('\u{200F}', warning.control());
+    assert_eq!(
+        "value contains bidirectional formatting character U+200F",
+        warning.to_string(),
+    );
+
+    assert_eq!(Ok(()), bidi_safe(b"foo\xF1", BidiPolicy::Warn).check());
+    let value = "\u{202E}".as_bytes();
+    assert_eq!(Ok(()), bidi_safe(value, BidiPolicy::Escape).check());
+    assert_eq!(Ok(()), bidi_safe(value, BidiPolicy::Isolate).check());
+    Ok(())
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::caret_notation;
+use print_bytes::write_lossy;
+use print_bytes::CaretNotation;
+
+const VALUE: &[u8] = b"a\tb\r\nc\x01\x7F\x80\x9F\xA0\xFF\xC3\xA9\n\r\nx\r";
+
+fn assert_rendered<F>(expected: &[u8], options: F) -> io::Result<()>
+where
+    F: FnOnce(CaretNotation<'_, [u8]>) -> CaretNotation<'_, [u8]>,
+{
+    let mut string = Vec::new();
+    write_lossy(&mut string, &options(caret_notation(VALUE)))?;
+    assert_eq!(expected, string, "{:?}", String::from_utf8_lossy(&string));
+    Ok(())
+}
+
+#[test]
+fn test_show_nonprinting() -> io::Result<()> {
+    assert_rendered(b"a\tb^M\nc^A^?M-^@M-^_M- M-^?M-CM-)\n^M\nx^M", |x| x)?;
+    assert_rendered(b"a^Ib^M\nc^A^?M-^@M-^_M- M-^?M-CM-)\n^M\nx^M", |x| {
+        x.show_tabs(true)
+    })?;
+    assert_rendered(b"a\tb^M$\nc^A^?M-^@M-^_M- M-^?M-CM-)$\n^M$\nx^M", |x| {
+        x.show_ends(true)
+    })?;
+    assert_rendered(b"a^Ib^M$\nc^A^?M-^@M-^_M- M-^?M-CM-)$\n^M$\nx^M", |x| {
+        x.show_all()
+    })
+}
+
+#[test]
+fn test_show_printing() -> io::Result<()> {
+    assert_rendered(VALUE, |x| x.show_nonprinting(false))?;
+    assert_rendered(
+        b"a^Ib\r\nc\x01\x7F\x80\x9F\xA0\xFF\xC3\xA9\n\r\nx\r",
+        |x| x.show_nonprinting(false).show_tabs(true),
+    )?;
+    assert_rendered(
+        b"a\tb^M$\nc\x01\x7F\x80\x9F\xA0\xFF\xC3\xA9$\n^M$\nx\r",
+        |x| x.show_nonprinting(false).show_ends(true),
+    )?;
+    assert_rendered(
+        b"a^Ib^M$\nc\x01\x7F\x80\x9F\xA0\xFF\xC3\xA9$\n^M$\nx\r",
+        |x| x.show_nonprinting(false).show_tabs(true).show_ends(true),
+    )
+}
+
+#[test]
+fn test_all_bytes() -> io::Result<()> {
+    let value: Vec<_> = (0..=u8::MAX).collect();
+    let mut string = Vec::new();
+    write_lossy(&mut string, &caret_notation(&*value).show_all())?;
+    assert!(string.is_ascii());
+    assert!(string.starts_with(b"^@^A^B"));
+    assert!(string.ends_with(b"M-}M-~M-^?"));
+    Ok(())
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::chain;
+use print_bytes::from_fn;
+use print_bytes::join;
+use print_bytes::write_lossy;
+use print_bytes::ToBytes;
+
+fn assert_written<T>(expected: &[u8], value: &T) -> io::Result<()>
+where
+    T: ?Sized + ToBytes,
+{
+    let mut writer = Vec::new();
+    write_lossy(&mut writer, value)?;
+    assert_eq!(expected, writer);
+    Ok(())
+}
+
+#[test]
+fn test_chain() -> io::Result<()> {
+    assert_written(b"foobar", &chain(b"foo", b"bar"))?;
+    assert_written(b"foo", &chain(b"", b"foo"))?;
+    assert_written(b"\xF0\x9F\x92\x96", &chain(b"\xF0\x9F", b"\x92\x96"))?;
+    assert_written(b"foo/bar.txt", &chain(&chain(b"foo", b"/"), b"bar.txt"))
+}
+
+#[test]
+fn test_join() -> io::Result<()> {
+    let values: [&[u8]; 3] = [b"foo", b"\x80", b"bar"];
+    assert_written(b"foo:\x80:bar", &join(values, b":"))?;
+    assert_written(b"foo\x80bar", &join(values, b""))?;
+
+    let values = vec![b"foo".to_vec(), b"bar".to_vec()];
+    assert_written(b"foo/bar", &join(&values, b"/"))?;
+
+    let values: [&[u8]; 0] = [];
+    assert_written(b"", &join(values, b":"))?;
+
+    assert_written(b"foo", &join(vec![b"foo".to_vec()], b":"))
+}
+
+#[test]
+fn test_from_fn() -> io::Result<()> {
+    let value = from_fn(|parts| {
+        for i in 0..3 {
+            parts.push(&[b'0' + i]);
+        }
+    });
+    assert_written(b"012", &value)?;
+    assert_written(b"012", &value)?;
+
+    assert_written(b"", &from_fn(|_| ()))
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "os_str_bytes")]
+
+use std::process::Command;
+
+use print_bytes::command_line;
+
+fn assert_displayed(expected: &str, command: &Command) {
+    assert_eq!(expected, command_line(command).to_string());
+}
+
+#[test]
+fn test_args() {
+    let mut command = Command::new("echo");
+    assert_displayed("echo", &command);
+
+    let _ = command.args(["foo", "", "it's", "a\nb"]);
+    assert_displayed(r"echo foo '' 'it'\''s' 'a'$'\x0a''b'", &command);
+
+    assert_displayed("'FOO=bar' baz", Command::new("FOO=bar").arg("baz"));
+    assert_displayed(
+        r#"env -u X sh -c 'exec "$0" "$@"' 'FOO=bar' baz"#,
+        Command::new("FOO=bar").arg("baz").env_remove("X"),
+    );
+}
+
+#[test]
+fn test_reserved_words() {
+    assert_displayed("'time' ls", Command::new("time").arg("ls"));
+    assert_displayed("'if' true", Command::new("if").arg("true"));
+    assert_displayed("'!' true", Command::new("!").arg("true"));
+    assert_displayed("'{' ls", Command::new("{").arg("ls"));
+    assert_displayed("'[[' x", Command::new("[[").arg("x"));
+    assert_displayed("A=1 'while'", Command::new("while").env("A", "1"));
+    assert_displayed("env -u X 'do'", Command::new("do").env_remove("X"));
+
+    assert_displayed(
+        "echo if time",
+        Command::new("echo").args(["if", "time"]),
+    );
+    assert_displayed("timeout 1", Command::new("timeout").arg("1"));
+    assert_displayed("ifconfig", &Command::new("ifconfig"));
+}
+
+#[test]
+fn test_env() {
+    let mut command = Command::new("make");
+    let _ = command
+        .env("CC", "clang -g")
+        .env("B", "")
+        .current_dir("src");
+    assert_displayed("cd src && B='' CC='clang -g' make", &command);
+
+    let _ = command.env_remove("HOME").env("A-B", "1");
+    assert_displayed(
+        "cd src && env -u HOME A-B=1 B= 'CC=clang -g' make",
+        &command,
+    );
+}
+
+#[test]
+fn test_dash() {
+    assert_displayed("cd ./- && ls", Command::new("ls").current_dir("-"));
+    assert_displayed("cd ./-x && ls", Command::new("ls").current_dir("-x"));
+    assert_displayed("cd /-x && ls", Command::new("ls").current_dir("/-x"));
+
+    assert_displayed("-x", &Command::new("-x"));
+    assert_displayed(
+        "env -u X -- -x -y",
+        Command::new("-x").arg("-y").env_remove("X"),
+    );
+    assert_displayed(
+        "env -- -A=1 B=2 -x",
+        Command::new("-x").env("-A", "1").env("B", "2"),
+    );
+    assert_displayed("env A-B=1 -x", Command::new("-x").env("A-B", "1"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_invalid() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let value = OsStr::from_bytes(b"foo\x80");
+    let mut command = Command::new(value);
+    let _ = command.arg(value).env("X", value);
+    assert_displayed(r"X='foo'$'\x80' 'foo'$'\x80' 'foo'$'\x80'", &command);
+}
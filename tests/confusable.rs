@@ -0,0 +1,118 @@
+use print_bytes::check_confusables;
+use print_bytes::display;
+
+fn scripts(value: &str) -> Vec<&'static str> {
+    check_confusables(value.as_bytes())
+        .scripts()
+        .iter()
+        .map(|x| x.code())
+        .collect()
+}
+
+#[test]
+fn test_confusables() {
+    let report = check_confusables("p\u{430}yp\u{430}l".as_bytes());
+    assert!(report.is_mixed_script());
+    assert_eq!(1, report.chars().len());
+    let ch = report.chars()[0];
+    assert_eq!('\u{430}', ch.ch());
+    assert_eq!(Some("a"), ch.prototype());
+    assert!(ch.is_mixed_script());
+
+    let report = check_confusables("\u{FF41}\u{2215}\u{3BB}".as_bytes());
+    assert_eq!(
+        [Some("a"), Some("/")],
+        *report
+            .chars()
+            .iter()
+            .map(|x| x.prototype())
+            .collect::<Vec<_>>(),
+    );
+    assert!(!report.chars()[1].is_mixed_script());
+
+    for value in ["foo/bar-1.txt", "\u{65E5}\u{672C}", "caf\u{E9}", ""] {
+        assert!(!check_confusables(value.as_bytes()).is_suspicious());
+    }
+    assert!(!check_confusables(b"foo\x80").is_suspicious());
+}
+
+#[test]
+fn test_single_script() {
+    let report = check_confusables("\u{441}\u{43E}\u{440}".as_bytes());
+    assert!(!report.is_mixed_script());
+    assert_eq!(
+        [Some("c"), Some("o"), Some("p")],
+        *report
+            .chars()
+            .iter()
+            .map(|x| x.prototype())
+            .collect::<Vec<_>>(),
+    );
+    assert!(report.chars().iter().all(|x| !x.is_mixed_script()));
+    assert_eq!(["Cyrl"], *scripts("\u{441}\u{43E}\u{440}"));
+
+    for value in ["\u{3BF}\u{3BD}", "\u{FF41}\u{2215}b"] {
+        let report = check_confusables(value.as_bytes());
+        assert!(!report.is_mixed_script());
+        assert!(report.is_suspicious());
+        assert!(report
+            .chars()
+            .iter()
+            .all(|x| x.prototype().is_some() && !x.is_mixed_script()));
+    }
+}
+
+#[test]
+fn test_mixed_script() {
+    let report = check_confusables("\u{3BB}\u{3BC}c".as_bytes());
+    assert!(report.is_mixed_script());
+    assert_eq!(["Grek", "Latn"], *scripts("\u{3BB}\u{3BC}c"));
+    let ch = report.chars()[0];
+    assert_eq!('c', ch.ch());
+    assert_eq!(None, ch.prototype());
+    assert!(ch.is_mixed_script());
+
+    let value = "\u{3072}\u{3089}\u{304C}\u{306A}\u{6F22}\u{5B57}";
+    assert!(!check_confusables(value.as_bytes()).is_mixed_script());
+    assert_eq!(["Hani", "Hira"], *scripts(value));
+
+    let value = "\u{D55C}\u{AE00}\u{6F22}\u{5B57}";
+    assert!(!check_confusables(value.as_bytes()).is_mixed_script());
+    assert_eq!(["Hang", "Hani"], *scripts(value));
+
+    let value = "\u{D55C}\u{3072}";
+    assert!(check_confusables(value.as_bytes()).is_mixed_script());
+    assert!(!check_confusables(b"123 - 456").is_mixed_script());
+    assert!(scripts("123 - 456").is_empty());
+}
+
+#[test]
+fn test_script() {
+    let report = check_confusables("\u{430}".as_bytes());
+    let script = report.scripts()[0];
+    assert_eq!("Cyrl", script.code());
+    assert_eq!("Cyrillic", script.name());
+    assert_eq!("Cyrillic", script.to_string());
+}
+
+#[test]
+fn test_escape_confusables() {
+    let escape =
+        |x: &str| display(x.as_bytes()).escape_confusables(true).to_string();
+    assert_eq!(r"p\u{430}ypal", escape("p\u{430}ypal"));
+    assert_eq!(r"\u{441}\u{43e}\u{440}", escape("\u{441}\u{43E}\u{440}"));
+    assert_eq!("\u{3BB}\u{3BC}\\u{63}", escape("\u{3BB}\u{3BC}c"));
+    assert_eq!(r"foo\\bar", escape(r"foo\bar"));
+    assert_eq!("\u{65E5}\u{672C}", escape("\u{65E5}\u{672C}"));
+    assert_eq!(
+        r"p\u{430}y\u{200b}pal",
+        display("p\u{430}y\u{200B}pal".as_bytes())
+            .escape_confusables(true)
+            .escape_invisible(true)
+            .to_string(),
+    );
+    assert_eq!(
+        "p\u{430}ypal",
+        display("p\u{430}ypal".as_bytes()).to_string()
+    );
+}
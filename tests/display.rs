@@ -0,0 +1,123 @@
+use print_bytes::display;
+
+const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz\\";
+const ESCAPED_STRING: &str = r"\xf1foo\xf1\x80bar\xf1\x80\x80baz\\";
+
+#[test]
+fn test_display() {
+    assert_eq!(ESCAPED_STRING, display(INVALID_STRING).to_string());
+    assert_eq!("[foobar]", format!("[{}]", display(b"foobar")));
+
+    assert_eq!(
+        "Display { value: [102, 111, 111], escape_invisible: false, \
+         escape_confusables: false }",
+        format!("{:?}", display(b"foo")),
+    );
+    assert_eq!(
+        "Display { value: \"foo\", escape_invisible: true, \
+         escape_confusables: true }",
+        format!(
+            "{:?}",
+            display(c"foo")
+                .escape_invisible(true)
+                .escape_confusables(true),
+        ),
+    );
+}
+
+#[test]
+fn test_copy() {
+    fn assert_copy<T>(_: T)
+    where
+        T: Copy,
+    {
+    }
+
+    // Neither [u8] nor CStr implements Clone.
+    let value = display(INVALID_STRING);
+    assert_copy(value);
+    assert_copy(display(c"foo"));
+
+    let copy = value;
+    assert_eq!(ESCAPED_STRING, value.to_string());
+    assert_eq!(ESCAPED_STRING, copy.to_string());
+
+    let value = display("a\u{200B}".as_bytes()).escape_invisible(true);
+    let clone = Clone::clone(&value);
+    assert_eq!(r"a\u{200b}", clone.to_string());
+    assert_eq!(r"a\u{200b}", value.to_string());
+    assert_eq!(format!("{:?}", value), format!("{:?}", clone));
+}
+
+#[test]
+fn test_escape_invisible() {
+    #[rustfmt::skip]
+    let fixtures = [
+        ("foo bar", "foo bar"),
+        ("caf\u{E9} \u{1F600} \u{65}\u{301}", "caf\u{E9} \u{1F600} e\u{301}"),
+        ("a\tb\nc\u{7F}\u{85}", r"a\u{9}b\u{a}c\u{7f}\u{85}"),
+        ("\u{A0}\u{2007}\u{3000}", r"\u{a0}\u{2007}\u{3000}"),
+        ("\u{2028}\u{2029}", r"\u{2028}\u{2029}"),
+        ("a\u{200B}\u{200D}b", r"a\u{200b}\u{200d}b"),
+        ("\u{AD}\u{FEFF}", r"\u{ad}\u{feff}"),
+        ("\u{202E}\u{2066}", r"\u{202e}\u{2066}"),
+        ("\u{2764}\u{FE0F}", "\u{2764}\\u{fe0f}"),
+        ("\u{E0041}\u{E0100}", r"\u{e0041}\u{e0100}"),
+        ("\u{3164}\u{115F}", r"\u{3164}\u{115f}"),
+        ("\u{E000}\u{F0000}", r"\u{e000}\u{f0000}"),
+        ("\u{378}\u{FFFF}", r"\u{378}\u{ffff}"),
+        ("\\\u{200B}", r"\\\u{200b}"),
+    ];
+    for (value, expected) in fixtures {
+        assert_eq!(
+            expected,
+            display(value.as_bytes()).escape_invisible(true).to_string(),
+        );
+        assert_eq!(
+            value.replace('\\', r"\\"),
+            display(value.as_bytes()).to_string(),
+        );
+    }
+
+    assert_eq!(
+        r"\u{200b}\xf1\u{a0}",
+        display(b"\xE2\x80\x8B\xF1\xC2\xA0")
+            .escape_invisible(true)
+            .to_string(),
+    );
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn test_log() {
+    use log::kv::ToValue;
+
+    let value = display(INVALID_STRING);
+    #[cfg(not(feature = "serde"))]
+    assert_eq!(ESCAPED_STRING, value.to_value().to_string());
+    #[cfg(feature = "serde")]
+    {
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            serde_json::to_string(&value.to_value()).unwrap(),
+        );
+        assert_eq!(
+            "\"foo\"",
+            serde_json::to_string(&display(b"foo").to_value()).unwrap(),
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() -> serde_json::Result<()> {
+    let value = display(INVALID_STRING);
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        INVALID_STRING,
+        print_bytes::serde::bytes::deserialize(
+            &mut serde_json::Deserializer::from_str(&json),
+        )?,
+    );
+    Ok(())
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "embedded-io")]
+
+use embedded_io::SliceWriteError;
+
+use print_bytes::embedded_io::write_lossy;
+
+const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz";
+
+#[test]
+fn test_write() -> Result<(), SliceWriteError> {
+    const LENGTH: usize = 32;
+
+    let mut buffer = [0; LENGTH];
+    let mut writer = &mut buffer[..];
+    write_lossy(&mut writer, INVALID_STRING)?;
+    write_lossy(&mut writer, b"!")?;
+    let written_length = LENGTH - writer.len();
+
+    let mut expected = INVALID_STRING.to_owned();
+    expected.push(b'!');
+    assert_eq!(expected, &buffer[..written_length]);
+
+    Ok(())
+}
+
+#[test]
+fn test_full_write() {
+    let mut buffer = [0; 4];
+    assert_eq!(
+        Err(SliceWriteError::Full),
+        write_lossy(&mut &mut buffer[..], INVALID_STRING),
+    );
+}
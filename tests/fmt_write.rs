@@ -0,0 +1,42 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use print_bytes::write_fmt_escaped;
+use print_bytes::write_fmt_lossy;
+
+const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz\\";
+
+#[test]
+fn test_lossy() -> fmt::Result {
+    let mut string = String::new();
+    write_fmt_lossy(&mut string, INVALID_STRING)?;
+    assert_eq!(String::from_utf8_lossy(INVALID_STRING), string);
+    Ok(())
+}
+
+#[test]
+fn test_escaped() -> fmt::Result {
+    let mut string = String::new();
+    write_fmt_escaped(&mut string, INVALID_STRING)?;
+    assert_eq!(r"\xf1foo\xf1\x80bar\xf1\x80\x80baz\\", string);
+
+    string.clear();
+    write_fmt_escaped(&mut string, "\u{1F496}\\\\".as_bytes())?;
+    assert_eq!("\u{1F496}\\\\\\\\", string);
+
+    Ok(())
+}
+
+#[test]
+fn test_formatter() {
+    struct Value(&'static [u8]);
+
+    impl Display for Value {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write_fmt_lossy(f, self.0)
+        }
+    }
+
+    assert_eq!("foo\u{FFFD}", Value(b"foo\x80").to_string());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "std")]
+
+use std::borrow::Cow;
+use std::io;
+
+use print_bytes::git_quote;
+use print_bytes::git_unquote;
+use print_bytes::write_lossy;
+
+type Quoted = [&'static [u8]; 3];
+
+#[rustfmt::skip]
+const FIXTURES: [(&[u8], Quoted); 13] = [
+    (b"foo", [b"foo", b"foo", b"foo"]),
+    (b"foo bar", [b"foo bar", b"foo bar", br#""foo bar""#]),
+    (b"q'uote", [b"q'uote", b"q'uote", b"q'uote"]),
+    (b"caf\xC3\xA9", [
+        br#""caf\303\251""#, b"caf\xC3\xA9", br#""caf\303\251""#,
+    ]),
+    (b"sp\xC3\xA9 ce", [
+        br#""sp\303\251 ce""#, b"sp\xC3\xA9 ce", br#""sp\303\251 ce""#,
+    ]),
+    (b"bad\x80\xFF", [
+        br#""bad\200\377""#, b"bad\x80\xFF", br#""bad\200\377""#,
+    ]),
+    (b"a\"b", [br#""a\"b""#, br#""a\"b""#, br#""a\"b""#]),
+    (b"a\\b", [br#""a\\b""#, br#""a\\b""#, br#""a\\b""#]),
+    (b"tab\there", [br#""tab\there""#, br#""tab\there""#, br#""tab\there""#]),
+    (b"nl\nx", [br#""nl\nx""#, br#""nl\nx""#, br#""nl\nx""#]),
+    (b"bell\x07\x08\x0B\x0C\rx", [
+        br#""bell\a\b\v\f\rx""#, br#""bell\a\b\v\f\rx""#,
+        br#""bell\a\b\v\f\rx""#,
+    ]),
+    (b"\x01\x7F", [br#""\001\177""#, br#""\001\177""#, br#""\001\177""#]),
+    (b"\xC3\xA9 \n", [
+        br#""\303\251 \n""#, b"\"\xC3\xA9 \\n\"", br#""\303\251 \n""#,
+    ]),
+];
+
+#[test]
+fn test_quote() -> io::Result<()> {
+    for (value, expected) in FIXTURES {
+        let quoted = [
+            git_quote(value),
+            git_quote(value).quote_path(false),
+            git_quote(value).quote_spaces(true),
+        ];
+        for (quoted, expected) in quoted.into_iter().zip(expected) {
+            let mut string = Vec::new();
+            write_lossy(&mut string, &quoted)?;
+            assert_eq!(expected, string, "{:?}", value);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_unquote() {
+    for (value, expected) in FIXTURES {
+        for quoted in expected {
+            assert_eq!(Ok(value), git_unquote(quoted).as_deref());
+        }
+    }
+
+    assert_eq!(
+        Ok(Cow::Borrowed(&b"foo\"bar"[..])),
+        git_unquote(b"foo\"bar")
+    );
+    assert_eq!(Ok(&b""[..]), git_unquote(br#""""#).as_deref());
+    assert_eq!(Ok(&b"\0"[..]), git_unquote(br#""\000""#).as_deref());
+}
+
+#[test]
+fn test_unquote_invalid() {
+    for value in [
+        &br#"""#[..],
+        br#""foo"#,
+        br#""foo"bar""#,
+        br#""foo" "#,
+        br#""foo\""#,
+        br#""\x41""#,
+        br#""\e""#,
+        br#""\400""#,
+        br#""\08""#,
+        br#""\07""#,
+    ] {
+        assert!(git_unquote(value).is_err(), "{:?}", value);
+    }
+}
@@ -0,0 +1,104 @@
+use print_bytes::hexdump;
+use print_bytes::HexdumpStyle;
+
+const VALUE: &[u8] = b"hello, world\x00\x01\xFF\x80abcdefghijklmnopqrstu";
+
+#[test]
+fn test_canonical() {
+    assert_eq!(
+        "\
+00000000  68 65 6c 6c 6f 2c 20 77  6f 72 6c 64 00 01 ff 80  |hello, world....|
+00000010  61 62 63 64 65 66 67 68  69 6a 6b 6c 6d 6e 6f 70  |abcdefghijklmnop|
+00000020  71 72 73 74 75                                    |qrstu|
+00000025",
+        hexdump(VALUE, HexdumpStyle::Canonical).to_string(),
+    );
+    assert_eq!(
+        "\
+00000000  66 6f 6f 80 0a                                    |foo..|
+00000005",
+        hexdump(b"foo\x80\n", HexdumpStyle::Canonical).to_string(),
+    );
+    assert_eq!(
+        "\
+00000000  68 65 6c 6c 6f 2c  20 77 6f 72 6c 64  00 01 ff 80  |hello, world....|
+00000010  61 62 63 64 65 66  67 68 69 6a 6b 6c  6d 6e 6f 70  |abcdefghijklmnop|
+00000020  71 72 73 74 75                                     |qrstu|
+00000025",
+        hexdump(VALUE, HexdumpStyle::Canonical)
+            .group_size(6)
+            .to_string(),
+    );
+    assert_eq!("", hexdump(b"", HexdumpStyle::Canonical).to_string());
+}
+
+#[test]
+fn test_xxd() {
+    assert_eq!(
+        "\
+00000000: 6865 6c6c 6f2c 2077 6f72 6c64 0001 ff80  hello, world....
+00000010: 6162 6364 6566 6768 696a 6b6c 6d6e 6f70  abcdefghijklmnop
+00000020: 7172 7374 75                             qrstu",
+        hexdump(VALUE, HexdumpStyle::Xxd).to_string(),
+    );
+    assert_eq!(
+        "\
+00000000: 68 65 6c 6c 6f 2c 20 77  hello, w
+00000008: 6f 72 6c 64 00 01 ff 80  orld....
+00000010: 61 62 63 64 65 66 67 68  abcdefgh
+00000018: 69 6a 6b 6c 6d 6e 6f 70  ijklmnop
+00000020: 71 72 73 74 75           qrstu",
+        hexdump(VALUE, HexdumpStyle::Xxd)
+            .width(8)
+            .group_size(1)
+            .to_string(),
+    );
+    assert_eq!(
+        "\
+00000000: 68656c6c6f2c20776f726c64  hello, world
+0000000c: 0001ff806162636465666768  ....abcdefgh
+00000018: 696a6b6c6d6e6f7071727374  ijklmnopqrst
+00000024: 75                        u",
+        hexdump(VALUE, HexdumpStyle::Xxd)
+            .width(12)
+            .group_size(0)
+            .to_string(),
+    );
+    assert_eq!("", hexdump(b"", HexdumpStyle::Xxd).to_string());
+}
+
+#[test]
+#[should_panic = "width is zero"]
+fn test_zero_width() {
+    let _ = hexdump(VALUE, HexdumpStyle::Xxd).width(0);
+}
+
+#[test]
+fn test_threshold() {
+    // Two of the six bytes are suspicious.
+    let value = |x| hexdump(b"foo\\\x80\x01", HexdumpStyle::Xxd).threshold(x);
+    assert_eq!(r"foo\\\x80\u{1}", value(0.5).to_string());
+    assert_eq!(r"foo\\\x80\u{1}", value(1.0 / 3.0).to_string());
+    assert_eq!(
+        "00000000: 666f 6f5c 8001                           foo\\..",
+        value(0.3).to_string(),
+    );
+
+    // The escape sequence is mostly printable, but it must not reach the
+    // terminal.
+    let value = b"error: \x1b[31mfailed\x1b[0m \xe2\x80\xae";
+    assert_eq!(
+        r"error: \u{1b}[31mfailed\u{1b}[0m \u{202e}",
+        hexdump(value, HexdumpStyle::Xxd).threshold(0.5).to_string(),
+    );
+
+    let value = hexdump(b"foo", HexdumpStyle::Xxd).threshold(0.0);
+    assert_eq!("foo", value.to_string());
+    let value = hexdump(b"", HexdumpStyle::Xxd).threshold(0.0);
+    assert_eq!("", value.to_string());
+    let value = hexdump("\u{85}".as_bytes(), HexdumpStyle::Xxd).threshold(0.5);
+    assert_eq!(
+        "00000000: c285                                     ..",
+        value.to_string()
+    );
+}
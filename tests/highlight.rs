@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::highlight;
+use print_bytes::write_lossy;
+use print_bytes::Highlight;
+use print_bytes::HighlightStyle;
+
+const VALUE: &[u8] = b"foo\\\x80\xF1\x80bar";
+
+fn to_vec(value: &Highlight<'_, [u8]>) -> io::Result<Vec<u8>> {
+    let mut string = Vec::new();
+    write_lossy(&mut string, value)?;
+    Ok(string)
+}
+
+#[test]
+fn test_highlight() -> io::Result<()> {
+    assert_eq!(
+        b"foo\x1B[7m\\\\\x1B[27m\x1B[7m\\x80\x1B[27m\
+          \x1B[7m\\xf1\\x80\x1B[27mbar",
+        &*to_vec(&highlight(VALUE, false).color(true))?,
+    );
+    assert_eq!(
+        "foo\\\x1B[31m\u{FFFD}\x1B[39m\x1B[31m\u{FFFD}\x1B[39mbar".as_bytes(),
+        &*to_vec(
+            &highlight(VALUE, false)
+                .style(HighlightStyle::Red)
+                .color(true)
+                .lossy(true),
+        )?,
+    );
+    assert_eq!(
+        br"foo\\\x80\xf1\x80bar",
+        &*to_vec(&highlight(VALUE, true).color(false))?,
+    );
+    assert_eq!(
+        "foo\\\u{FFFD}\u{FFFD}bar".as_bytes(),
+        &*to_vec(&highlight(VALUE, false).color(false).lossy(true))?,
+    );
+    assert_eq!(
+        b"foo",
+        &*to_vec(&highlight(&b"foo"[..], false).color(true))?,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_not_terminal() -> io::Result<()> {
+    assert_eq!(
+        br"foo\\\x80\xf1\x80bar",
+        &*to_vec(&highlight(VALUE, false))?,
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+#![cfg(all(feature = "os_str_bytes", unix))]
+
+use std::env;
+use std::ffi::OsStr;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+use print_bytes::hyperlink;
+use print_bytes::write_lossy;
+use print_bytes::Hyperlink;
+
+fn to_vec(value: &Hyperlink<'_>) -> io::Result<Vec<u8>> {
+    let mut string = Vec::new();
+    write_lossy(&mut string, value)?;
+    Ok(string)
+}
+
+fn assert_link(uri: &[u8], path: &[u8], hostname: &str) -> io::Result<()> {
+    let path = Path::new(OsStr::from_bytes(path));
+    let mut expected = b"\x1B]8;;".to_vec();
+    expected.extend_from_slice(uri);
+    expected.extend_from_slice(b"\x1B\\");
+    expected.extend_from_slice(path.as_os_str().as_bytes());
+    expected.extend_from_slice(b"\x1B]8;;\x1B\\");
+    assert_eq!(
+        expected,
+        to_vec(&hyperlink(path, false).enabled(true).hostname(hostname))?,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_hyperlink() -> io::Result<()> {
+    assert_link(b"file://host/tmp/foo", b"/tmp/foo", "host")?;
+    assert_link(b"file:///tmp/foo", b"/tmp/foo", "")?;
+    assert_link(b"file://host/", b"/", "host")?;
+    assert_link(b"file://host/tmp/foo", b"//tmp/./foo/", "host")?;
+    assert_link(
+        b"file://my%20host/a%20b/%25%3F%23%5C/%80%FF/a:b@c;d=e",
+        b"/a b/%?#\\/\x80\xFF/a:b@c;d=e",
+        "my host",
+    )?;
+    assert_link(b"file://host/a%1B%5D8;;%07", b"/a\x1B]8;;\x07", "host")?;
+
+    let mut uri = b"file://host".to_vec();
+    for component in env::current_dir()?.join("foo").components().skip(1) {
+        uri.push(b'/');
+        uri.extend_from_slice(component.as_os_str().as_bytes());
+    }
+    if uri
+        .iter()
+        .all(|&x| x.is_ascii_alphanumeric() || b"/:._-".contains(&x))
+    {
+        assert_link(&uri, b"foo", "host")?;
+    }
+
+    let path = Path::new("/tmp/../foo");
+    assert_eq!(
+        b"/tmp/../foo",
+        &*to_vec(&hyperlink(path, false).enabled(true))?,
+    );
+
+    let path = Path::new("/tmp/foo");
+    assert_eq!(b"/tmp/foo", &*to_vec(&hyperlink(path, false))?);
+    assert_eq!(
+        b"/tmp/foo",
+        &*to_vec(&hyperlink(path, true).enabled(false))?,
+    );
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#[cfg(feature = "std")]
+use std::io;
+
+use print_bytes::write_json_string;
+#[cfg(feature = "std")]
+use print_bytes::write_json_string_io;
+use print_bytes::InvalidUtf8;
+use print_bytes::JsonError;
+
+fn to_json(value: &[u8], policy: InvalidUtf8) -> Result<String, JsonError> {
+    let mut string = String::new();
+    write_json_string(&mut string, value, policy)?;
+    Ok(string)
+}
+
+#[test]
+fn test_escapes() -> Result<(), JsonError> {
+    let value = "a\"b\\c\n\r\t\x08\x0C\0\x1F\x7F\u{2028}é";
+    let json = to_json(value.as_bytes(), InvalidUtf8::Error)?;
+    assert_eq!(
+        "\"a\\\"b\\\\c\\n\\r\\t\\b\\f\\u0000\\u001f\x7F\u{2028}é\"",
+        json,
+    );
+    assert_eq!(value, serde_json::from_str::<String>(&json).unwrap());
+
+    assert_eq!(r#""""#, to_json(b"", InvalidUtf8::Error)?);
+    Ok(())
+}
+
+#[test]
+fn test_invalid() -> Result<(), JsonError> {
+    let value = b"foo\x80\xF0\x9F\x92bar\xFF";
+
+    let json = to_json(value, InvalidUtf8::Replace)?;
+    assert_eq!("\"foo\u{FFFD}\u{FFFD}bar\u{FFFD}\"", json);
+    assert!(serde_json::from_str::<String>(&json).is_ok());
+
+    let json = to_json(value, InvalidUtf8::SurrogateEscape)?;
+    assert_eq!(r#""foo\udc80\udcf0\udc9f\udc92bar\udcff""#, json);
+    assert!(serde_json::from_str::<String>(&json).is_err());
+
+    let mut string = String::from("[");
+    assert_eq!(
+        Err(JsonError::InvalidUtf8),
+        write_json_string(&mut string, value, InvalidUtf8::Error),
+    );
+    assert_eq!("[\"foo", string);
+    assert_eq!(
+        Err(JsonError::InvalidUtf8),
+        to_json(b"\x80foo", InvalidUtf8::Error),
+    );
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io() -> io::Result<()> {
+    let mut writer = b"[".to_vec();
+    write_json_string_io(&mut writer, b"a\"b\x80", InvalidUtf8::Replace)?;
+    assert_eq!("[\"a\\\"b\u{FFFD}\"".as_bytes(), writer);
+
+    writer.clear();
+    let error =
+        write_json_string_io(&mut writer, b"foo\x80", InvalidUtf8::Error)
+            .unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        Some(&JsonError::InvalidUtf8),
+        error.get_ref().and_then(|x| x.downcast_ref()),
+    );
+    assert!(writer.is_empty());
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use print_bytes::c_str_literal;
+use print_bytes::rust_byte_str_literal;
+use print_bytes::rust_str_literal;
+
+const VALUE: &[u8] =
+    b"a\"b\\c\0d\x01\x7F\x80\xFFe\n\r\t??/?? ?\xC3\xA9F\x80a\x80G";
+
+#[test]
+fn test_rust_byte_str() {
+    assert_eq!(r#"b"""#, rust_byte_str_literal(b"").to_string());
+    assert_eq!(
+        r#"b"a\"b\\c\0d\x01\x7f\x80\xffe\n\r\t??/?? ?\xc3\xa9F\x80a\x80G""#,
+        rust_byte_str_literal(VALUE).to_string(),
+    );
+}
+
+#[test]
+fn test_rust_str() {
+    let value = "a\"b\\\0\x01\x7F\u{E9}\u{202E}x\u{2066}\u{200B}\u{200E}\
+                 \u{61C}\n\r\t\u{85}";
+    assert_eq!(
+        Some(
+            "\"a\\\"b\\\\\\0\\u{1}\\u{7f}\u{E9}\\u{202e}x\\u{2066}\u{200B}\
+             \\u{200e}\\u{61c}\\n\\r\\t\\u{85}\"",
+        ),
+        rust_str_literal(value.as_bytes())
+            .map(|x| x.to_string())
+            .as_deref(),
+    );
+    assert_eq!(
+        Some(r#""""#),
+        rust_str_literal(b"").map(|x| x.to_string()).as_deref(),
+    );
+
+    assert!(rust_str_literal(VALUE).is_none());
+    assert!(rust_str_literal(b"\xC3").is_none());
+}
+
+#[test]
+fn test_c_str() {
+    assert_eq!(r#""""#, c_str_literal(b"").to_string());
+    assert_eq!(
+        r#""a\"b\\c\000d\001\177\200\377e\n\r\t?\?/?\? ?\303\251F\200a\200G""#,
+        c_str_literal(VALUE).to_string(),
+    );
+    assert_eq!(
+        r#""\a\b\v\f?\?\? ?""#,
+        c_str_literal(b"\x07\x08\x0B\x0C??? ?").to_string(),
+    );
+}
+
+#[test]
+fn test_c_str_hex() {
+    assert_eq!(
+        concat!(
+            r#""a\"b\\c\x00""d\x01\x7f\x80\xff""e\n\r\t?\?/?\? "#,
+            r#"?\xc3\xa9""F\x80""a\x80G""#,
+        ),
+        c_str_literal(VALUE).hex_escapes(true).to_string(),
+    );
+    assert_eq!(
+        r#""\x80-\x80""0""#,
+        c_str_literal(b"\x80-\x800").hex_escapes(true).to_string()
+    );
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use print_bytes::ls_quote;
+use print_bytes::write_lossy;
+use print_bytes::QuotingStyle;
+
+const STYLES: [QuotingStyle; 7] = [
+    QuotingStyle::Literal,
+    QuotingStyle::Shell,
+    QuotingStyle::ShellAlways,
+    QuotingStyle::ShellEscape,
+    QuotingStyle::C,
+    QuotingStyle::Escape,
+    QuotingStyle::Locale,
+];
+
+type Quoted<const N: usize> = [&'static [u8]; N];
+
+fn assert_quoted(
+    value: &[u8],
+    expected: Quoted<7>,
+    hide_control_chars: bool,
+) -> io::Result<()> {
+    for (style, expected) in STYLES.into_iter().zip(expected) {
+        let mut string = Vec::new();
+        write_lossy(
+            &mut string,
+            &ls_quote(value, style).hide_control_chars(hide_control_chars),
+        )?;
+        assert_eq!(
+            expected,
+            string,
+            "{:?} {:?}: {:?}",
+            value,
+            style,
+            String::from_utf8_lossy(&string),
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_printable() -> io::Result<()> {
+    #[rustfmt::skip]
+    let fixtures: [(&[u8], Quoted<7>); 12] = [
+        (b"foo", [
+            b"foo", b"foo", b"'foo'", b"foo", b"\"foo\"", b"foo",
+            "\u{2018}foo\u{2019}".as_bytes(),
+        ]),
+        (b"", [
+            b"", b"''", b"''", b"''", b"\"\"", b"",
+            "\u{2018}\u{2019}".as_bytes(),
+        ]),
+        (b"foo bar", [
+            b"foo bar", b"'foo bar'", b"'foo bar'", b"'foo bar'",
+            b"\"foo bar\"", br"foo\ bar",
+            "\u{2018}foo bar\u{2019}".as_bytes(),
+        ]),
+        (b"it's", [
+            b"it's", b"\"it's\"", b"\"it's\"", b"\"it's\"", b"\"it's\"",
+            b"it's", "\u{2018}it's\u{2019}".as_bytes(),
+        ]),
+        (b"it's $5", [
+            b"it's $5", br"'it'\''s $5'", br"'it'\''s $5'",
+            br"'it'\''s $5'", b"\"it's $5\"", br"it's\ $5",
+            "\u{2018}it's $5\u{2019}".as_bytes(),
+        ]),
+        (br#"a\"b"#, [
+            br#"a\"b"#, br#"'a\"b'"#, br#"'a\"b'"#, br#"'a\"b'"#,
+            br#""a\\\"b""#, br#"a\\"b"#,
+            "\u{2018}a\\\\\"b\u{2019}".as_bytes(),
+        ]),
+        (b"~foo", [
+            b"~foo", b"'~foo'", b"'~foo'", b"'~foo'", b"\"~foo\"", b"~foo",
+            "\u{2018}~foo\u{2019}".as_bytes(),
+        ]),
+        (b"foo~#", [
+            b"foo~#", b"foo~#", b"'foo~#'", b"foo~#", b"\"foo~#\"",
+            b"foo~#", "\u{2018}foo~#\u{2019}".as_bytes(),
+        ]),
+        (b"{", [
+            b"{", b"'{'", b"'{'", b"'{'", b"\"{\"", b"{",
+            "\u{2018}{\u{2019}".as_bytes(),
+        ]),
+        (b"{a}@b,c", [
+            b"{a}@b,c", b"{a}@b,c", b"'{a}@b,c'", b"{a}@b,c",
+            b"\"{a}@b,c\"", b"{a}@b,c",
+            "\u{2018}{a}@b,c\u{2019}".as_bytes(),
+        ]),
+        (b"a?b", [
+            b"a?b", b"'a?b'", b"'a?b'", b"'a?b'", b"\"a?b\"", b"a?b",
+            "\u{2018}a?b\u{2019}".as_bytes(),
+        ]),
+        ("caf\u{E9}\u{2019}".as_bytes(), [
+            "caf\u{E9}\u{2019}".as_bytes(),
+            "caf\u{E9}\u{2019}".as_bytes(),
+            "'caf\u{E9}\u{2019}'".as_bytes(),
+            "caf\u{E9}\u{2019}".as_bytes(),
+            "\"caf\u{E9}\u{2019}\"".as_bytes(),
+            "caf\u{E9}\u{2019}".as_bytes(),
+            "\u{2018}caf\u{E9}\\\u{2019}\u{2019}".as_bytes(),
+        ]),
+    ];
+    for (value, expected) in fixtures {
+        assert_quoted(value, expected, false)?;
+        assert_quoted(value, expected, true)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_nonprintable() -> io::Result<()> {
+    #[rustfmt::skip]
+    let fixtures: [(&[u8], Quoted<7>, Quoted<3>); 10] = [
+        (b"a\nb", [
+            b"a\nb", b"'a\nb'", b"'a\nb'", br"'a'$'\n''b'", br#""a\nb""#,
+            br"a\nb", "\u{2018}a\\nb\u{2019}".as_bytes(),
+        ], [b"a?b", b"'a?b'", b"'a?b'"]),
+        (b"\x01x", [
+            b"\x01x", b"\x01x", b"'\x01x'", br"''$'\001''x'", br#""\001x""#,
+            br"\001x", "\u{2018}\\001x\u{2019}".as_bytes(),
+        ], [b"?x", b"?x", b"'?x'"]),
+        (b"x\x7F\x07", [
+            b"x\x7F\x07", b"x\x7F\x07", b"'x\x7F\x07'", br"'x'$'\177\a'",
+            br#""x\177\a""#, br"x\177\a",
+            "\u{2018}x\\177\\a\u{2019}".as_bytes(),
+        ], [b"x??", b"x??", b"'x??'"]),
+        (b"\x80", [
+            b"\x80", b"\x80", b"'\x80'", br"''$'\200'", br#""\200""#,
+            br"\200", "\u{2018}\\200\u{2019}".as_bytes(),
+        ], [b"?", b"?", b"'?'"]),
+        (b"\xF0\x9F\x92x\xF0\x9F\x92", [
+            b"\xF0\x9F\x92x\xF0\x9F\x92", b"\xF0\x9F\x92x\xF0\x9F\x92",
+            b"'\xF0\x9F\x92x\xF0\x9F\x92'",
+            br"''$'\360\237\222''x'$'\360\237\222'",
+            br#""\360\237\222x\360\237\222""#,
+            br"\360\237\222x\360\237\222",
+            "\u{2018}\\360\\237\\222x\\360\\237\\222\u{2019}".as_bytes(),
+        ], [b"???x?", b"???x?", b"'???x???'"]),
+        (b"a\x00\x31", [
+            b"a\x00\x31", b"a\x00\x31", b"'a\x00\x31'", br"'a'$'\0''1'",
+            br#""a\0001""#, br"a\0001",
+            "\u{2018}a\\0001\u{2019}".as_bytes(),
+        ], [b"a?1", b"a?1", b"'a?1'"]),
+        (b"it's \x01", [
+            b"it's \x01", b"'it'\\''s \x01'", b"'it'\\''s \x01'",
+            br"'''it'\''s '$'\001'", br#""it's \001""#, br"it's\ \001",
+            "\u{2018}it's \\001\u{2019}".as_bytes(),
+        ], [b"it's ?", br"'it'\''s ?'", br"'it'\''s ?'"]),
+        (b"it's\x80", [
+            b"it's\x80", b"'it'\\''s\x80'", b"'it'\\''s\x80'",
+            br"'''it'\''s'$'\200'", br#""it's\200""#, br"it's\200",
+            "\u{2018}it's\\200\u{2019}".as_bytes(),
+        ], [b"it's?", br"'it'\''s?'", br"'it'\''s?'"]),
+        (b"\x01'\x01", [
+            b"\x01'\x01", b"'\x01'\\''\x01'", b"'\x01'\\''\x01'",
+            br"'\001'\'''$'\001'", br#""\001'\001""#, br"\001'\001",
+            "\u{2018}\\001'\\001\u{2019}".as_bytes(),
+        ], [b"?'?", br"'?'\''?'", br"'?'\''?'"]),
+        (b"a'\x01b", [
+            b"a'\x01b", b"'a'\\''\x01b'", b"'a'\\''\x01b'",
+            br"'a'\'''$'\001''b'", br#""a'\001b""#, br"a'\001b",
+            "\u{2018}a'\\001b\u{2019}".as_bytes(),
+        ], [b"a'?b", br"'a'\''?b'", br"'a'\''?b'"]),
+    ];
+    for (value, expected, hidden) in fixtures {
+        assert_quoted(value, expected, false)?;
+
+        let mut expected = expected;
+        expected[..3].copy_from_slice(&hidden);
+        assert_quoted(value, expected, true)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(QuotingStyle::ShellEscape, QuotingStyle::ls_default(true));
+    assert_eq!(QuotingStyle::Literal, QuotingStyle::ls_default(false));
+}
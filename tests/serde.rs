@@ -0,0 +1,98 @@
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_test::Configure;
+use serde_test::Token;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Bytes(#[serde(with = "print_bytes::serde::bytes")] Vec<u8>);
+
+fn assert_json(expected: &str, value: &[u8]) -> serde_json::Result<()> {
+    let value = Bytes(value.to_vec());
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(expected, json);
+    assert_eq!(value, serde_json::from_str(&json)?);
+    Ok(())
+}
+
+#[test]
+fn test_json() -> serde_json::Result<()> {
+    assert_json(r#""""#, b"")?;
+    assert_json(r#""foo\\bar""#, br"foo\bar")?;
+    assert_json(r#"{"escaped":"foo\\x80bar"}"#, b"foo\x80bar")?;
+    assert_json(r#"{"escaped":"\\\\x80\\x80"}"#, b"\\x80\x80")
+}
+
+#[test]
+fn test_json_invalid() {
+    for json in [
+        r#"{"escaped":"\\x8"}"#,
+        r#"{"escaped":"\\x+8"}"#,
+        r#"{"escaped":"\\y"}"#,
+        r#"{"escaped":"foo\\"}"#,
+        r#"{"escaped":"\\u{d800}"}"#,
+        r#"{"escaped":"\\u{41}"}"#,
+        r#"{"bytes":"foo"}"#,
+        r#"{"escaped":"foo","bytes":"bar"}"#,
+        r#"{}"#,
+        "1",
+    ] {
+        assert!(serde_json::from_str::<Bytes>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn test_tokens() {
+    serde_test::assert_tokens(
+        &Bytes(b"foo".to_vec()).readable(),
+        &[Token::NewtypeStruct { name: "Bytes" }, Token::Str("foo")],
+    );
+    serde_test::assert_tokens(
+        &Bytes(b"foo\x80".to_vec()).readable(),
+        &[
+            Token::NewtypeStruct { name: "Bytes" },
+            Token::Map { len: Some(1) },
+            Token::Str("escaped"),
+            Token::Str(r"foo\x80"),
+            Token::MapEnd,
+        ],
+    );
+
+    serde_test::assert_tokens(
+        &Bytes(br"foo\".to_vec()).compact(),
+        &[Token::NewtypeStruct { name: "Bytes" }, Token::Str(r"foo\\")],
+    );
+    serde_test::assert_tokens(
+        &Bytes(b"foo\x80".to_vec()).compact(),
+        &[
+            Token::NewtypeStruct { name: "Bytes" },
+            Token::Str(r"foo\x80"),
+        ],
+    );
+}
+
+#[cfg(all(feature = "os_str_bytes", unix))]
+#[test]
+fn test_platform_strings() -> serde_json::Result<()> {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+    use std::path::PathBuf;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Entry {
+        #[serde(with = "print_bytes::serde::os_string")]
+        name: OsString,
+        #[serde(with = "print_bytes::serde::path_buf")]
+        path: PathBuf,
+    }
+
+    let entry = Entry {
+        name: OsString::from_vec(b"foo\x80".to_vec()),
+        path: PathBuf::from("/tmp/foo"),
+    };
+    let json = serde_json::to_string(&entry)?;
+    assert_eq!(r#"{"name":{"escaped":"foo\\x80"},"path":"/tmp/foo"}"#, json);
+    assert_eq!(entry, serde_json::from_str(&json)?);
+    Ok(())
+}
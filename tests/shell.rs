@@ -0,0 +1,216 @@
+use print_bytes::cmd_quote;
+use print_bytes::powershell_quote;
+use print_bytes::shell_quote;
+
+#[test]
+fn test_quote() {
+    for (expected, value) in [
+        ("''", &b""[..]),
+        ("foo", b"foo"),
+        (
+            "/usr/bin/foo-bar_1.2@3+4%5:6,7=8",
+            b"/usr/bin/foo-bar_1.2@3+4%5:6,7=8",
+        ),
+        ("'foo bar'", b"foo bar"),
+        ("'~'", b"~"),
+        ("'~foo'", b"~foo"),
+        ("'=ls'", b"=ls"),
+        ("'='", b"="),
+        ("a=b=", b"a=b="),
+        ("'#foo'", b"#foo"),
+        ("'$HOME *'", b"$HOME *"),
+        (r"\'", b"'"),
+        (r"'foo'\'\''bar'", b"foo''bar"),
+        ("'caf\u{E9}'", "caf\u{E9}".as_bytes()),
+        (r"'foo'$'\x0a\x09''bar'", b"foo\n\tbar"),
+        (r"$'\xc2\x85'", "\u{85}".as_bytes()),
+        (r"$'\x80\xff'\''x'", b"\x80\xff'x"),
+    ] {
+        assert_eq!(expected, shell_quote(value).to_string());
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_round_trip() -> std::io::Result<()> {
+    use std::io::ErrorKind;
+    use std::process::Command;
+
+    let values: [&[u8]; 6] = [
+        b"foo",
+        b"foo bar",
+        b"it's",
+        b"\x80\xFF",
+        b"line\nbreak \"$x\" `y` \\z",
+        b"\xF0\x9F\x92\x96\x1B[0m",
+    ];
+    for value in values {
+        let script = format!("printf %s {}", shell_quote(value));
+        let output = match Command::new("bash").args(["-c", &script]).output()
+        {
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                return Ok(());
+            }
+            result => result?,
+        };
+        assert!(output.status.success());
+        assert_eq!(value, output.stdout);
+    }
+    Ok(())
+}
+
+const WINDOWS_VALUES: [&str; 10] = [
+    "foo",
+    "",
+    r"C:\Program Files\foo",
+    r"C:\foo bar\",
+    r#"foo "bar" \"baz\\"#,
+    "it's \u{2019}quoted\u{201D}",
+    "100% & (a|b) <c> ^d! $e `f",
+    "-foo",
+    "12",
+    "tab\tbell\x07esc\x1B\u{85}",
+];
+
+fn parse_powershell(string: &str) -> String {
+    if let Some(string) = string.strip_prefix('\'') {
+        let mut result = String::new();
+        let mut chars = string.strip_suffix('\'').unwrap().chars();
+        while let Some(ch) = chars.next() {
+            result.push(ch);
+            if matches!(ch, '\'' | '\u{2018}'..='\u{201B}') {
+                assert_eq!(Some(ch), chars.next());
+            }
+        }
+        return result;
+    }
+    let Some(string) = string.strip_prefix('"') else {
+        return string.to_owned();
+    };
+
+    let mut result = String::new();
+    let mut string = string.strip_suffix('"').unwrap();
+    while let Some(ch) = string.chars().next() {
+        string = &string[ch.len_utf8()..];
+        match ch {
+            '`' => {
+                let ch = string.chars().next().unwrap();
+                string = &string[ch.len_utf8()..];
+                result.push(match ch {
+                    '0' => '\0',
+                    'a' => '\x07',
+                    'b' => '\x08',
+                    'f' => '\x0C',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'v' => '\x0B',
+                    _ => ch,
+                });
+            }
+            '$' => {
+                let rest = string.strip_prefix("([char]0x").unwrap();
+                let end = rest.find(')').unwrap();
+                let code = u32::from_str_radix(&rest[..end], 16).unwrap();
+                result.push(char::from_u32(code).unwrap());
+                string = &rest[end + 1..];
+            }
+            '"' | '\u{201C}'..='\u{201E}' => panic!("unescaped quote"),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+fn parse_cmd(string: &str) -> Vec<String> {
+    // Percent signs are expanded before other characters are parsed.
+    let string = string.replace("%%", "%");
+    let mut line = String::new();
+    let mut chars = string.chars();
+    while let Some(ch) = chars.next() {
+        assert!(!"!\"&()<>|".contains(ch), "unescaped {:?}", ch);
+        line.push(if ch == '^' { chars.next().unwrap() } else { ch });
+    }
+
+    // These are the rules used by [CommandLineToArgvW] for arguments.
+    let mut args = Vec::new();
+    let mut arg = None::<String>;
+    let mut is_quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                let mut backslashes = 1;
+                while chars.next_if_eq(&'\\').is_some() {
+                    backslashes += 1;
+                }
+                let arg = arg.get_or_insert_with(String::new);
+                if chars.peek() == Some(&'"') {
+                    arg.extend((0..backslashes / 2).map(|_| '\\'));
+                    if backslashes % 2 == 1 {
+                        arg.push('"');
+                        let _ = chars.next();
+                    }
+                } else {
+                    arg.extend((0..backslashes).map(|_| '\\'));
+                }
+            }
+            '"' => {
+                let arg = arg.get_or_insert_with(String::new);
+                if is_quoted && chars.next_if_eq(&'"').is_some() {
+                    arg.push('"');
+                } else {
+                    is_quoted ^= true;
+                }
+            }
+            ' ' | '\t' if !is_quoted => args.extend(arg.take()),
+            _ => arg.get_or_insert_with(String::new).push(ch),
+        }
+    }
+    args.extend(arg);
+    args
+}
+
+#[test]
+fn test_powershell_quote() {
+    for (expected, value) in [
+        ("foo", "foo"),
+        ("''", ""),
+        (r"C:\foo\bar.txt", r"C:\foo\bar.txt"),
+        ("'-foo'", "-foo"),
+        ("'12'", "12"),
+        ("'foo,bar'", "foo,bar"),
+        ("'it''s'", "it's"),
+        ("'\u{2018}\u{2018}$x \"y\"'", "\u{2018}$x \"y\""),
+        ("\"a`tb`n`0`\"`$`` $([char]0x1b)\"", "a\tb\n\0\"$` \x1B"),
+    ] {
+        assert_eq!(expected, powershell_quote(value.as_bytes()).to_string());
+    }
+    assert_eq!("'\u{FFFD}'", powershell_quote(b"\x80").to_string());
+
+    for value in WINDOWS_VALUES {
+        let quoted = powershell_quote(value.as_bytes()).to_string();
+        assert_eq!(value, parse_powershell(&quoted));
+    }
+}
+
+#[test]
+fn test_cmd_quote() {
+    for (expected, value) in [
+        ("foo", "foo"),
+        (r#"^"^""#, ""),
+        (r"C:\foo\bar.txt", r"C:\foo\bar.txt"),
+        (r#"^"C:\foo bar\\^""#, r"C:\foo bar\"),
+        (r#"^"a\\\^"b^""#, r#"a\"b"#),
+        ("100%%^&^(x^)^!", "100%&(x)!"),
+        ("a\u{FFFD}b", "a\nb"),
+    ] {
+        assert_eq!(expected, cmd_quote(value.as_bytes()).to_string());
+    }
+    assert_eq!("\u{FFFD}", cmd_quote(b"\x80").to_string());
+
+    for value in WINDOWS_VALUES {
+        let quoted = cmd_quote(value.as_bytes()).to_string();
+        assert_eq!([value], *parse_cmd(&quoted));
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use std::borrow::Cow;
 use std::ffi::CStr;
 #[cfg(feature = "os_str_bytes")]
@@ -7,6 +9,7 @@ use std::io;
 use std::path::Path;
 
 use print_bytes::write_lossy;
+use print_bytes::write_terminated_lossy;
 
 const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz";
 
@@ -40,6 +43,22 @@ fn test_multiple_writes() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_terminated_writes() -> io::Result<()> {
+    let mut writer = Vec::new();
+
+    write_terminated_lossy(&mut writer, [&b"foo"[..], INVALID_STRING], b"\n")?;
+    write_terminated_lossy(&mut writer, [b"bar"], b"\0")?;
+    write_terminated_lossy(&mut writer, Vec::<&[u8]>::new(), b"\0")?;
+
+    let mut expected = b"foo\n".to_vec();
+    expected.extend_from_slice(INVALID_STRING);
+    expected.extend_from_slice(b"\nbar\0");
+    assert_eq!(expected, writer);
+
+    Ok(())
+}
+
 #[test]
 fn test_implementations() -> io::Result<()> {
     const C_STRING: &CStr = c"foobar";
@@ -0,0 +1,134 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span;
+use tracing::subscriber;
+use tracing::Event;
+use tracing::Metadata;
+use tracing::Subscriber;
+
+use print_bytes::tracing::escaped_value;
+use print_bytes::tracing::raw_value;
+use print_bytes::tracing::value;
+
+#[derive(Debug, PartialEq)]
+enum Recorded {
+    Bytes(Vec<u8>),
+    Debug(String),
+    Str(String),
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<Recorded>>>);
+
+impl Visit for Capture {
+    fn record_str(&mut self, _: &Field, value: &str) {
+        self.0.lock().unwrap().push(Recorded::Str(value.to_owned()));
+    }
+
+    fn record_bytes(&mut self, _: &Field, value: &[u8]) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(Recorded::Bytes(value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() != "message" {
+            self.0
+                .lock()
+                .unwrap()
+                .push(Recorded::Debug(format!("{:?}", value)));
+        }
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+impl fmt::Debug for Capture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Capture")
+    }
+}
+
+fn capture<F>(event_fn: F) -> Vec<Recorded>
+where
+    F: FnOnce(),
+{
+    let capture = Capture::default();
+    subscriber::with_default(capture.clone(), event_fn);
+    let recorded = capture.0.lock().unwrap().drain(..).collect();
+    recorded
+}
+
+#[test]
+fn test_value() {
+    assert_eq!(
+        [
+            Recorded::Str(r"C:\Users".to_owned()),
+            Recorded::Str(r"foo\x80".to_owned()),
+            Recorded::Str(r"foo\\\x80bar".to_owned()),
+        ],
+        &*capture(|| {
+            tracing::info!(valid = value(br"C:\Users"), "test");
+            tracing::info!(invalid = value(b"foo\x80"), "test");
+            tracing::info!(invalid = value(b"foo\\\x80bar"), "test");
+        }),
+    );
+}
+
+#[test]
+fn test_escaped_value() {
+    assert_eq!(
+        [
+            Recorded::Str(r"C:\\Users".to_owned()),
+            Recorded::Str(r"foo\\x80".to_owned()),
+            Recorded::Str(r"foo\x80".to_owned()),
+        ],
+        &*capture(|| {
+            tracing::info!(valid = escaped_value(br"C:\Users"), "test");
+            tracing::info!(valid = escaped_value(br"foo\x80"), "test");
+            tracing::info!(invalid = escaped_value(b"foo\x80"), "test");
+        }),
+    );
+}
+
+#[test]
+fn test_raw_value() {
+    assert_eq!(
+        [
+            Recorded::Str("foobar".to_owned()),
+            Recorded::Bytes(b"foo\x80bar".to_vec()),
+        ],
+        &*capture(|| {
+            tracing::info!(valid = raw_value(b"foobar"), "test");
+            tracing::info!(invalid = raw_value(b"foo\x80bar"), "test");
+        }),
+    );
+}
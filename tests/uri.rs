@@ -0,0 +1,119 @@
+use print_bytes::percent_decode;
+use print_bytes::percent_encode;
+use print_bytes::EncodeSet;
+
+const VALUE: &[u8] = b"a-Z_9.~ !$&'()*+,;=:@/?#%[]\"\\\x00\x7F\x80\xFF";
+
+#[test]
+fn test_percent_encode() {
+    #[rustfmt::skip]
+    let fixtures = [
+        (
+            EncodeSet::PathSegment,
+            "a-Z_9.~%20!$&'()*+,;=:@%2F%3F%23%25%5B%5D%22%5C%00%7F%80%FF",
+        ),
+        (
+            EncodeSet::Query,
+            "a-Z_9.~%20!$%26'()*%2B,;%3D:@/?%23%25%5B%5D%22%5C%00%7F%80%FF",
+        ),
+        (
+            EncodeSet::Userinfo,
+            "a-Z_9.~%20!$&'()*+,;=%3A%40%2F%3F%23%25%5B%5D%22%5C%00%7F%80%FF",
+        ),
+    ];
+    for (encode_set, expected) in fixtures {
+        let string = percent_encode(VALUE, encode_set).to_string();
+        assert_eq!(expected, string);
+        assert_eq!(Ok(VALUE), percent_decode(&string).as_deref());
+    }
+    assert_eq!("", percent_encode(b"", EncodeSet::Query).to_string());
+}
+
+#[test]
+fn test_percent_decode() {
+    let value: Vec<_> = (0..=u8::MAX).collect();
+    let string = percent_encode(&value, EncodeSet::PathSegment).to_string();
+    assert_eq!(Ok(&*value), percent_decode(&string).as_deref());
+
+    assert_eq!(Ok(&b"\xAB\xCD"[..]), percent_decode("%ab%Cd").as_deref());
+    assert_eq!(Ok(&b"a+b"[..]), percent_decode("a+b").as_deref());
+    for string in ["%", "%4", "%4G", "%zz", "a%2", "%%41"] {
+        assert!(percent_decode(string).is_err());
+    }
+}
+
+#[cfg(all(feature = "os_str_bytes", unix))]
+mod file_uri {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use print_bytes::file_uri_to_path;
+    use print_bytes::path_to_file_uri;
+    use print_bytes::UriError;
+
+    fn assert_round_trip(expected: &str, path: &[u8]) -> Result<(), UriError> {
+        let path = Path::new(OsStr::from_bytes(path));
+        let uri = path_to_file_uri(path).expect("path is absolute");
+        assert_eq!(expected, uri);
+        assert_eq!(path, file_uri_to_path(&uri)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), UriError> {
+        assert_round_trip("file:///", b"/")?;
+        assert_round_trip("file:///tmp/foo", b"/tmp/foo")?;
+        assert_round_trip("file:///tmp/foo", b"//tmp/./foo/")?;
+        assert_round_trip("file:///.foo/..bar/...", b"/.foo/..bar/...")?;
+        assert_round_trip(
+            "file:///a%20b/%25%3F%23/%80%FF",
+            b"/a b/%?#/\x80\xFF",
+        )?;
+
+        let mut path = b"/".to_vec();
+        path.extend((1..=u8::MAX).filter(|&x| x != b'/'));
+        let uri = path_to_file_uri(Path::new(OsStr::from_bytes(&path)))
+            .expect("path is absolute");
+        assert!(uri.is_ascii());
+        assert_eq!(OsStr::from_bytes(&path), file_uri_to_path(&uri)?);
+
+        assert_eq!(None, path_to_file_uri(Path::new("tmp/foo")));
+        assert_eq!(None, path_to_file_uri(Path::new("/tmp/../foo")));
+        assert_eq!(None, path_to_file_uri(Path::new("/..")));
+        assert_eq!(None, path_to_file_uri(Path::new("")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_uri_to_path() -> Result<(), UriError> {
+        for uri in [
+            "file:/tmp/foo",
+            "FILE:///tmp/foo",
+            "file://localhost/tmp/foo",
+            "file://LocalHost/tmp/foo",
+            "file:///tmp/fo%6F",
+        ] {
+            assert_eq!(Path::new("/tmp/foo"), file_uri_to_path(uri)?);
+        }
+        assert_eq!(Path::new("/a//b/"), file_uri_to_path("file:///a//b/")?);
+
+        for uri in [
+            "",
+            "file:",
+            "file:tmp",
+            "file://",
+            "file://localhost",
+            "file://host/tmp",
+            "http:///tmp",
+            "file:///tmp?foo",
+            "file:///tmp#foo",
+            "file:///a%2Fb",
+            "file:///a%00b",
+            "file:///a%2",
+        ] {
+            assert!(file_uri_to_path(uri).is_err(), "{}", uri);
+        }
+        Ok(())
+    }
+}
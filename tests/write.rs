@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use std::io;
+#[cfg(any(feature = "specialization", not(windows)))]
+use std::io::Write;
+
+#[cfg(any(feature = "specialization", not(windows)))]
+use print_bytes::chain;
+use print_bytes::write_atomic_lossy;
+#[cfg(all(not(feature = "specialization"), not(windows)))]
+use print_bytes::WriteLossy;
+
+const INVALID_STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz";
+
+#[test]
+fn test_atomic_write() -> io::Result<()> {
+    let mut writer = Vec::new();
+    write_atomic_lossy(&mut writer, INVALID_STRING, b"\n")?;
+
+    let mut expected = INVALID_STRING.to_owned();
+    expected.push(b'\n');
+    assert_eq!(expected, writer);
+
+    Ok(())
+}
+
+// Custom writers can only implement [WriteLossy] on Windows when
+// specialization is available.
+#[cfg(any(feature = "specialization", not(windows)))]
+#[test]
+fn test_atomic_write_calls() -> io::Result<()> {
+    #[derive(Default)]
+    struct Writer(Vec<Vec<u8>>);
+
+    impl Write for Writer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(buf.to_owned());
+            Ok(buf.len())
+        }
+
+        fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+            self.0.push(buf.to_owned());
+            Ok(())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "specialization"))]
+    impl WriteLossy for Writer {}
+
+    let mut writer = Writer::default();
+    write_atomic_lossy(&mut writer, b"foo", b"\n")?;
+    write_atomic_lossy(&mut writer, &chain(b"bar", INVALID_STRING), b"\0")?;
+
+    let mut expected = b"bar".to_vec();
+    expected.extend_from_slice(INVALID_STRING);
+    expected.push(b'\0');
+    assert_eq!([&b"foo\n"[..], &expected], &*writer.0);
+
+    Ok(())
+}
@@ -0,0 +1,319 @@
+#!/usr/bin/env python3
+
+# Generates "src/unicode.rs" from the Unicode Character Database and the
+# data files of Unicode Technical Standard #39.
+#
+# Usage: tools/generate_unicode.py [DATA_DIRECTORY]
+#
+# The files listed in [UCD_FILES] and [SECURITY_FILES] are read from the
+# data directory, which defaults to "tools/ucd/<UNICODE_VERSION>". Files that
+# are missing are downloaded from unicode.org first. To update the tables,
+# change [UNICODE_VERSION] and run this script again.
+
+import os
+import subprocess
+import sys
+import urllib.request
+
+UNICODE_VERSION = "16.0.0"
+
+UCD_URL = "https://www.unicode.org/Public/{version}/ucd/{name}"
+UCD_FILES = [
+    "DerivedCoreProperties.txt",
+    "PropertyValueAliases.txt",
+    "ScriptExtensions.txt",
+    "Scripts.txt",
+    "UnicodeData.txt",
+]
+
+SECURITY_URL = "https://www.unicode.org/Public/security/{version}/{name}"
+SECURITY_FILES = ["confusables.txt"]
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUTPUT = os.path.join(ROOT, "src", "unicode.rs")
+
+MAX_CHAR = 0x10FFFF
+SURROGATES = range(0xD800, 0xE000)
+
+# Section 5.1 of Unicode Technical Standard #39.
+AUGMENTED_SCRIPTS = {
+    "Bopo": ["Hanb"],
+    "Hang": ["Kore"],
+    "Hani": ["Hanb", "Jpan", "Kore"],
+    "Hira": ["Jpan"],
+    "Kana": ["Jpan"],
+}
+
+# The names of augmented scripts, which are not Unicode script values, from
+# ISO 15924.
+AUGMENTED_SCRIPT_NAMES = {
+    "Hanb": "Han_with_Bopomofo",
+    "Jpan": "Japanese",
+    "Kore": "Korean",
+}
+
+# Characters with these scripts belong to all scripts.
+ALL_SCRIPTS = ["Zinh", "Zyyy"]
+
+
+def fetch(directory, url, name):
+    path = os.path.join(directory, name)
+    if not os.path.exists(path):
+        url = url.format(version=UNICODE_VERSION, name=name)
+        print("downloading", url, file=sys.stderr)
+        os.makedirs(directory, exist_ok=True)
+        urllib.request.urlretrieve(url, path)
+    return path
+
+
+def read_lines(path):
+    with open(path, encoding="utf-8-sig") as file:
+        for line in file:
+            line = line.split("#", 1)[0].strip()
+            if line:
+                yield [x.strip() for x in line.split(";")]
+
+
+def parse_range(value):
+    start, _, end = value.partition("..")
+    return range(int(start, 16), int(end or start, 16) + 1)
+
+
+def read_property(path):
+    values = {}
+    for fields in read_lines(path):
+        for ch in parse_range(fields[0]):
+            values[ch] = fields[1]
+    return values
+
+
+def read_binary_property(path, name):
+    chars = set()
+    for fields in read_lines(path):
+        if fields[1] == name:
+            chars.update(parse_range(fields[0]))
+    return chars
+
+
+def read_categories(path):
+    categories = {}
+    first = None
+    for fields in read_lines(path):
+        ch = int(fields[0], 16)
+        if fields[1].endswith(", First>"):
+            first = ch
+            continue
+        if fields[1].endswith(", Last>"):
+            chars = range(first, ch + 1)
+        else:
+            chars = [ch]
+        for ch in chars:
+            categories[ch] = fields[2]
+    return categories
+
+
+def read_script_names(path):
+    names = dict(AUGMENTED_SCRIPT_NAMES)
+    for fields in read_lines(path):
+        if fields[0] == "sc":
+            names[fields[1]] = fields[2]
+    return names
+
+
+def read_confusables(path):
+    confusables = []
+    for fields in read_lines(path):
+        source = int(fields[0], 16)
+        prototype = [int(x, 16) for x in fields[1].split()]
+        if source > 0x7F and all(x <= 0x7F for x in prototype):
+            confusables.append((source, "".join(map(chr, prototype))))
+    return sorted(confusables)
+
+
+def char_literal(ch):
+    return "'\\u{{{:X}}}'".format(ch)
+
+
+def str_literal(string):
+    string = string.replace("\\", "\\\\").replace('"', '\\"')
+    for ch in string:
+        assert 0x20 <= ord(ch) < 0x7F, "unexpected prototype"
+    return '"{}"'.format(string)
+
+
+def invisible_ranges(categories, default_ignorables):
+    ranges = []
+    for ch in range(MAX_CHAR + 1):
+        if ch in SURROGATES:
+            continue
+        category = categories.get(ch, "Cn")
+        if not (
+            category in ["Cc", "Cf", "Co", "Cn", "Zl", "Zp"]
+            or (category == "Zs" and ch != 0x20)
+            or ch in default_ignorables
+        ):
+            continue
+        if ranges and ranges[-1][1] == ch - 1:
+            ranges[-1][1] = ch
+        else:
+            ranges.append([ch, ch])
+    return ranges
+
+
+def script_tables(script_names, scripts, script_extensions):
+    long_names = {v: k for k, v in script_names.items()}
+
+    def get_scripts(ch):
+        if ch in script_extensions:
+            return script_extensions[ch].split()
+        return [long_names[scripts.get(ch, "Unknown")]]
+
+    char_scripts = [get_scripts(ch) for ch in range(MAX_CHAR + 1)]
+
+    codes = {x for ch in char_scripts for x in ch}
+    codes.difference_update(ALL_SCRIPTS)
+    codes.update(x for ch in AUGMENTED_SCRIPTS.values() for x in ch)
+    codes = sorted(codes)
+    assert len(codes) <= 256, "too many scripts"
+    indices = {x: i for i, x in enumerate(codes)}
+
+    sets = [(1 << 256) - 1]
+    extensions = []
+    for ch, names in enumerate(char_scripts):
+        if any(x in ALL_SCRIPTS for x in names):
+            bits = sets[0]
+        else:
+            augmented = set(names)
+            for name in names:
+                augmented.update(AUGMENTED_SCRIPTS.get(name, []))
+            bits = sum(1 << indices[x] for x in augmented)
+        if bits not in sets:
+            sets.append(bits)
+        index = sets.index(bits)
+        if not extensions or extensions[-1][1] != index:
+            extensions.append((ch, index))
+    assert len(sets) <= 1 << 16, "too many script sets"
+
+    scripts = [(x, script_names[x].replace("_", " ")) for x in codes]
+    return scripts, sets, extensions
+
+
+def generate(directory):
+    paths = {}
+    for name in UCD_FILES:
+        paths[name] = fetch(directory, UCD_URL, name)
+    for name in SECURITY_FILES:
+        paths[name] = fetch(directory, SECURITY_URL, name)
+
+    categories = read_categories(paths["UnicodeData.txt"])
+    default_ignorables = read_binary_property(
+        paths["DerivedCoreProperties.txt"],
+        "Default_Ignorable_Code_Point",
+    )
+    script_names = read_script_names(paths["PropertyValueAliases.txt"])
+    scripts, sets, extensions = script_tables(
+        script_names,
+        read_property(paths["Scripts.txt"]),
+        read_property(paths["ScriptExtensions.txt"]),
+    )
+    confusables = read_confusables(paths["confusables.txt"])
+
+    lines = []
+    write = lines.append
+
+    write("// This file is generated by tools/generate_unicode.py from the data")
+    write("// files of Unicode {}. Do not edit it directly.".format(
+        UNICODE_VERSION,
+    ))
+    write("")
+    write("use core::cmp::Ordering;")
+    write("")
+    write("// Characters that are unlikely to be visible when displayed. This table")
+    write("// includes characters in the following general categories:")
+    write("// - Cc (Control), Cf (Format), Co (Private_Use), and Cn (Unassigned)")
+    write("// - Zl (Line_Separator) and Zp (Paragraph_Separator)")
+    write("// - Zs (Space_Separator), except for U+0020")
+    write("//")
+    write("// It also includes characters in other categories with the")
+    write("// Default_Ignorable_Code_Point property, such as variation selectors and")
+    write("// Hangul fillers. Surrogates are excluded, since they are not characters.")
+    write("const INVISIBLE: &[(char, char)] = &[")
+    for start, end in invisible_ranges(categories, default_ignorables):
+        write("    ({}, {}),".format(char_literal(start), char_literal(end)))
+    write("];")
+    write("")
+    write("pub(super) fn is_invisible(ch: char) -> bool {")
+    write("    INVISIBLE")
+    write("        .binary_search_by(|&(start, end)| {")
+    write("            if ch < start {")
+    write("                Ordering::Greater")
+    write("            } else if ch > end {")
+    write("                Ordering::Less")
+    write("            } else {")
+    write("                Ordering::Equal")
+    write("            }")
+    write("        })")
+    write("        .is_ok()")
+    write("}")
+    write("")
+    write("// The scripts used by [SCRIPT_EXTENSIONS], sorted by their ISO 15924 codes.")
+    write("// This list includes the scripts used to augment script sets, as described")
+    write("// in section 5.1 of Unicode Technical Standard #39.")
+    write("pub(super) const SCRIPTS: [(&str, &str); {}] = [".format(len(scripts)))
+    for code, name in scripts:
+        write('    ("{}", "{}"),'.format(code, name))
+    write("];")
+    write("")
+    write("// Augmented script sets, as bit sets of indices into [SCRIPTS]. Common and")
+    write("// Inherited characters belong to all scripts.")
+    write("const SCRIPT_SETS: [[u64; 4]; {}] = [".format(len(sets)))
+    for bits in sets:
+        words = ", ".join(
+            "0x{:X}".format((bits >> (64 * i)) & ((1 << 64) - 1))
+            for i in range(4)
+        )
+        write("    [{}],".format(words))
+    write("];")
+    write("")
+    write("// The Script_Extensions property, as the first code point of each range")
+    write("// and an index into [SCRIPT_SETS].")
+    write("const SCRIPT_EXTENSIONS: &[(u32, u16)] = &[")
+    for start, index in extensions:
+        write("    (0x{:X}, {}),".format(start, index))
+    write("];")
+    write("")
+    write("// Non-ASCII characters that can be confused with ASCII strings. This table")
+    write("// includes each mapping from confusables.txt of Unicode Technical Standard")
+    write("// #39 whose source is not ASCII and whose prototype is ASCII. Mappings to")
+    write("// other prototypes are excluded.")
+    write("const CONFUSABLES: &[(char, &str)] = &[")
+    for ch, prototype in confusables:
+        write("    ({}, {}),".format(char_literal(ch), str_literal(prototype)))
+    write("];")
+    write("")
+    write("pub(super) fn script_set(ch: char) -> [u64; 4] {")
+    write("    let ch = u32::from(ch);")
+    write("    let index = SCRIPT_EXTENSIONS.partition_point(|&(start, _)| start <= ch);")
+    write("    SCRIPT_SETS[usize::from(SCRIPT_EXTENSIONS[index - 1].1)]")
+    write("}")
+    write("")
+    write("pub(super) fn confusable_prototype(ch: char) -> Option<&'static str> {")
+    write("    CONFUSABLES")
+    write("        .binary_search_by_key(&ch, |&(x, _)| x)")
+    write("        .ok()")
+    write("        .map(|x| CONFUSABLES[x].1)")
+    write("}")
+
+    with open(OUTPUT, "w", encoding="utf-8", newline="\n") as file:
+        file.write("\n".join(lines) + "\n")
+    subprocess.run(["rustfmt", "--edition", "2021", OUTPUT], check=True)
+
+
+if __name__ == "__main__":
+    if len(sys.argv) > 2:
+        sys.exit("usage: {} [DATA_DIRECTORY]".format(sys.argv[0]))
+    generate(
+        sys.argv[1]
+        if len(sys.argv) == 2
+        else os.path.join(ROOT, "tools", "ucd", UNICODE_VERSION),
+    )